@@ -2,13 +2,16 @@ use gitcg_sim::deck::{random_decklist, Decklist};
 use gitcg_sim::prelude::*;
 use gitcg_sim::rand::{rngs::SmallRng, RngCore, SeedableRng};
 use gitcg_sim::thiserror;
-use gitcg_sim_cli_utils::cli_args::{GenericSearch, SearchConfig};
+use gitcg_sim_cli_utils::{
+    cli_args::{GenericSearch, SearchConfig},
+    match_round::{iterate_match, iterate_paired_match, IterateMatchOpts},
+};
+use gitcg_sim_search::opening_book::{OpeningBook, OpeningBookSearch};
 use gitcg_sim_search::SearchLimits;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::{BufRead, BufReader, BufWriter};
 use std::path::PathBuf;
-
-use crate::iterate_match;
+use std::sync::{Arc, Mutex};
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
 pub enum DeckSrc {
@@ -25,6 +28,23 @@ pub struct EntryConfig {
     pub name: String,
     pub deck: DeckSrc,
     pub search_config: SearchConfig,
+    /// Path to the opening book JSON file to play moves from.
+    #[serde(default)]
+    pub opening_book: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct RecordOpeningBookOpts {
+    /// Path to the opening book JSON file. Existing statistics in the file are kept.
+    pub path: String,
+    #[serde(default = "RecordOpeningBookOpts::default_max_round")]
+    pub max_round: u8,
+}
+
+impl RecordOpeningBookOpts {
+    fn default_max_round() -> u8 {
+        OpeningBook::<Input>::DEFAULT_MAX_ROUND
+    }
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -43,6 +63,13 @@ pub struct CompareOpts {
     pub max_steps_per_round: u32,
     #[serde(default)]
     pub configs: Vec<EntryConfig>,
+    /// Record the moves of all finished games into an opening book.
+    #[serde(default)]
+    pub record_opening_book: Option<RecordOpeningBookOpts>,
+    /// Play `match_rounds` pairs of games with the same decks and RNG streams, swapping sides between
+    /// the games of a pair, and report the paired-difference statistics.
+    #[serde(default)]
+    pub paired: bool,
 }
 
 impl CompareOpts {
@@ -69,6 +96,24 @@ pub enum ParseCompareOptsError {
 pub enum ConstructEntryError {
     #[error("failed to load decklist")]
     LoadDecklistError(#[from] std::io::Error),
+    #[error("failed to load opening book")]
+    LoadOpeningBookError(#[from] OpeningBookFileError),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum OpeningBookFileError {
+    #[error("failed to open file")]
+    FileError(#[from] std::io::Error),
+    #[error("failed to parse JSON")]
+    DeserializeError(#[from] serde_json::Error),
+}
+
+pub fn load_opening_book(path: &str) -> Result<OpeningBook<Input>, OpeningBookFileError> {
+    Ok(serde_json::from_reader(BufReader::new(File::open(path)?))?)
+}
+
+pub fn save_opening_book(path: &str, book: &OpeningBook<Input>) -> Result<(), OpeningBookFileError> {
+    Ok(serde_json::to_writer(BufWriter::new(File::create(path)?), book)?)
 }
 
 impl EntryConfig {
@@ -78,7 +123,17 @@ impl EntryConfig {
         limits: Option<SearchLimits>,
     ) -> Result<(Decklist, impl '_ + Fn() -> GenericSearch<S>), ConstructEntryError> {
         let decklist = self.deck.decklist()?;
-        let make_search = move || self.search_config.make_search(parallel, limits);
+        let opening_book = match &self.opening_book {
+            Some(path) => Some(Arc::new(load_opening_book(path)?)),
+            None => None,
+        };
+        let make_search = move || {
+            let search = self.search_config.make_search(parallel, limits);
+            match &opening_book {
+                Some(book) => GenericSearch::OpeningBook(Box::new(OpeningBookSearch::new(book.clone(), search))),
+                None => search,
+            }
+        };
         Ok((decklist, make_search))
     }
 }
@@ -102,12 +157,16 @@ pub fn parse_compare_opts(json_path: &PathBuf) -> Result<CompareOpts, ParseCompa
     Ok(serde_json::from_reader(BufReader::new(File::open(json_path)?))?)
 }
 
-fn standard_game(decks: ByPlayer<&Decklist>, mut rng: SmallRng, random_decks: bool) -> GameStateWrapper {
-    let decklists = if random_decks {
-        ByPlayer::generate(|_| random_decklist(&mut rng))
+fn standard_decks(decks: ByPlayer<&Decklist>, rng: &mut SmallRng, random_decks: bool) -> ByPlayer<Decklist> {
+    if random_decks {
+        ByPlayer::generate(|_| random_decklist(rng))
     } else {
         decks.map(|d| d.clone())
-    };
+    }
+}
+
+pub(crate) fn standard_game(decks: ByPlayer<&Decklist>, mut rng: SmallRng, random_decks: bool) -> GameStateWrapper {
+    let decklists = standard_decks(decks, &mut rng, random_decks);
     let rng = SmallRng::seed_from_u64(rng.next_u64());
     gitcg_sim::prelude::new_standard_game(decklists.as_ref(), rng)
 }
@@ -130,6 +189,14 @@ pub fn main_compare(opts: CompareOpts) -> Result<(), String> {
     let rounds = opts.match_rounds;
     let steps = opts.max_steps_per_round;
     let random_seed = opts.random_seed;
+    let opening_book = match &opts.record_opening_book {
+        Some(RecordOpeningBookOpts { path, max_round }) => {
+            let mut book = load_opening_book(path).unwrap_or_else(|_| OpeningBook::new(*max_round));
+            book.max_round = *max_round;
+            Some(Mutex::new(book))
+        }
+        None => None,
+    };
     for (i, row) in matchup.iter_mut().enumerate() {
         for (j, cell) in row.iter_mut().enumerate() {
             if j <= i {
@@ -139,16 +206,34 @@ pub fn main_compare(opts: CompareOpts) -> Result<(), String> {
             let decks = cs.map(|(_, (x, _))| x);
             println!("--- {} vs. {}", entries[i].0, entries[j].0);
             // dbg!(&decks);
-            let (_, score, _) = iterate_match(
-                &|| cs.map(|(_, x)| x.1()),
-                &|rng| standard_game(decks, rng, random_decks),
-                crate::IterateMatchOpts {
-                    rounds,
-                    steps,
-                    random_seed,
-                },
-            );
-            *cell = score;
+            let match_opts = IterateMatchOpts {
+                rounds,
+                steps,
+                random_seed,
+                opening_book: opening_book.as_ref(),
+            };
+            if opts.paired {
+                let (paired, _) = iterate_paired_match(
+                    &|| cs.map(|(_, x)| x.1()),
+                    &|rng| standard_decks(decks, rng, random_decks),
+                    match_opts,
+                );
+                let (low, high) = paired.confidence_interval();
+                println!(
+                    "{:?} pairs={:?} mean_difference={:+.4} 95% CI=[{low:+.4}, {high:+.4}]",
+                    paired.stats,
+                    paired.pair_scores,
+                    paired.mean_difference()
+                );
+                *cell = paired.stats.score_rate();
+            } else {
+                let (_, score, _) = iterate_match(
+                    &|| cs.map(|(_, x)| x.1()),
+                    &|rng| standard_game(decks, rng, random_decks),
+                    match_opts,
+                );
+                *cell = score;
+            }
         }
     }
     for row in matchup {
@@ -157,5 +242,10 @@ pub fn main_compare(opts: CompareOpts) -> Result<(), String> {
         }
         println!();
     }
+    if let (Some(RecordOpeningBookOpts { path, .. }), Some(book)) = (&opts.record_opening_book, opening_book) {
+        let book = book.into_inner().map_err(|e| e.to_string())?;
+        println!("Opening book: {} positions saved to {path}", book.positions.len());
+        save_opening_book(path, &book).map_err(|e| e.to_string())?;
+    }
     Ok(())
 }
@@ -0,0 +1,177 @@
+use gitcg_sim::deck::{card_allowed_for, fill_random_cards, Decklist, DECK_SIZE};
+use gitcg_sim::ids::CharId;
+use gitcg_sim::prelude::*;
+use gitcg_sim::rand::{rngs::SmallRng, Rng, SeedableRng};
+use gitcg_sim_cli_utils::cli_args::SearchConfig;
+use gitcg_sim_search::SearchLimits;
+use serde_json::json;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::PathBuf;
+
+use crate::compare::{DeckSrc, ParseCompareOptsError};
+use crate::matchups::play_mirrored;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct DeckbuilderOpts {
+    #[serde(default)]
+    pub parallel: bool,
+    #[serde(default = "DeckbuilderOpts::default_random_seed")]
+    pub random_seed: u64,
+    #[serde(default)]
+    pub search_limits: Option<SearchLimits>,
+    /// Names of the 3 characters of the deck.
+    pub characters: Vec<String>,
+    /// Deck to start from. Cards that are not allowed for the characters are replaced.
+    #[serde(default)]
+    pub initial_deck: Option<DeckSrc>,
+    /// Reference decks to score the candidates against.
+    pub gauntlet: Vec<DeckSrc>,
+    /// Search configuration used by both players.
+    pub search_config: SearchConfig,
+    #[serde(default = "DeckbuilderOpts::default_generations")]
+    pub generations: u32,
+    #[serde(default = "DeckbuilderOpts::default_candidates_per_generation")]
+    pub candidates_per_generation: u32,
+    /// Number of cards replaced in each candidate.
+    #[serde(default = "DeckbuilderOpts::default_mutation_size")]
+    pub mutation_size: usize,
+    /// Games against each reference deck per evaluation. Each seed is played twice with the decks
+    /// swapping sides, so odd numbers are rounded up.
+    #[serde(default = "DeckbuilderOpts::default_games_per_opponent")]
+    pub games_per_opponent: u32,
+    #[serde(default = "DeckbuilderOpts::default_max_steps_per_round")]
+    pub max_steps_per_round: u32,
+    /// Path to write the best decklist to after each improvement.
+    pub output: String,
+}
+
+impl DeckbuilderOpts {
+    fn default_random_seed() -> u64 {
+        100
+    }
+    fn default_generations() -> u32 {
+        20
+    }
+    fn default_candidates_per_generation() -> u32 {
+        4
+    }
+    fn default_mutation_size() -> usize {
+        2
+    }
+    fn default_games_per_opponent() -> u32 {
+        10
+    }
+    fn default_max_steps_per_round() -> u32 {
+        200
+    }
+}
+
+pub fn parse_deckbuilder_opts(json_path: &PathBuf) -> Result<DeckbuilderOpts, ParseCompareOptsError> {
+    Ok(serde_json::from_reader(BufReader::new(File::open(json_path)?))?)
+}
+
+/// Replace `count` random cards with random cards allowed for the characters.
+fn mutate<R: Rng>(rng: &mut R, decklist: &Decklist, count: usize) -> Decklist {
+    let mut decklist = decklist.clone();
+    for _ in 0..count.min(decklist.cards.len()) {
+        let i = rng.gen_range(0..decklist.cards.len());
+        decklist.cards.remove(i);
+    }
+    fill_random_cards(rng, &decklist.characters, &mut decklist.cards);
+    decklist
+}
+
+fn save_decklist(path: &str, decklist: &Decklist) -> Result<(), std::io::Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    for line in decklist.to_lines() {
+        writeln!(writer, "{line}")?;
+    }
+    writer.flush()
+}
+
+/// Hill climbing over the cards of a deck with fixed characters: each generation, mutated candidates
+/// of the current deck are scored against the gauntlet with the same seeds as the current deck,
+/// and the best candidate replaces it if it scores higher.
+pub fn main_deckbuilder(opts: DeckbuilderOpts) -> Result<(), String> {
+    let characters = opts
+        .characters
+        .iter()
+        .map(|name| CharId::from_name(name).ok_or_else(|| format!("Unknown character: {name}")))
+        .collect::<Result<Vec<_>, _>>()?;
+    let gauntlet = opts
+        .gauntlet
+        .iter()
+        .map(|d| d.decklist())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("failed to load gauntlet: {e}"))?;
+    if gauntlet.is_empty() {
+        return Err("The gauntlet is empty.".to_string());
+    }
+    let mut rng = SmallRng::seed_from_u64(opts.random_seed);
+    let mut current = match &opts.initial_deck {
+        Some(src) => {
+            let mut cards = src.decklist().map_err(|e| e.to_string())?.cards;
+            cards.retain(|card_id| card_allowed_for(&characters, *card_id));
+            cards.truncate(DECK_SIZE);
+            fill_random_cards(&mut rng, &characters, &mut cards);
+            Decklist::new(characters.as_slice().into(), cards)
+        }
+        None => Decklist::random_for_characters(&mut rng, &characters),
+    };
+    current.validate().map_err(|e| format!("Illegal deck: {e}"))?;
+
+    let seeds = opts.games_per_opponent.div_ceil(2) as u64;
+    let make_search = || {
+        opts.search_config
+            .make_search::<StandardNondetHandlerState>(opts.parallel, opts.search_limits)
+    };
+    let evaluate = |decklist: &Decklist, random_seed: u64| -> f32 {
+        let total: f32 = gauntlet
+            .iter()
+            .map(|opponent| {
+                let decks = ByPlayer::new(decklist, opponent);
+                play_mirrored(decks, &make_search, random_seed, seeds, opts.max_steps_per_round).score_rate()
+            })
+            .sum();
+        total / gauntlet.len() as f32
+    };
+
+    let mut current_score = evaluate(&current, opts.random_seed);
+    println!("{}", json!({ "initial_score_rate": current_score }));
+    save_decklist(&opts.output, &current).map_err(|e| e.to_string())?;
+    for generation in 0..opts.generations {
+        // Common random numbers: all decks of the generation play the same seeds
+        let random_seed = rng.gen();
+        let current_rescored = evaluate(&current, random_seed);
+        let best = (0..opts.candidates_per_generation)
+            .map(|_| mutate(&mut rng, &current, opts.mutation_size))
+            .filter(|candidate| candidate.validate().is_ok())
+            .map(|candidate| {
+                let score = evaluate(&candidate, random_seed);
+                (candidate, score)
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b));
+        let accepted = match best {
+            Some((candidate, score)) if score > current_rescored => {
+                current = candidate;
+                current_score = score;
+                save_decklist(&opts.output, &current).map_err(|e| e.to_string())?;
+                true
+            }
+            _ => {
+                current_score = current_rescored;
+                false
+            }
+        };
+        println!(
+            "{}",
+            json!({ "generation": generation, "score_rate": current_score, "accepted": accepted })
+        );
+    }
+    println!("Best deck ({current_score:.3} score rate) saved to {}:", opts.output);
+    for line in current.to_lines() {
+        println!("{line}");
+    }
+    Ok(())
+}
@@ -9,16 +9,28 @@ use structopt::StructOpt;
 use gitcg_sim::prelude::*;
 use gitcg_sim_cli_utils::cli_args::{DeckGen, SearchAlgorithm, SearchConfig, SearchOpts};
 use gitcg_sim_search::mcts::CpuctConfig;
+use gitcg_sim_search::GameTreeSearch;
 
-mod match_round;
-use match_round::*;
+use gitcg_sim_cli_utils::match_round::*;
 
 mod compare;
 use compare::*;
 
+mod deckbuilder;
+use deckbuilder::*;
+
+mod matchups;
+use matchups::*;
+
 mod perft;
 use perft::*;
 
+mod tournament;
+use tournament::*;
+
+mod tune;
+use tune::*;
+
 #[derive(Debug, StructOpt, Clone)]
 #[structopt(about = "Genius Invokation TCG simulator")]
 pub enum BenchmarkOpts {
@@ -38,6 +50,11 @@ pub enum BenchmarkOpts {
     Evaluate {
         #[structopt(long = "--parallel")]
         parallel: bool,
+        #[structopt(
+            long = "--multi-pv",
+            help = "Print an analysis table of the top moves instead of tracing the search."
+        )]
+        multi_pv: Option<usize>,
         #[structopt(flatten)]
         search: SearchOpts,
     },
@@ -66,6 +83,28 @@ pub enum BenchmarkOpts {
         #[structopt(help = "Path to the JSON file for the configuration.")]
         json_path: PathBuf,
     },
+    #[structopt(
+        help = "Rate multiple search configurations with a round-robin or Swiss tournament, or run an SPRT between two of them."
+    )]
+    Tournament {
+        #[structopt(help = "Path to the JSON file for the configuration.")]
+        json_path: PathBuf,
+    },
+    #[structopt(help = "Play every pair of decks in a directory with a fixed search configuration.")]
+    Matchups {
+        #[structopt(help = "Path to the JSON file for the configuration.")]
+        json_path: PathBuf,
+    },
+    #[structopt(help = "Build a deck for a fixed set of characters by hill climbing against a gauntlet of decks.")]
+    BuildDeck {
+        #[structopt(help = "Path to the JSON file for the configuration.")]
+        json_path: PathBuf,
+    },
+    #[structopt(help = "Tune numeric search parameters with SPSA using self-play matches.")]
+    Tune {
+        #[structopt(help = "Path to the JSON file for the configuration.")]
+        json_path: PathBuf,
+    },
     #[structopt(
         help = "Count the total number of positions visited at a given depth, starting from a particular initial position."
     )]
@@ -98,7 +137,11 @@ impl BenchmarkOpts {
             | BenchmarkOpts::Benchmark { search: deck, .. }
             | BenchmarkOpts::Evaluate { search: deck, .. }
             | BenchmarkOpts::Match { search: deck, .. } => Some(deck),
-            BenchmarkOpts::Compare { .. } => Some(&_DEFAULT_SEARCH_OPTS),
+            BenchmarkOpts::Compare { .. }
+            | BenchmarkOpts::Tournament { .. }
+            | BenchmarkOpts::Matchups { .. }
+            | BenchmarkOpts::BuildDeck { .. }
+            | BenchmarkOpts::Tune { .. } => Some(&_DEFAULT_SEARCH_OPTS),
             BenchmarkOpts::Perft { search: deck, .. } => Some(deck),
         }
     }
@@ -196,6 +239,7 @@ fn main() -> Result<(), std::io::Error> {
                 rounds,
                 random_seed: deck_opts.seed.unwrap_or(100),
                 steps,
+                opening_book: None,
             },
         );
 
@@ -210,6 +254,24 @@ fn main() -> Result<(), std::io::Error> {
     match opts {
         BenchmarkOpts::Speedup { .. } => speedup(),
         BenchmarkOpts::Benchmark { parallel, .. } => benchmark(parallel, steps),
+        BenchmarkOpts::Evaluate {
+            parallel,
+            multi_pv: Some(multi_pv),
+            ref search,
+        } => {
+            let game = search.standard_game(None)?;
+            let Some(player_id) = game.to_move() else {
+                println!("Winner: {:?}", game.winner());
+                return Ok(());
+            };
+            let mut s = search.make_search(parallel, search.limits());
+            let t0 = Instant::now();
+            let lines = s.analyze(&game, player_id, multi_pv);
+            println!("Analysis for {player_id} ({:.2}ms):", t0.elapsed().as_millis());
+            for (i, line) in lines.iter().enumerate() {
+                println!("{:2}. {}", i + 1, line.summary(8));
+            }
+        }
         BenchmarkOpts::Evaluate { parallel, .. } => benchmark(parallel, 1),
         BenchmarkOpts::Match {
             parallel,
@@ -231,6 +293,34 @@ fn main() -> Result<(), std::io::Error> {
                 std::process::exit(1)
             }
         }
+        BenchmarkOpts::Tournament { json_path, .. } => {
+            let opts = parse_tournament_opts(&json_path).expect("Failed to parse config.");
+            if let Err(e) = main_tournament(opts) {
+                println!("{e}");
+                std::process::exit(1)
+            }
+        }
+        BenchmarkOpts::Matchups { json_path, .. } => {
+            let opts = parse_matchup_opts(&json_path).expect("Failed to parse config.");
+            if let Err(e) = main_matchups(opts) {
+                println!("{e}");
+                std::process::exit(1)
+            }
+        }
+        BenchmarkOpts::BuildDeck { json_path, .. } => {
+            let opts = parse_deckbuilder_opts(&json_path).expect("Failed to parse config.");
+            if let Err(e) = main_deckbuilder(opts) {
+                println!("{e}");
+                std::process::exit(1)
+            }
+        }
+        BenchmarkOpts::Tune { json_path, .. } => {
+            let opts = parse_tune_opts(&json_path).expect("Failed to parse config.");
+            if let Err(e) = main_tune(opts) {
+                println!("{e}");
+                std::process::exit(1)
+            }
+        }
         BenchmarkOpts::Perft {
             parallel,
             iterative,
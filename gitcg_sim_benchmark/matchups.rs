@@ -0,0 +1,253 @@
+use gitcg_sim::deck::Decklist;
+use gitcg_sim::prelude::*;
+use gitcg_sim::rand::{rngs::SmallRng, Rng, SeedableRng};
+use gitcg_sim_cli_utils::{
+    cli_args::{GenericSearch, SearchConfig},
+    match_round::{match_round, MatchStats},
+};
+use gitcg_sim_search::SearchLimits;
+use rayon::prelude::*;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::compare::{DeckSrc, ParseCompareOptsError};
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct MatchupOpts {
+    #[serde(default)]
+    pub parallel: bool,
+    #[serde(default = "MatchupOpts::default_random_seed")]
+    pub random_seed: u64,
+    #[serde(default)]
+    pub search_limits: Option<SearchLimits>,
+    /// Directory of decklist files, e.g. `sample_decks`.
+    pub decks_dir: String,
+    /// Search configuration used by both players.
+    pub search_config: SearchConfig,
+    /// Games per pair of decks. Each seed is played twice with the decks swapping sides,
+    /// so odd numbers are rounded up.
+    #[serde(default = "MatchupOpts::default_games_per_matchup")]
+    pub games_per_matchup: u32,
+    #[serde(default = "MatchupOpts::default_max_steps_per_round")]
+    pub max_steps_per_round: u32,
+    /// Path to write the matchup matrix to, as CSV if it ends with `.csv` and as JSON otherwise.
+    #[serde(default)]
+    pub output: Option<String>,
+}
+
+impl MatchupOpts {
+    fn default_random_seed() -> u64 {
+        100
+    }
+    fn default_games_per_matchup() -> u32 {
+        20
+    }
+    fn default_max_steps_per_round() -> u32 {
+        200
+    }
+}
+
+/// Results of `deck` against `opponent`, named by their file names.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct MatchupCell {
+    pub deck: String,
+    pub opponent: String,
+    pub games: u32,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+    pub score_rate: f64,
+    /// 95% Wilson score interval of the score rate
+    pub ci_low: f64,
+    pub ci_high: f64,
+}
+
+impl MatchupCell {
+    fn new(deck: &str, opponent: &str, stats: MatchStats) -> Self {
+        let (ci_low, ci_high) = wilson_interval(stats);
+        Self {
+            deck: deck.to_string(),
+            opponent: opponent.to_string(),
+            games: stats.games(),
+            wins: stats.wins,
+            draws: stats.draws,
+            losses: stats.losses,
+            score_rate: stats.score_rate() as f64,
+            ci_low,
+            ci_high,
+        }
+    }
+
+    const CSV_HEADER: &'static str = "deck,opponent,games,wins,draws,losses,score_rate,ci_low,ci_high";
+
+    fn csv_row(&self) -> String {
+        let quote = |s: &str| format!("\"{}\"", s.replace('"', "\"\""));
+        format!(
+            "{},{},{},{},{},{},{:.4},{:.4},{:.4}",
+            quote(&self.deck),
+            quote(&self.opponent),
+            self.games,
+            self.wins,
+            self.draws,
+            self.losses,
+            self.score_rate,
+            self.ci_low,
+            self.ci_high
+        )
+    }
+}
+
+/// 95% Wilson score interval, with draws counted as half a win.
+fn wilson_interval(stats: MatchStats) -> (f64, f64) {
+    const Z: f64 = 1.96;
+    let n = stats.games() as f64;
+    if n == 0.0 {
+        return (0.0, 1.0);
+    }
+    let p = stats.score_rate() as f64;
+    let z2 = Z * Z;
+    let center = (p + z2 / (2.0 * n)) / (1.0 + z2 / n);
+    let half = Z * (p * (1.0 - p) / n + z2 / (4.0 * n * n)).sqrt() / (1.0 + z2 / n);
+    ((center - half).max(0.0), (center + half).min(1.0))
+}
+
+pub fn parse_matchup_opts(json_path: &PathBuf) -> Result<MatchupOpts, ParseCompareOptsError> {
+    Ok(serde_json::from_reader(BufReader::new(File::open(json_path)?))?)
+}
+
+/// Decklist files in the directory, sorted by file name.
+fn load_decks(dir: &Path) -> Result<Vec<(String, Decklist)>, std::io::Error> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<Result<_, _>>()?;
+    paths.retain(|p| p.is_file());
+    paths.sort();
+    paths
+        .into_iter()
+        .map(|path| {
+            let name = path
+                .file_name()
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let decklist = DeckSrc::FromFile(path.to_string_lossy().to_string()).decklist()?;
+            Ok((name, decklist))
+        })
+        .collect()
+}
+
+fn write_output(path: &str, cells: &[MatchupCell]) -> Result<(), std::io::Error> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    if path.ends_with(".csv") {
+        writeln!(writer, "{}", MatchupCell::CSV_HEADER)?;
+        for cell in cells {
+            writeln!(writer, "{}", cell.csv_row())?;
+        }
+    } else {
+        serde_json::to_writer_pretty(&mut writer, cells)?;
+    }
+    writer.flush()
+}
+
+/// Play `seeds` pairs of games between the decks, where each seed is played twice with the decks
+/// swapping sides. Returns the results for the first deck.
+pub(crate) fn play_mirrored<M: Sync + Fn() -> GenericSearch<StandardNondetHandlerState>>(
+    decks: ByPlayer<&Decklist>,
+    make_search: &M,
+    random_seed: u64,
+    seeds: u64,
+    steps: u32,
+) -> MatchStats {
+    (0..seeds)
+        .flat_map(|k| [(k, PlayerId::PlayerFirst), (k, PlayerId::PlayerSecond)])
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|(k, side)| {
+            // The first deck plays as `side`, keeping the same RNG streams for both sides
+            let mut rng = SmallRng::seed_from_u64(random_seed.wrapping_add(k));
+            let seed: u64 = rng.gen();
+            let player_seeds: ByPlayer<u64> = ByPlayer::generate(|_| rng.gen());
+            let (pair, player_seeds) = match side {
+                PlayerId::PlayerFirst => (decks, player_seeds),
+                PlayerId::PlayerSecond => (
+                    ByPlayer::new(decks.1, decks.0),
+                    ByPlayer::new(player_seeds.1, player_seeds.0),
+                ),
+            };
+            let game = new_standard_game_with_player_rngs(
+                pair,
+                SmallRng::seed_from_u64(seed),
+                player_seeds.map(SmallRng::seed_from_u64),
+            );
+            let mut searches = ByPlayer::new(make_search(), make_search());
+            let (winner, _, _) = match_round(game, &mut searches, steps, None);
+            MatchStats::from_winner(winner, side)
+        })
+        .reduce(MatchStats::default, |mut a, b| {
+            a.add_in_place(&b);
+            a
+        })
+}
+
+/// Play every pair of decks with the same search configuration on both sides. Each seed is played
+/// twice with the decks swapping sides, so that first player advantage and the luck of the draw
+/// cancel out, and the results for the reverse ordered pair are the flipped results.
+pub fn main_matchups(opts: MatchupOpts) -> Result<(), String> {
+    let decks = load_decks(Path::new(&opts.decks_dir)).map_err(|e| format!("failed to load decks: {e}"))?;
+    if decks.len() < 2 {
+        return Err(format!("At least 2 decklists are required in {}", opts.decks_dir));
+    }
+    let n = decks.len();
+    let seeds = opts.games_per_matchup.div_ceil(2) as u64;
+    let make_search = || {
+        opts.search_config
+            .make_search::<StandardNondetHandlerState>(opts.parallel, opts.search_limits)
+    };
+    let mut stats = vec![vec![MatchStats::default(); n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            println!("--- {} vs. {}", decks[i].0, decks[j].0);
+            let pair_stats = play_mirrored(
+                ByPlayer::new(&decks[i].1, &decks[j].1),
+                &make_search,
+                opts.random_seed,
+                seeds,
+                opts.max_steps_per_round,
+            );
+            println!("{pair_stats:?}");
+            stats[i][j] = pair_stats;
+            stats[j][i] = pair_stats.flip();
+        }
+    }
+
+    let cells: Vec<MatchupCell> = (0..n)
+        .flat_map(|i| (0..n).filter(move |&j| j != i).map(move |j| (i, j)))
+        .map(|(i, j)| MatchupCell::new(&decks[i].0, &decks[j].0, stats[i][j]))
+        .collect();
+
+    print!("{:24}", "");
+    for j in 0..n {
+        print!(" {:>8}", format!("#{}", j + 1));
+    }
+    println!(" {:>8}", "Average");
+    for (i, row) in stats.iter().enumerate() {
+        print!("{:24}", format!("#{} {}", i + 1, decks[i].0));
+        for (j, cell) in row.iter().enumerate() {
+            if i == j {
+                print!(" {:>8}", "-");
+            } else {
+                print!(" {:7.1}%", 100.0 * cell.score_rate());
+            }
+        }
+        let total = row.iter().fold(MatchStats::default(), |mut a, b| {
+            a.add_in_place(b);
+            a
+        });
+        println!(" {:7.1}%", 100.0 * total.score_rate());
+    }
+    if let Some(path) = &opts.output {
+        write_output(path, &cells).map_err(|e| e.to_string())?;
+        println!("Matchup matrix saved to {path}");
+    }
+    Ok(())
+}
@@ -0,0 +1,579 @@
+use gitcg_sim::prelude::*;
+use gitcg_sim_cli_utils::match_round::{iterate_match_stats, IterateMatchOpts, MatchStats};
+use gitcg_sim_search::SearchLimits;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+
+use crate::compare::{standard_game, EntryConfig, ParseCompareOptsError};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum TournamentFormat {
+    /// Every pair of entries plays `games_per_pairing` games.
+    #[default]
+    #[serde(rename = "round_robin")]
+    RoundRobin,
+    /// Entries are paired by current standings for a number of rounds.
+    #[serde(rename = "swiss")]
+    Swiss { rounds: u32 },
+}
+
+/// Sequential probability ratio test between two entries, stopping as soon as the
+/// Elo difference is decided to be `elo0` (H0) or `elo1` (H1).
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SprtOpts {
+    pub candidate: String,
+    pub baseline: String,
+    #[serde(default)]
+    pub elo0: f64,
+    #[serde(default = "SprtOpts::default_elo1")]
+    pub elo1: f64,
+    /// False positive rate
+    #[serde(default = "SprtOpts::default_error_rate")]
+    pub alpha: f64,
+    /// False negative rate
+    #[serde(default = "SprtOpts::default_error_rate")]
+    pub beta: f64,
+    /// Games played between each test of the stopping condition
+    #[serde(default = "SprtOpts::default_batch_games")]
+    pub batch_games: u32,
+    #[serde(default = "SprtOpts::default_max_games")]
+    pub max_games: u32,
+}
+
+impl SprtOpts {
+    fn default_elo1() -> f64 {
+        20.0
+    }
+    fn default_error_rate() -> f64 {
+        0.05
+    }
+    fn default_batch_games() -> u32 {
+        20
+    }
+    fn default_max_games() -> u32 {
+        2000
+    }
+
+    /// (lower, upper) bounds of the log-likelihood ratio.
+    pub fn bounds(&self) -> (f64, f64) {
+        (
+            (self.beta / (1.0 - self.alpha)).ln(),
+            ((1.0 - self.beta) / self.alpha).ln(),
+        )
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TournamentOpts {
+    #[serde(default)]
+    pub parallel: bool,
+    #[serde(default = "TournamentOpts::default_random_seed")]
+    pub random_seed: u64,
+    #[serde(default)]
+    pub search_limits: Option<SearchLimits>,
+    #[serde(default)]
+    pub random_decks: bool,
+    #[serde(default = "TournamentOpts::default_games_per_pairing")]
+    pub games_per_pairing: u32,
+    #[serde(default = "TournamentOpts::default_max_steps_per_round")]
+    pub max_steps_per_round: u32,
+    #[serde(default)]
+    pub format: TournamentFormat,
+    #[serde(default)]
+    pub configs: Vec<EntryConfig>,
+    /// Run an SPRT between two of the configs instead of the tournament.
+    #[serde(default)]
+    pub sprt: Option<SprtOpts>,
+    /// Path to the JSON file of game results. Existing results are loaded and included in the ratings.
+    #[serde(default)]
+    pub results_path: Option<String>,
+}
+
+impl TournamentOpts {
+    fn default_random_seed() -> u64 {
+        100
+    }
+    fn default_games_per_pairing() -> u32 {
+        20
+    }
+    fn default_max_steps_per_round() -> u32 {
+        200
+    }
+}
+
+/// Results between two entries from the perspective of `a`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct PairResult {
+    pub a: String,
+    pub b: String,
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+impl PairResult {
+    fn stats(&self) -> MatchStats {
+        MatchStats {
+            wins: self.wins,
+            draws: self.draws,
+            losses: self.losses,
+        }
+    }
+}
+
+/// Accumulated game results of all tournaments.
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct TournamentResults {
+    pub pairs: Vec<PairResult>,
+    /// Number of batches played so far. Offsets the random seed so that later runs play new games.
+    #[serde(default)]
+    pub batches: u64,
+}
+
+impl TournamentResults {
+    pub fn load(path: &str) -> Result<Self, ParseCompareOptsError> {
+        Ok(serde_json::from_reader(BufReader::new(File::open(path)?))?)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), ParseCompareOptsError> {
+        Ok(serde_json::to_writer_pretty(BufWriter::new(File::create(path)?), self)?)
+    }
+
+    /// Results of `a` against `b`.
+    pub fn get(&self, a: &str, b: &str) -> MatchStats {
+        let mut stats = MatchStats::default();
+        for p in &self.pairs {
+            if p.a == a && p.b == b {
+                stats.add_in_place(&p.stats());
+            } else if p.a == b && p.b == a {
+                stats.add_in_place(&p.stats().flip());
+            }
+        }
+        stats
+    }
+
+    pub fn record(&mut self, a: &str, b: &str, stats: MatchStats) {
+        let (a, b, stats) = if a <= b { (a, b, stats) } else { (b, a, stats.flip()) };
+        let pair = match self.pairs.iter_mut().find(|p| p.a == a && p.b == b) {
+            Some(pair) => pair,
+            None => {
+                self.pairs.push(PairResult {
+                    a: a.to_string(),
+                    b: b.to_string(),
+                    wins: 0,
+                    draws: 0,
+                    losses: 0,
+                });
+                self.pairs.last_mut().expect("pair was just pushed")
+            }
+        };
+        pair.wins += stats.wins;
+        pair.draws += stats.draws;
+        pair.losses += stats.losses;
+    }
+
+    /// Names of all entries with results, in order of first appearance.
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = vec![];
+        for p in &self.pairs {
+            for name in [&p.a, &p.b] {
+                if !names.contains(name) {
+                    names.push(name.clone());
+                }
+            }
+        }
+        names
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Rating {
+    pub name: String,
+    pub games: u32,
+    pub score_rate: f32,
+    /// Elo relative to the reference entry
+    pub elo: f64,
+    /// Half-width of the 95% confidence interval
+    pub elo_ci: f64,
+}
+
+const ELO_PER_NAT: f64 = 400.0 / std::f64::consts::LN_10;
+
+fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
+
+fn elo_to_score(elo: f64) -> f64 {
+    logistic(elo / ELO_PER_NAT)
+}
+
+/// Invert a symmetric positive definite matrix by Gauss-Jordan elimination.
+fn invert(mut m: Vec<Vec<f64>>) -> Option<Vec<Vec<f64>>> {
+    let n = m.len();
+    let mut inv: Vec<Vec<f64>> = (0..n).map(|i| (0..n).map(|j| f64::from(i == j)).collect()).collect();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| m[i][col].abs().total_cmp(&m[j][col].abs()))?;
+        if m[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        m.swap(col, pivot);
+        inv.swap(col, pivot);
+        let d = m[col][col];
+        for j in 0..n {
+            m[col][j] /= d;
+            inv[col][j] /= d;
+        }
+        for i in 0..n {
+            if i == col {
+                continue;
+            }
+            let f = m[i][col];
+            for j in 0..n {
+                m[i][j] -= f * m[col][j];
+                inv[i][j] -= f * inv[col][j];
+            }
+        }
+    }
+    Some(inv)
+}
+
+/// Bradley-Terry ratings with draws counted as half a win for each side, and a prior of
+/// one virtual draw per pair that has played so that undefeated entries get a finite rating.
+/// Ratings and confidence intervals are relative to the first name.
+pub fn bradley_terry(names: &[String], results: &TournamentResults) -> Vec<Rating> {
+    let n = names.len();
+    // games[i][j]: games between i and j, wins[i][j]: points of i against j
+    let mut games = vec![vec![0.0; n]; n];
+    let mut wins = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            if i == j {
+                continue;
+            }
+            let stats = results.get(&names[i], &names[j]);
+            if stats.games() > 0 {
+                games[i][j] = stats.games() as f64 + 1.0;
+                wins[i][j] = stats.wins as f64 + 0.5 * stats.draws as f64 + 0.5;
+            }
+        }
+    }
+
+    // Minorization-maximization (Hunter, 2004) on gamma = exp(r)
+    let total_wins: Vec<f64> = wins.iter().map(|row| row.iter().sum()).collect();
+    let mut gamma = vec![1.0; n];
+    for _ in 0..10_000 {
+        let mut next: Vec<f64> = (0..n)
+            .map(|i| {
+                let denom: f64 = (0..n)
+                    .filter(|&j| games[i][j] > 0.0)
+                    .map(|j| games[i][j] / (gamma[i] + gamma[j]))
+                    .sum();
+                if denom > 0.0 {
+                    total_wins[i] / denom
+                } else {
+                    gamma[i]
+                }
+            })
+            .collect();
+        let norm = next[0];
+        next.iter_mut().for_each(|g| *g /= norm);
+        let delta = next
+            .iter()
+            .zip(&gamma)
+            .map(|(a, b)| (a.ln() - b.ln()).abs())
+            .fold(0.0, f64::max);
+        gamma = next;
+        if delta < 1e-9 {
+            break;
+        }
+    }
+    let r: Vec<f64> = gamma.iter().map(|g| g.ln()).collect();
+
+    // Standard errors from the Fisher information, with the reference rating fixed at 0
+    let mut info = vec![vec![0.0; n.saturating_sub(1)]; n.saturating_sub(1)];
+    for i in 1..n {
+        for j in 0..n {
+            if i == j || games[i][j] == 0.0 {
+                continue;
+            }
+            let p = logistic(r[i] - r[j]);
+            let w = games[i][j] * p * (1.0 - p);
+            info[i - 1][i - 1] += w;
+            if j > 0 {
+                info[i - 1][j - 1] -= w;
+            }
+        }
+    }
+    let cov = invert(info);
+    names
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let stats = names
+                .iter()
+                .filter(|other| *other != name)
+                .fold(MatchStats::default(), |mut s, other| {
+                    s.add_in_place(&results.get(name, other));
+                    s
+                });
+            let elo_ci = match (&cov, i) {
+                (_, 0) => 0.0,
+                (Some(cov), _) => 1.96 * cov[i - 1][i - 1].sqrt() * ELO_PER_NAT,
+                (None, _) => f64::INFINITY,
+            };
+            Rating {
+                name: name.clone(),
+                games: stats.games(),
+                score_rate: stats.score_rate(),
+                elo: r[i] * ELO_PER_NAT,
+                elo_ci,
+            }
+        })
+        .collect()
+}
+
+/// Log-likelihood ratio of H1 (Elo difference `elo1`) against H0 (`elo0`) for the results,
+/// using the normal approximation of the trinomial (win/draw/loss) distribution.
+pub fn sprt_llr(stats: MatchStats, elo0: f64, elo1: f64) -> f64 {
+    if stats.games() == 0 {
+        return 0.0;
+    }
+    // Empty outcomes are counted as half a game so that the variance is never zero
+    let [w, d, l] = [stats.wins, stats.draws, stats.losses].map(|x| if x == 0 { 0.5 } else { x as f64 });
+    let n = w + d + l;
+    let s = (w + 0.5 * d) / n;
+    let var = (w * (1.0 - s).powi(2) + d * (0.5 - s).powi(2) + l * s.powi(2)) / n;
+    let (s0, s1) = (elo_to_score(elo0), elo_to_score(elo1));
+    n * (s1 - s0) * (2.0 * s - s0 - s1) / (2.0 * var)
+}
+
+/// Swiss pairings for one round: entries sorted by score rate are paired with the next entry they
+/// have played the least. With an odd number of entries, the lowest ranked unpaired entry gets a bye.
+fn swiss_pairings(names: &[String], results: &TournamentResults) -> Vec<(usize, usize)> {
+    let score_rate = |i: usize| {
+        (0..names.len())
+            .filter(|&j| j != i)
+            .fold(MatchStats::default(), |mut s, j| {
+                s.add_in_place(&results.get(&names[i], &names[j]));
+                s
+            })
+            .score_rate()
+    };
+    let mut order: Vec<usize> = (0..names.len()).collect();
+    order.sort_by(|&i, &j| score_rate(j).total_cmp(&score_rate(i)));
+    let mut paired = HashSet::new();
+    let mut pairings = vec![];
+    for (k, &i) in order.iter().enumerate() {
+        if paired.contains(&i) {
+            continue;
+        }
+        let opponent = order[k + 1..]
+            .iter()
+            .copied()
+            .filter(|j| !paired.contains(j))
+            .min_by_key(|&j| results.get(&names[i], &names[j]).games());
+        if let Some(j) = opponent {
+            paired.insert(i);
+            paired.insert(j);
+            pairings.push((i, j));
+        }
+    }
+    pairings
+}
+
+pub fn parse_tournament_opts(json_path: &PathBuf) -> Result<TournamentOpts, ParseCompareOptsError> {
+    Ok(serde_json::from_reader(BufReader::new(File::open(json_path)?))?)
+}
+
+pub fn main_tournament(opts: TournamentOpts) -> Result<(), String> {
+    let parallel = opts.parallel;
+    let limits = opts.search_limits;
+    let names: Vec<String> = opts.configs.iter().map(|c| c.name.clone()).collect();
+    if names.iter().collect::<HashSet<_>>().len() != names.len() {
+        return Err("Config names must be unique.".to_string());
+    }
+    let entries = opts
+        .configs
+        .iter()
+        .map(|c| c.construct::<StandardNondetHandlerState>(parallel, limits))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    let mut results = match &opts.results_path {
+        Some(path) => TournamentResults::load(path).unwrap_or_default(),
+        None => TournamentResults::default(),
+    };
+    let play = |results: &mut TournamentResults, i: usize, j: usize, rounds: u32| {
+        println!("--- {} vs. {}", names[i], names[j]);
+        let decks = ByPlayer::new(&entries[i].0, &entries[j].0);
+        let (stats, _) = iterate_match_stats(
+            &|| ByPlayer::new((entries[i].1)(), (entries[j].1)()),
+            &|rng| standard_game(decks, rng, opts.random_decks),
+            IterateMatchOpts {
+                rounds,
+                steps: opts.max_steps_per_round,
+                // Different games for each batch, including the batches of previous runs
+                random_seed: opts.random_seed.wrapping_add(results.batches),
+                opening_book: None,
+            },
+        );
+        results.batches += 1;
+        results.record(&names[i], &names[j], stats);
+        if let Some(path) = &opts.results_path {
+            results.save(path).map_err(|e| e.to_string())?;
+        }
+        Ok::<_, String>(stats)
+    };
+
+    if let Some(sprt) = &opts.sprt {
+        let index = |name: &str| {
+            names
+                .iter()
+                .position(|n| n == name)
+                .ok_or_else(|| format!("SPRT: unknown config {name}"))
+        };
+        let (i, j) = (index(&sprt.candidate)?, index(&sprt.baseline)?);
+        let (lower, upper) = sprt.bounds();
+        loop {
+            let stats = results.get(&names[i], &names[j]);
+            let llr = sprt_llr(stats, sprt.elo0, sprt.elo1);
+            println!(
+                "SPRT [{}, {}]: {:?} LLR={llr:.3} ({lower:.3}, {upper:.3})",
+                sprt.elo0, sprt.elo1, stats
+            );
+            if llr >= upper {
+                println!("H1 accepted: {} is stronger than {}", names[i], names[j]);
+                break;
+            } else if llr <= lower {
+                println!("H0 accepted: {} is not stronger than {}", names[i], names[j]);
+                break;
+            } else if stats.games() >= sprt.max_games {
+                println!("Inconclusive after {} games", stats.games());
+                break;
+            }
+            play(&mut results, i, j, sprt.batch_games)?;
+        }
+    } else {
+        match opts.format {
+            TournamentFormat::RoundRobin => {
+                for i in 0..names.len() {
+                    for j in (i + 1)..names.len() {
+                        play(&mut results, i, j, opts.games_per_pairing)?;
+                    }
+                }
+            }
+            TournamentFormat::Swiss { rounds } => {
+                for round in 0..rounds {
+                    println!("=== Swiss round {}", round + 1);
+                    for (i, j) in swiss_pairings(&names, &results) {
+                        play(&mut results, i, j, opts.games_per_pairing)?;
+                    }
+                }
+            }
+        }
+    }
+
+    // Entries from previous runs are rated as well, relative to the first config
+    let mut rated = names.clone();
+    rated.extend(results.names().into_iter().filter(|n| !names.contains(n)));
+    let played = results.names();
+    rated.retain(|n| played.contains(n));
+    let mut ratings = bradley_terry(&rated, &results);
+    ratings.sort_by(|a, b| b.elo.total_cmp(&a.elo));
+    println!(
+        "{:20} {:>6} {:>7} {:>8} {:>8}",
+        "Name", "Games", "Score", "Elo", "95% CI"
+    );
+    for r in &ratings {
+        println!(
+            "{:20} {:6} {:6.1}% {:8.1} {:>8}",
+            r.name,
+            r.games,
+            100.0 * r.score_rate,
+            r.elo,
+            format!("±{:.1}", r.elo_ci)
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn stats(wins: u32, draws: u32, losses: u32) -> MatchStats {
+        MatchStats { wins, draws, losses }
+    }
+
+    #[test]
+    fn invert_2x2() {
+        let inv = invert(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).unwrap();
+        let expected = [[2.0 / 3.0, -1.0 / 3.0], [-1.0 / 3.0, 2.0 / 3.0]];
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((inv[i][j] - expected[i][j]).abs() < 1e-9);
+            }
+        }
+        assert!(invert(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).is_none());
+    }
+
+    #[test]
+    fn bradley_terry_symmetric_results_give_equal_ratings() {
+        let names = names(&["a", "b", "c"]);
+        let mut results = TournamentResults::default();
+        results.record("a", "b", stats(4, 2, 4));
+        results.record("b", "c", stats(3, 4, 3));
+        results.record("a", "c", stats(5, 0, 5));
+        let ratings = bradley_terry(&names, &results);
+        for r in &ratings {
+            assert!(r.elo.abs() < 1e-6, "{r:?}");
+            assert!(r.elo_ci.is_finite());
+        }
+        assert_eq!(0.0, ratings[0].elo_ci);
+    }
+
+    #[test]
+    fn bradley_terry_stronger_entry_rated_higher() {
+        let names = names(&["a", "b"]);
+        let mut results = TournamentResults::default();
+        results.record("a", "b", stats(2, 0, 8));
+        let ratings = bradley_terry(&names, &results);
+        assert!(ratings[1].elo > 0.0);
+    }
+
+    #[test]
+    fn sprt_llr_sign_follows_results() {
+        assert_eq!(0.0, sprt_llr(MatchStats::default(), 0.0, 20.0));
+        assert!(sprt_llr(stats(70, 0, 30), 0.0, 20.0) > 0.0);
+        assert!(sprt_llr(stats(30, 0, 70), 0.0, 20.0) < 0.0);
+    }
+
+    #[test]
+    fn swiss_pairings_give_a_bye_for_odd_entry_count() {
+        let names = names(&["a", "b", "c"]);
+        let pairings = swiss_pairings(&names, &TournamentResults::default());
+        assert_eq!(1, pairings.len());
+        let (i, j) = pairings[0];
+        assert_ne!(i, j);
+    }
+
+    #[test]
+    fn swiss_pairings_pair_every_entry_for_even_entry_count() {
+        let names = names(&["a", "b", "c", "d"]);
+        let mut results = TournamentResults::default();
+        results.record("a", "b", stats(1, 0, 0));
+        results.record("c", "d", stats(1, 0, 0));
+        let pairings = swiss_pairings(&names, &results);
+        assert_eq!(2, pairings.len());
+        let mut paired: Vec<usize> = pairings.iter().flat_map(|&(i, j)| [i, j]).collect();
+        paired.sort();
+        assert_eq!(vec![0, 1, 2, 3], paired);
+        // Winners are paired with each other instead of a rematch
+        assert!(pairings.contains(&(0, 2)));
+    }
+}
@@ -0,0 +1,293 @@
+use gitcg_sim::prelude::*;
+use gitcg_sim::rand::{rngs::SmallRng, Rng, SeedableRng};
+use gitcg_sim_cli_utils::{
+    cli_args::SearchConfig,
+    match_round::{iterate_match, IterateMatchOpts},
+};
+use gitcg_sim_search::SearchLimits;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+
+use crate::compare::{standard_game, DeckSrc, ParseCompareOptsError};
+
+/// A numeric field of `SearchConfig` to be tuned, e.g. `mcts_cpuct_init`.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TuneParameter {
+    pub name: String,
+    pub min: f64,
+    pub max: f64,
+    /// Starting value, defaults to the middle of the range.
+    #[serde(default)]
+    pub initial: Option<f64>,
+    /// Round to the nearest integer before applying.
+    #[serde(default)]
+    pub integer: bool,
+}
+
+impl TuneParameter {
+    fn value(&self, x: f64) -> f64 {
+        let v = self.min + x.clamp(0.0, 1.0) * (self.max - self.min);
+        if self.integer {
+            v.round()
+        } else {
+            v
+        }
+    }
+
+    fn normalized(&self, v: f64) -> f64 {
+        ((v - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+    }
+}
+
+/// Simultaneous perturbation stochastic approximation (SPSA) gains, in terms of the normalized parameters.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SpsaOpts {
+    /// Step size
+    #[serde(default = "SpsaOpts::default_a")]
+    pub a: f64,
+    /// Perturbation size
+    #[serde(default = "SpsaOpts::default_c")]
+    pub c: f64,
+    /// Stability constant, usually ~10% of the iterations
+    #[serde(default = "SpsaOpts::default_big_a", rename = "A")]
+    pub big_a: f64,
+    #[serde(default = "SpsaOpts::default_alpha")]
+    pub alpha: f64,
+    #[serde(default = "SpsaOpts::default_gamma")]
+    pub gamma: f64,
+}
+
+impl SpsaOpts {
+    fn default_a() -> f64 {
+        0.5
+    }
+    fn default_c() -> f64 {
+        0.1
+    }
+    fn default_big_a() -> f64 {
+        5.0
+    }
+    fn default_alpha() -> f64 {
+        0.602
+    }
+    fn default_gamma() -> f64 {
+        0.101
+    }
+
+    fn gains(&self, k: u32) -> (f64, f64) {
+        let k = k as f64 + 1.0;
+        (self.a / (k + self.big_a).powf(self.alpha), self.c / k.powf(self.gamma))
+    }
+}
+
+impl Default for SpsaOpts {
+    fn default() -> Self {
+        Self {
+            a: Self::default_a(),
+            c: Self::default_c(),
+            big_a: Self::default_big_a(),
+            alpha: Self::default_alpha(),
+            gamma: Self::default_gamma(),
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct TuneOpts {
+    #[serde(default)]
+    pub parallel: bool,
+    #[serde(default = "TuneOpts::default_random_seed")]
+    pub random_seed: u64,
+    #[serde(default)]
+    pub search_limits: Option<SearchLimits>,
+    #[serde(default)]
+    pub random_decks: bool,
+    /// Deck used by both players unless `random_decks` is set.
+    pub deck: DeckSrc,
+    /// Configuration the parameters are applied to.
+    pub base_config: SearchConfig,
+    pub parameters: Vec<TuneParameter>,
+    #[serde(default = "TuneOpts::default_iterations")]
+    pub iterations: u32,
+    /// Games between the two perturbed configurations per iteration.
+    #[serde(default = "TuneOpts::default_games_per_iteration")]
+    pub games_per_iteration: u32,
+    #[serde(default = "TuneOpts::default_max_steps_per_round")]
+    pub max_steps_per_round: u32,
+    #[serde(default)]
+    pub spsa: SpsaOpts,
+    /// Path to save the tuning state to after each iteration. An existing checkpoint is resumed from.
+    #[serde(default)]
+    pub checkpoint: Option<String>,
+    /// Games between the tuned and the base configuration for the final report.
+    #[serde(default)]
+    pub verification_games: u32,
+}
+
+impl TuneOpts {
+    fn default_random_seed() -> u64 {
+        100
+    }
+    fn default_iterations() -> u32 {
+        50
+    }
+    fn default_games_per_iteration() -> u32 {
+        20
+    }
+    fn default_max_steps_per_round() -> u32 {
+        200
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct TuneIteration {
+    pub iteration: u32,
+    /// Values of the parameters after the iteration
+    pub values: Vec<f64>,
+    /// Score rate of the positively perturbed configuration against the negatively perturbed one
+    pub score_rate: f32,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct TuneCheckpoint {
+    /// Normalized parameters
+    pub theta: Vec<f64>,
+    pub history: Vec<TuneIteration>,
+}
+
+pub fn parse_tune_opts(json_path: &PathBuf) -> Result<TuneOpts, ParseCompareOptsError> {
+    Ok(serde_json::from_reader(BufReader::new(File::open(json_path)?))?)
+}
+
+/// Set the tuned fields on top of `base` through its serialized form, so that any numeric field can be tuned.
+fn apply_parameters(base: &SearchConfig, parameters: &[TuneParameter], theta: &[f64]) -> Result<SearchConfig, String> {
+    let mut value = serde_json::to_value(base).map_err(|e| e.to_string())?;
+    let fields = value.as_object_mut().ok_or("SearchConfig is not an object")?;
+    for (p, &x) in parameters.iter().zip(theta) {
+        let v = p.value(x);
+        let v = if p.integer {
+            serde_json::Value::from(v as i64)
+        } else {
+            serde_json::Value::from(v)
+        };
+        fields.insert(p.name.clone(), v);
+    }
+    serde_json::from_value(value).map_err(|e| format!("invalid parameters: {e}"))
+}
+
+fn load_checkpoint(path: &str) -> Option<TuneCheckpoint> {
+    serde_json::from_reader(BufReader::new(File::open(path).ok()?)).ok()
+}
+
+fn save_checkpoint(path: &str, checkpoint: &TuneCheckpoint) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| e.to_string())?;
+    serde_json::to_writer_pretty(BufWriter::new(file), checkpoint).map_err(|e| e.to_string())
+}
+
+pub fn main_tune(opts: TuneOpts) -> Result<(), String> {
+    let parameters = &opts.parameters;
+    if parameters.is_empty() {
+        return Err("No parameters to tune.".to_string());
+    }
+    if let Some(p) = parameters.iter().find(|p| p.min >= p.max) {
+        return Err(format!("{}: min must be less than max", p.name));
+    }
+    let decklist = opts.deck.decklist().map_err(|e| e.to_string())?;
+    let decks = ByPlayer(&decklist, &decklist);
+    let mut checkpoint = match opts.checkpoint.as_deref().and_then(load_checkpoint) {
+        Some(checkpoint) if checkpoint.theta.len() == parameters.len() => {
+            println!("Resuming from iteration {}", checkpoint.history.len());
+            checkpoint
+        }
+        _ => TuneCheckpoint {
+            theta: parameters
+                .iter()
+                .map(|p| p.initial.map(|v| p.normalized(v)).unwrap_or(0.5))
+                .collect(),
+            history: vec![],
+        },
+    };
+    // Validate the parameter names before playing any games
+    apply_parameters(&opts.base_config, parameters, &checkpoint.theta)?;
+
+    let play = |a: &SearchConfig, b: &SearchConfig, rounds: u32, random_seed: u64| {
+        let (_, score_rate, _) = iterate_match(
+            &|| {
+                ByPlayer(
+                    a.make_search::<StandardNondetHandlerState>(opts.parallel, opts.search_limits),
+                    b.make_search(opts.parallel, opts.search_limits),
+                )
+            },
+            &|rng| standard_game(decks, rng, opts.random_decks),
+            IterateMatchOpts {
+                rounds,
+                steps: opts.max_steps_per_round,
+                random_seed,
+                opening_book: None,
+            },
+        );
+        score_rate
+    };
+
+    let start = checkpoint.history.len() as u32;
+    let mut rng = SmallRng::seed_from_u64(opts.random_seed.wrapping_add(start as u64));
+    for k in start..opts.iterations {
+        let (a_k, c_k) = opts.spsa.gains(k);
+        let delta: Vec<f64> = parameters
+            .iter()
+            .map(|_| if rng.gen::<bool>() { 1.0 } else { -1.0 })
+            .collect();
+        let perturbed = |sign: f64| -> Vec<f64> {
+            checkpoint
+                .theta
+                .iter()
+                .zip(&delta)
+                .map(|(t, d)| (t + sign * c_k * d).clamp(0.0, 1.0))
+                .collect()
+        };
+        let plus = apply_parameters(&opts.base_config, parameters, &perturbed(1.0))?;
+        let minus = apply_parameters(&opts.base_config, parameters, &perturbed(-1.0))?;
+        let score_rate = play(&plus, &minus, opts.games_per_iteration, rng.gen());
+        // Score difference of the two configurations in [-1, 1]
+        let diff = 2.0 * score_rate as f64 - 1.0;
+        for (t, d) in checkpoint.theta.iter_mut().zip(&delta) {
+            *t = (*t + a_k * diff / (2.0 * c_k * d)).clamp(0.0, 1.0);
+        }
+        let values: Vec<f64> = parameters
+            .iter()
+            .zip(&checkpoint.theta)
+            .map(|(p, &x)| p.value(x))
+            .collect();
+        println!(
+            "{}",
+            serde_json::json!({ "iteration": k, "score_rate": score_rate, "values": values })
+        );
+        checkpoint.history.push(TuneIteration {
+            iteration: k,
+            values,
+            score_rate,
+        });
+        if let Some(path) = &opts.checkpoint {
+            save_checkpoint(path, &checkpoint)?;
+        }
+    }
+
+    let tuned = apply_parameters(&opts.base_config, parameters, &checkpoint.theta)?;
+    println!("Tuned parameters:");
+    for (p, &x) in parameters.iter().zip(&checkpoint.theta) {
+        println!("  {:32} {:10.4}  (range {} - {})", p.name, p.value(x), p.min, p.max);
+    }
+    println!(
+        "Tuned search_config: {}",
+        serde_json::to_string(&tuned).map_err(|e| e.to_string())?
+    );
+    if opts.verification_games > 0 {
+        let score_rate = play(&tuned, &opts.base_config, opts.verification_games, opts.random_seed);
+        println!(
+            "Tuned vs. base: score rate {score_rate:.3} over {} games",
+            opts.verification_games
+        );
+    }
+    Ok(())
+}
@@ -3,6 +3,7 @@ use std::{
     io::{self, BufRead},
     path::PathBuf,
     str::FromStr,
+    sync::Arc,
 };
 use structopt::StructOpt;
 
@@ -17,10 +18,16 @@ use gitcg_sim_search::{
     mcts::{policy::RuleBasedPuct, CpuctConfig, MCTSConfig, MCTS},
     minimax::{
         search::{STATIC_SEARCH_MAX_ITERS, TACTICAL_SEARCH_DEPTH, TARGET_ROUND_DELTA},
-        MinimaxConfig, MinimaxSearch,
+        EndgameConfig, MinimaxConfig, MinimaxSearch,
     },
-    training::policy::{search::PolicyNetworkBasedSearch, PolicyNetwork},
-    Game, GameTreeSearch, SearchLimits, SearchResult,
+    opening_book::OpeningBookSearch,
+    time_manager::{TimeControl, TimeManagedSearch},
+    training::{
+        policy::{search::PolicyNetworkBasedSearch, PolicyNetwork},
+        policy_value::PolicyValueNetwork,
+    },
+    AnalysisLine, Evaluator, Game, GameTreeSearch, HeuristicEvaluator, SearchLimits, SearchObserver, SearchProgress,
+    SearchResult, StopSignal,
 };
 
 fn read_decklist_from_file(file: File) -> Result<Decklist, io::Error> {
@@ -57,6 +64,27 @@ impl FromStr for SearchAlgorithm {
     }
 }
 
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EvaluatorKind {
+    Heuristic,
+    Linear,
+    PolicyValue,
+}
+
+impl FromStr for EvaluatorKind {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "heuristic" => Ok(Self::Heuristic),
+            "linear" => Ok(Self::Linear),
+            "policy-value" => Ok(Self::PolicyValue),
+            _ => Err(""),
+        }
+    }
+}
+
 #[derive(Debug, StructOpt, Clone, Default)]
 #[cfg_attr(
     feature = "serde",
@@ -88,6 +116,28 @@ pub struct SearchConfig {
     #[cfg_attr(feature = "serde", serde(default))]
     pub static_search_iters: Option<u8>,
 
+    #[structopt(
+        long = "--endgame-max-size",
+        help = "Minimax: max. position size estimate for solving endgames exactly, 0 to disable"
+    )]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub endgame_max_size: Option<u32>,
+
+    #[structopt(
+        long = "--evaluator",
+        help = "heuristic|linear|policy-value, Minimax/MCTS: static evaluation function"
+    )]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub evaluator: Option<EvaluatorKind>,
+
+    #[structopt(
+        parse(from_os_str),
+        long = "--evaluator-weights",
+        help = "Path to the weights of the linear (JSON) or policy-value (GCPV) evaluator"
+    )]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub evaluator_weights: Option<PathBuf>,
+
     #[structopt(
         short = "C",
         long = "--mcts-c",
@@ -135,6 +185,14 @@ pub struct SearchConfig {
     #[cfg_attr(feature = "serde", serde(default))]
     pub mcts_policy_npz_path: Option<PathBuf>,
 
+    #[structopt(
+        parse(from_os_str),
+        long = "--policy-weights",
+        help = "Path to policy network weights to use instead of the hard-coded model"
+    )]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub policy_weights: Option<PathBuf>,
+
     #[structopt(long = "--mcts-use-policy-network", help = "MCTS: Use hard-coded policy network")]
     #[cfg_attr(feature = "serde", serde(default))]
     pub mcts_use_policy_network: bool,
@@ -162,6 +220,20 @@ pub struct SearchConfig {
     #[cfg_attr(feature = "serde", serde(default))]
     pub max_positions: Option<u64>,
 
+    #[structopt(
+        long = "--match-time-ms",
+        help = "Total time per player for the whole match in milliseconds. Overrides the time limit per move."
+    )]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub match_time_ms: Option<u128>,
+
+    #[structopt(
+        long = "--increment-ms",
+        help = "Time added to the match clock after each move in milliseconds"
+    )]
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub increment_ms: Option<u128>,
+
     #[structopt(long = "--tt-size-mb", help = "Transposition table size")]
     #[cfg_attr(feature = "serde", serde(default))]
     pub tt_size_mb: Option<u32>,
@@ -271,14 +343,18 @@ impl SearchOpts {
     }
 }
 
+pub type DynEvaluator<S> = Arc<dyn Evaluator<GameStateWrapper<S>>>;
+
 pub enum GenericSearch<S: NondetState = StandardNondetHandlerState> {
     Minimax(MinimaxSearch<GameStateWrapper<S>>),
-    MCTS(MCTS<GameStateWrapper<S>>),
-    MCTSRuleBasedPolicy(MCTS<GameStateWrapper<S>, gitcg_sim_search::mcts::policy::DefaultEvalPolicy, RuleBasedPuct>),
-    MCTSPolicy(MCTS<GameStateWrapper<S>, gitcg_sim_search::mcts::policy::DefaultEvalPolicy, PolicyNetwork>),
+    MCTS(MCTS<GameStateWrapper<S>, DynEvaluator<S>>),
+    MCTSRuleBasedPolicy(MCTS<GameStateWrapper<S>, DynEvaluator<S>, RuleBasedPuct>),
+    MCTSPolicy(MCTS<GameStateWrapper<S>, DynEvaluator<S>, PolicyNetwork>),
     RuleBasedSearch(RuleBasedSearch),
     PolicyBasedSearch(PolicyNetworkBasedSearch<SmallRng>),
     Random,
+    TimeManaged(Box<TimeManagedSearch<GameStateWrapper<S>, GenericSearch<S>>>),
+    OpeningBook(Box<OpeningBookSearch<GameStateWrapper<S>, GenericSearch<S>>>),
 }
 
 fn random_search<S: NondetState>(position: &GameStateWrapper<S>) -> SearchResult<GameStateWrapper<S>> {
@@ -306,12 +382,149 @@ impl<S: NondetState> GameTreeSearch<GameStateWrapper<S>> for GenericSearch<S> {
             Self::RuleBasedSearch(s) => s.search(position, maximize_player),
             Self::PolicyBasedSearch(s) => s.search(position, maximize_player),
             Self::Random => random_search(position),
+            Self::TimeManaged(s) => s.search(position, maximize_player),
+            Self::OpeningBook(s) => s.search(position, maximize_player),
+        }
+    }
+
+    fn search_with_observer(
+        &mut self,
+        position: &GameStateWrapper<S>,
+        maximize_player: PlayerId,
+        observer: &mut dyn SearchObserver<GameStateWrapper<S>>,
+    ) -> SearchResult<GameStateWrapper<S>> {
+        match self {
+            Self::Minimax(s) => s.search_with_observer(position, maximize_player, observer),
+            Self::MCTS(s) => s.search_with_observer(position, maximize_player, observer),
+            Self::MCTSRuleBasedPolicy(s) => s.search_with_observer(position, maximize_player, observer),
+            Self::MCTSPolicy(s) => s.search_with_observer(position, maximize_player, observer),
+            Self::RuleBasedSearch(s) => s.search_with_observer(position, maximize_player, observer),
+            Self::PolicyBasedSearch(s) => s.search_with_observer(position, maximize_player, observer),
+            Self::Random => {
+                let res = random_search(position);
+                observer.on_progress(&SearchProgress::from_result(&res, 0));
+                res
+            }
+            Self::TimeManaged(s) => s.search_with_observer(position, maximize_player, observer),
+            Self::OpeningBook(s) => s.search_with_observer(position, maximize_player, observer),
+        }
+    }
+
+    fn stop_signal(&self) -> Option<StopSignal> {
+        match self {
+            Self::Minimax(s) => s.stop_signal(),
+            Self::MCTS(s) => s.stop_signal(),
+            Self::MCTSRuleBasedPolicy(s) => s.stop_signal(),
+            Self::MCTSPolicy(s) => s.stop_signal(),
+            Self::TimeManaged(s) => s.stop_signal(),
+            Self::OpeningBook(s) => s.stop_signal(),
+            Self::RuleBasedSearch(..) | Self::PolicyBasedSearch(..) | Self::Random => None,
+        }
+    }
+
+    fn analyze(
+        &mut self,
+        position: &GameStateWrapper<S>,
+        maximize_player: PlayerId,
+        multi_pv: usize,
+    ) -> Vec<AnalysisLine<GameStateWrapper<S>>> {
+        match self {
+            Self::Minimax(s) => s.analyze(position, maximize_player, multi_pv),
+            Self::MCTS(s) => s.analyze(position, maximize_player, multi_pv),
+            Self::MCTSRuleBasedPolicy(s) => s.analyze(position, maximize_player, multi_pv),
+            Self::MCTSPolicy(s) => s.analyze(position, maximize_player, multi_pv),
+            Self::RuleBasedSearch(s) => s.analyze(position, maximize_player, multi_pv),
+            Self::PolicyBasedSearch(s) => s.analyze(position, maximize_player, multi_pv),
+            Self::Random => vec![AnalysisLine::from_result(&random_search(position))],
+            Self::TimeManaged(s) => s.analyze(position, maximize_player, multi_pv),
+            Self::OpeningBook(s) => s.analyze(position, maximize_player, multi_pv),
+        }
+    }
+
+    fn set_limits(&mut self, limits: Option<SearchLimits>) {
+        match self {
+            Self::Minimax(s) => s.set_limits(limits),
+            Self::MCTS(s) => s.set_limits(limits),
+            Self::MCTSRuleBasedPolicy(s) => s.set_limits(limits),
+            Self::MCTSPolicy(s) => s.set_limits(limits),
+            Self::TimeManaged(s) => s.set_limits(limits),
+            Self::OpeningBook(s) => s.set_limits(limits),
+            Self::RuleBasedSearch(..) | Self::PolicyBasedSearch(..) | Self::Random => {}
         }
     }
 }
 
 impl SearchConfig {
     pub fn make_search<S: NondetState>(&self, parallel: bool, limits: Option<SearchLimits>) -> GenericSearch<S> {
+        let search = self.make_search_without_time_control(parallel, limits);
+        match self.time_control() {
+            Some(control) => GenericSearch::TimeManaged(Box::new(TimeManagedSearch::new(search, control))),
+            None => search,
+        }
+    }
+
+    pub fn endgame_config(&self) -> Option<EndgameConfig> {
+        match self.endgame_max_size {
+            Some(0) => None,
+            Some(max_size) => Some(EndgameConfig {
+                max_size,
+                ..Default::default()
+            }),
+            None => Some(Default::default()),
+        }
+    }
+
+    pub fn time_control(&self) -> Option<TimeControl> {
+        self.match_time_ms.map(|total_time_ms| TimeControl {
+            total_time_ms,
+            increment_ms: self.increment_ms.unwrap_or_default(),
+        })
+    }
+
+    /// The policy network loaded from `--policy-weights`, or the hard-coded one.
+    /// Panics if the weights cannot be loaded.
+    pub fn policy_network(&self) -> PolicyNetwork {
+        match &self.policy_weights {
+            Some(path) => PolicyNetwork::from_weights_file(path).expect("Failed to load policy network weights."),
+            None => PolicyNetwork::new_hard_coded(),
+        }
+    }
+
+    /// Construct the evaluator selected by `--evaluator`.
+    /// Panics if the linear or policy-value evaluator weights cannot be loaded.
+    pub fn make_evaluator<S: NondetState>(&self) -> DynEvaluator<S> {
+        match self.evaluator.unwrap_or(EvaluatorKind::Heuristic) {
+            EvaluatorKind::Heuristic => Arc::new(HeuristicEvaluator),
+            EvaluatorKind::Linear => {
+                let Some(path) = &self.evaluator_weights else {
+                    panic!("The linear evaluator requires --evaluator-weights.")
+                };
+                #[cfg(feature = "serde")]
+                {
+                    Arc::new(
+                        gitcg_sim_search::evaluator::LinearEvaluator::load(path)
+                            .expect("Failed to load evaluator weights."),
+                    )
+                }
+                #[cfg(not(feature = "serde"))]
+                {
+                    panic!("Loading {path:?} requires the serde feature.")
+                }
+            }
+            EvaluatorKind::PolicyValue => {
+                let Some(path) = &self.evaluator_weights else {
+                    panic!("The policy-value evaluator requires --evaluator-weights.")
+                };
+                Arc::new(PolicyValueNetwork::load(path).expect("Failed to load policy-value network weights."))
+            }
+        }
+    }
+
+    fn make_search_without_time_control<S: NondetState>(
+        &self,
+        parallel: bool,
+        limits: Option<SearchLimits>,
+    ) -> GenericSearch<S> {
         match self.algorithm.unwrap_or(SearchAlgorithm::Minimax) {
             SearchAlgorithm::Minimax => {
                 let config = MinimaxConfig {
@@ -325,8 +538,9 @@ impl SearchConfig {
                     tactical_depth: self.tactical_depth.unwrap_or(TACTICAL_SEARCH_DEPTH),
                     target_round_delta: self.target_round_delta.unwrap_or(TARGET_ROUND_DELTA),
                     static_search_max_iters: self.static_search_iters.unwrap_or(STATIC_SEARCH_MAX_ITERS),
+                    endgame: self.endgame_config(),
                 };
-                GenericSearch::Minimax(MinimaxSearch::new(config))
+                GenericSearch::Minimax(MinimaxSearch::new_with_evaluator(config, self.make_evaluator()))
             }
             SearchAlgorithm::MCTS => {
                 let config = MCTSConfig {
@@ -339,20 +553,21 @@ impl SearchConfig {
                     random_playout_bias: self.mcts_random_playout_bias,
                     policy_bias: self.mcts_policy_bias,
                     debug: self.debug,
+                    progress_iters: None,
                 };
                 if self.mcts_use_rule_based_policy {
                     let selection_policy = Default::default();
                     return GenericSearch::MCTSRuleBasedPolicy(MCTS::new_with_eval_policy_and_selection_policy(
                         config,
-                        Default::default(),
+                        self.make_evaluator(),
                         selection_policy,
                     ));
                 }
                 if self.mcts_use_policy_network {
-                    let selection_policy = PolicyNetwork::new_hard_coded();
+                    let selection_policy = self.policy_network();
                     return GenericSearch::MCTSPolicy(MCTS::new_with_eval_policy_and_selection_policy(
                         config,
-                        Default::default(),
+                        self.make_evaluator(),
                         selection_policy,
                     ));
                 }
@@ -361,16 +576,16 @@ impl SearchConfig {
                     let selection_policy = PolicyNetwork::from_npz(npz_path).expect("Failed to load .npz.");
                     return GenericSearch::MCTSPolicy(MCTS::new_with_eval_policy_and_selection_policy(
                         config,
-                        Default::default(),
+                        self.make_evaluator(),
                         selection_policy,
                     ));
                 }
-                GenericSearch::MCTS(MCTS::new(config))
+                GenericSearch::MCTS(MCTS::new_with_eval_policy(config, self.make_evaluator()))
             }
             SearchAlgorithm::PolicyBased => GenericSearch::PolicyBasedSearch(PolicyNetworkBasedSearch::new(
                 SmallRng::from_entropy(),
                 self.policy_based_bias,
-                PolicyNetwork::new_hard_coded(),
+                self.policy_network(),
             )),
             SearchAlgorithm::RuleBased => GenericSearch::RuleBasedSearch(RuleBasedSearch::new(Default::default())),
             SearchAlgorithm::Random => GenericSearch::Random,
@@ -1 +1,4 @@
 pub mod cli_args;
+
+/// Playing matches between searches, shared by the benchmark and the self-play trainers
+pub mod match_round;
@@ -0,0 +1,392 @@
+use gitcg_sim::rand::prelude::*;
+use gitcg_sim_search::{opening_book::OpeningBook, GameTreeSearch, SearchCounter, SearchResult};
+use instant::Duration;
+use rayon::prelude::*;
+use std::{
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Mutex,
+    },
+    time::Instant,
+};
+
+use gitcg_sim::prelude::*;
+
+pub fn match_round<S: NondetState, T: GameTreeSearch<GameStateWrapper<S>>>(
+    initial_state: GameStateWrapper<S>,
+    searches: &mut ByPlayer<T>,
+    steps: u32,
+    opening_book: Option<&Mutex<OpeningBook<Input>>>,
+) -> (Option<PlayerId>, Duration, SearchCounter) {
+    let mut game = initial_state;
+    let t0 = Instant::now();
+    let mut total_counter = SearchCounter::default();
+    let key_book = opening_book.map(|book| OpeningBook::<Input>::new(book.lock().unwrap().max_round));
+    let mut book_moves = vec![];
+    for _ in 0..steps {
+        if game.winner().is_some() {
+            break;
+        }
+
+        let p = game.to_move().unwrap();
+        let search = &mut searches[p];
+        let SearchResult {
+            pv,
+            eval: _,
+            counter: c,
+        } = search.search_hidden(&game, p);
+        total_counter.add_in_place(&c);
+        if pv.is_empty() {
+            println!("perform_match: PV is empty.");
+            break;
+        }
+        let input = pv.head().unwrap();
+        if let Some(key) = key_book.as_ref().and_then(|book| book.key(&game)) {
+            book_moves.push((key, input));
+        }
+        if let Err(e) = game.advance(input) {
+            println!("----------");
+            println!("Error: {e:?}");
+            println!("Input: {input:?}");
+            println!("Game state: {game:?}");
+            println!("----------");
+            println!();
+            break;
+        };
+    }
+
+    if let Some(book) = opening_book {
+        if game.winner().is_some() {
+            book.lock().unwrap().record_game(&book_moves, game.winner());
+        }
+    }
+    (game.winner(), t0.elapsed(), total_counter)
+}
+
+pub fn trace_search<S: NondetState, T: GameTreeSearch<GameStateWrapper<S>>>(
+    game: &GameStateWrapper<S>,
+    searches: &mut ByPlayer<T>,
+    steps: u32,
+) -> (u128, SearchCounter) {
+    let mut game = game.clone();
+    let mut total_counter = SearchCounter::default();
+    let mut total_time: u128 = 0;
+    for i in 0..steps {
+        let t1 = Instant::now();
+        if let Some(winner) = game.winner() {
+            println!("Winner: {winner:?}");
+            break;
+        }
+        let p = game.to_move().unwrap();
+        let search = searches.get_mut(p);
+        let SearchResult {
+            pv,
+            eval: v,
+            counter: c,
+        } = search.search_hidden(&game, p);
+        total_counter.add_in_place(&c);
+        let input = pv.head().expect("PV is empty");
+        let dt_ns = t1.elapsed().as_nanos();
+        total_time += dt_ns;
+        match game.advance(input) {
+            Err(e) => {
+                println!("DispatchError (to_move_player={:?}, input={input:?}):", game.to_move());
+                println!("{game:?}");
+                println!();
+                println!("pv={pv:?} v={v:?}");
+                println!("available_actions:");
+                game.actions().into_iter().for_each(|x| println!(" - {x:?}"));
+                panic!("trace_search: failed: {e:?} {input:?}");
+            }
+            _ => {
+                println!("--> {i:2} {input:?} | {v:?} | {} | {c:?}", c.summary(dt_ns));
+            }
+        }
+    }
+    (total_time, total_counter)
+}
+
+#[derive(Default)]
+pub struct IterateMatchOpts<'a> {
+    pub rounds: u32,
+    pub steps: u32,
+    pub random_seed: u64,
+    /// Record the moves of finished games into this opening book.
+    pub opening_book: Option<&'a Mutex<OpeningBook<Input>>>,
+}
+
+/// Win/draw/loss counts from the perspective of the first search returned by `make_search`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatchStats {
+    pub wins: u32,
+    pub draws: u32,
+    pub losses: u32,
+}
+
+impl MatchStats {
+    pub fn games(&self) -> u32 {
+        self.wins + self.draws + self.losses
+    }
+
+    /// 2 points per win and 1 point per draw.
+    pub fn score(&self) -> i32 {
+        (2 * self.wins + self.draws) as i32
+    }
+
+    /// Score rate in [0, 1].
+    pub fn score_rate(&self) -> f32 {
+        (self.score() as f32) / ((2 * self.games().max(1)) as f32)
+    }
+
+    pub fn add_in_place(&mut self, other: &MatchStats) {
+        self.wins += other.wins;
+        self.draws += other.draws;
+        self.losses += other.losses;
+    }
+
+    /// Results from the perspective of the opponent.
+    pub fn flip(&self) -> MatchStats {
+        MatchStats {
+            wins: self.losses,
+            draws: self.draws,
+            losses: self.wins,
+        }
+    }
+
+    /// Result of a single game for `player_id`.
+    pub fn from_winner(winner: Option<PlayerId>, player_id: PlayerId) -> MatchStats {
+        match winner {
+            Some(w) => Self::from_score(if w == player_id { 2 } else { 0 }),
+            None => Self::from_score(1),
+        }
+    }
+
+    fn from_score(d_score: i32) -> MatchStats {
+        match d_score {
+            2 => MatchStats {
+                wins: 1,
+                ..Default::default()
+            },
+            1 => MatchStats {
+                draws: 1,
+                ..Default::default()
+            },
+            _ => MatchStats {
+                losses: 1,
+                ..Default::default()
+            },
+        }
+    }
+}
+
+pub fn iterate_match<
+    S: NondetState,
+    T: GameTreeSearch<GameStateWrapper<S>>,
+    M: Send + Sync + Fn() -> ByPlayer<T>,
+    G: Send + Sync + Fn(SmallRng) -> GameStateWrapper<S>,
+>(
+    make_search: &M,
+    game: &G,
+    opts: IterateMatchOpts,
+) -> (i32, f32, SearchCounter) {
+    let (stats, total_counter) = iterate_match_stats(make_search, game, opts);
+    (stats.score(), stats.score_rate(), total_counter)
+}
+
+/// Same as `iterate_match`, but returns the win/draw/loss counts.
+pub fn iterate_match_stats<
+    S: NondetState,
+    T: GameTreeSearch<GameStateWrapper<S>>,
+    M: Send + Sync + Fn() -> ByPlayer<T>,
+    G: Send + Sync + Fn(SmallRng) -> GameStateWrapper<S>,
+>(
+    make_search: &M,
+    game: &G,
+    opts: IterateMatchOpts,
+) -> (MatchStats, SearchCounter) {
+    let IterateMatchOpts {
+        rounds,
+        random_seed,
+        steps,
+        opening_book,
+    } = opts;
+    let matches_started = AtomicI32::default();
+    (0..rounds)
+        .into_par_iter()
+        .map(|_| {
+            let i = matches_started.fetch_add(1, Ordering::SeqCst);
+            let flip = i % 2 == 0;
+            let mut search = make_search();
+            if flip {
+                std::mem::swap(&mut search.0, &mut search.1);
+            }
+            let rng = SmallRng::seed_from_u64(random_seed.wrapping_add(2).overflowing_mul(i as u64).0);
+            let game = game(rng);
+
+            println!("+ Round {:3}", i + 1);
+            let (winner, dt, c) = match_round(game, &mut search, steps, opening_book);
+            let (winner_str, d_score) = winner_value(winner, flip);
+            println!(
+                "- Round {:3} ... {winner_str} dt={:6.2}ms, states_visited={:8}",
+                i + 1,
+                dt.as_millis(),
+                c.states_visited
+            );
+            (MatchStats::from_score(d_score), c)
+        })
+        .reduce(
+            || (Default::default(), Default::default()),
+            |(mut s, mut c), (s1, c1)| {
+                s.add_in_place(&s1);
+                c.add_in_place(&c1);
+                (s, c)
+            },
+        )
+}
+
+/// Results of paired games: both games of a pair start with the same decks and RNG streams,
+/// with the searches swapping sides.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PairedMatchStats {
+    /// Results of the first search
+    pub stats: MatchStats,
+    /// Number of pairs by the points of the first search over both games: 0, 1/2, 1, 3/2 and 2
+    pub pair_scores: [u32; 5],
+}
+
+impl PairedMatchStats {
+    pub fn pairs(&self) -> u32 {
+        self.pair_scores.iter().sum()
+    }
+
+    fn differences(&self) -> impl Iterator<Item = (f64, u32)> + '_ {
+        self.pair_scores
+            .iter()
+            .enumerate()
+            .map(|(k, &count)| (k as f64 / 4.0 - 0.5, count))
+    }
+
+    /// Mean over the pairs of the score rate of the first search minus 1/2.
+    pub fn mean_difference(&self) -> f64 {
+        let n = self.pairs().max(1) as f64;
+        self.differences().map(|(d, count)| d * count as f64).sum::<f64>() / n
+    }
+
+    /// Standard error of `mean_difference`.
+    pub fn std_error(&self) -> f64 {
+        let n = self.pairs() as f64;
+        if n < 2.0 {
+            return f64::INFINITY;
+        }
+        let mean = self.mean_difference();
+        let var = self
+            .differences()
+            .map(|(d, count)| (d - mean).powi(2) * count as f64)
+            .sum::<f64>()
+            / (n - 1.0);
+        (var / n).sqrt()
+    }
+
+    /// 95% confidence interval of `mean_difference`.
+    pub fn confidence_interval(&self) -> (f64, f64) {
+        let (mean, half) = (self.mean_difference(), 1.96 * self.std_error());
+        (mean - half, mean + half)
+    }
+
+    pub fn add_in_place(&mut self, other: &PairedMatchStats) {
+        self.stats.add_in_place(&other.stats);
+        for (a, b) in self.pair_scores.iter_mut().zip(other.pair_scores) {
+            *a += b;
+        }
+    }
+}
+
+/// Play `opts.rounds` pairs of games. For each pair, the decks and the RNG streams for each player's card
+/// draws and dice rolls are generated once, and both games are played with them, with the searches
+/// (along with their decks and RNG streams) swapping sides for the second game.
+pub fn iterate_paired_match<
+    T: GameTreeSearch<GameStateWrapper>,
+    M: Send + Sync + Fn() -> ByPlayer<T>,
+    D: Send + Sync + Fn(&mut SmallRng) -> ByPlayer<Decklist>,
+>(
+    make_search: &M,
+    decks: &D,
+    opts: IterateMatchOpts,
+) -> (PairedMatchStats, SearchCounter) {
+    let IterateMatchOpts {
+        rounds,
+        random_seed,
+        steps,
+        opening_book,
+    } = opts;
+    (0..rounds)
+        .into_par_iter()
+        .map(|i| {
+            let mut rng = SmallRng::seed_from_u64(random_seed.wrapping_add(2).overflowing_mul(i as u64).0);
+            let decklists = decks(&mut rng);
+            let seed: u64 = rng.gen();
+            let player_seeds: ByPlayer<u64> = ByPlayer::generate(|_| rng.gen());
+            let mut paired = PairedMatchStats::default();
+            let mut counter = SearchCounter::default();
+            let mut pair_score = 0;
+            for flip in [false, true] {
+                let mut search = make_search();
+                let mut decklists = decklists.as_ref();
+                let mut player_seeds = player_seeds;
+                if flip {
+                    std::mem::swap(&mut search.0, &mut search.1);
+                    std::mem::swap(&mut decklists.0, &mut decklists.1);
+                    std::mem::swap(&mut player_seeds.0, &mut player_seeds.1);
+                }
+                let game = new_standard_game_with_player_rngs(
+                    decklists,
+                    SmallRng::seed_from_u64(seed),
+                    player_seeds.map(SmallRng::seed_from_u64),
+                );
+                let (winner, dt, c) = match_round(game, &mut search, steps, opening_book);
+                let (winner_str, d_score) = winner_value(winner, flip);
+                println!(
+                    "- Pair {:3} game {} ... {winner_str} dt={:6.2}ms, states_visited={:8}",
+                    i + 1,
+                    if flip { 2 } else { 1 },
+                    dt.as_millis(),
+                    c.states_visited
+                );
+                paired.stats.add_in_place(&MatchStats::from_score(d_score));
+                pair_score += d_score as usize;
+                counter.add_in_place(&c);
+            }
+            paired.pair_scores[pair_score] += 1;
+            (paired, counter)
+        })
+        .reduce(
+            || (Default::default(), Default::default()),
+            |(mut s, mut c), (s1, c1)| {
+                s.add_in_place(&s1);
+                c.add_in_place(&c1);
+                (s, c)
+            },
+        )
+}
+
+pub fn winner_value(winner: Option<PlayerId>, flip: bool) -> (&'static str, i32) {
+    match winner {
+        Some(PlayerId::PlayerFirst) => {
+            if flip {
+                ("0-1", 0)
+            } else {
+                ("1-0", 2)
+            }
+        }
+        Some(PlayerId::PlayerSecond) => {
+            if flip {
+                ("1-0", 2)
+            } else {
+                ("0-1", 0)
+            }
+        }
+        None => ("1/2", 1),
+    }
+}
@@ -0,0 +1,143 @@
+use std::sync::Arc;
+
+use gitcg_sim::prelude::*;
+
+use crate::{
+    minimax::Eval,
+    training::{
+        as_slice::AsSlice,
+        describe::{DescribeFeatures, FeatureDescriptor},
+        features::{game_state_features, Features},
+    },
+    Game,
+};
+
+/// Static evaluation of a position from the perspective of `player_id`.
+/// Used for the leaf nodes of minimax and for the cutoff of MCTS random playouts.
+pub trait Evaluator<G: Game>: Send + Sync {
+    fn evaluate(&self, state: &G, player_id: PlayerId) -> G::Eval;
+}
+
+impl<G: Game, T: Evaluator<G> + ?Sized> Evaluator<G> for Arc<T> {
+    #[inline]
+    fn evaluate(&self, state: &G, player_id: PlayerId) -> G::Eval {
+        self.as_ref().evaluate(state, player_id)
+    }
+}
+
+/// The hand-written heuristic (`Game::eval`).
+#[derive(Debug, Default, Copy, Clone)]
+pub struct HeuristicEvaluator;
+
+impl<G: Game> Evaluator<G> for HeuristicEvaluator {
+    #[inline]
+    fn evaluate(&self, state: &G, player_id: PlayerId) -> G::Eval {
+        state.eval(player_id)
+    }
+}
+
+#[inline]
+fn winner_eval(winner: PlayerId, player_id: PlayerId) -> Eval {
+    if winner == player_id {
+        Eval::win(0)
+    } else {
+        Eval::lose(0)
+    }
+}
+
+/// Linear model over the game state features, as learned by temporal difference learning (TDL).
+/// The weights predict the logit of the win probability for `player_id` in units of the heuristic
+/// evaluation (see `SelfPlayModel::EVAL_SCALING`).
+#[derive(Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearEvaluator {
+    pub weights: Features,
+    pub player_id: PlayerId,
+}
+
+impl LinearEvaluator {
+    pub fn new(weights: Features, player_id: PlayerId) -> Self {
+        Self { weights, player_id }
+    }
+
+    /// Load the weights from a JSON file.
+    #[cfg(feature = "serde")]
+    pub fn load(path: &std::path::Path) -> Result<Self, String> {
+        let file = std::fs::File::open(path).map_err(|e| e.to_string())?;
+        serde_json::from_reader(std::io::BufReader::new(file)).map_err(|e| e.to_string())
+    }
+
+    /// Save the weights to a JSON file.
+    #[cfg(feature = "serde")]
+    pub fn save(&self, path: &std::path::Path) -> Result<(), String> {
+        let file = std::fs::File::create(path).map_err(|e| e.to_string())?;
+        serde_json::to_writer(std::io::BufWriter::new(file), self).map_err(|e| e.to_string())
+    }
+
+    /// Weights along with the name of the feature they apply to.
+    pub fn named_weights(&self) -> Vec<(FeatureDescriptor, f32)> {
+        let w = <Features as AsSlice<f32>>::as_slice_ref(&self.weights);
+        Features::describe()
+            .into_iter()
+            .map(|d| {
+                let wi = w[d.index];
+                (d, wi)
+            })
+            .collect()
+    }
+
+    /// Dot product of the weights and the game state features, for the player the weights were trained for.
+    pub fn logit<S: NondetState>(weights: &Features, game_state: &GameStateWrapper<S>) -> f32 {
+        let x = <Features as AsSlice<f32>>::as_slice(game_state_features::features(&game_state.game_state));
+        let w = <Features as AsSlice<f32>>::as_slice_ref(weights);
+        x.iter().zip(w.iter()).map(|(xi, wi)| xi * wi).sum()
+    }
+
+    /// Evaluate using `weights` trained for `weights_player_id`.
+    pub fn evaluate_with_weights<S: NondetState>(
+        weights: &Features,
+        weights_player_id: PlayerId,
+        state: &GameStateWrapper<S>,
+        player_id: PlayerId,
+    ) -> Eval {
+        if let Some(winner) = state.winner() {
+            return winner_eval(winner, player_id);
+        }
+        let v = Self::logit(weights, state);
+        let v = if player_id == weights_player_id { v } else { -v };
+        let max = crate::minimax::types::WINNER - 1;
+        Eval::from_eval(v.clamp(-max as f32, max as f32) as i16)
+    }
+}
+
+impl<S: NondetState> Evaluator<GameStateWrapper<S>> for LinearEvaluator {
+    #[inline]
+    fn evaluate(&self, state: &GameStateWrapper<S>, player_id: PlayerId) -> Eval {
+        Self::evaluate_with_weights(&self.weights, self.player_id, state, player_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gitcg_sim::{
+        deck::random_decklist,
+        rand::{rngs::SmallRng, SeedableRng},
+    };
+
+    use super::*;
+
+    #[test]
+    fn linear_evaluator_is_zero_sum() {
+        let mut rng = SmallRng::seed_from_u64(100);
+        let decklists = ByPlayer::generate(|_| random_decklist(&mut rng));
+        let game = new_standard_game(decklists.as_ref(), rng);
+        let mut weights = <Features as AsSlice<f32>>::as_slice(Default::default());
+        for (i, w) in weights.iter_mut().enumerate() {
+            *w = (i % 7) as f32 - 3.0;
+        }
+        let evaluator = LinearEvaluator::new(<Features as AsSlice<f32>>::from_slice(weights), PlayerId::PlayerFirst);
+        let e1 = evaluator.evaluate(&game, PlayerId::PlayerFirst);
+        let e2 = evaluator.evaluate(&game, PlayerId::PlayerSecond);
+        assert_eq!(e1, -e2);
+    }
+}
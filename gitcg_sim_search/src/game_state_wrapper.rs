@@ -124,6 +124,13 @@ impl<S: NondetState> Game for GameStateWrapper<S> {
         self.game_state.round_number()
     }
 
+    fn endgame_size_estimate(&self) -> Option<u32> {
+        let ByPlayer(alive1, alive2) =
+            ByPlayer::generate(|player_id| self.game_state.player(player_id).char_states().iter_valid().count() as u32);
+        let cards = ByPlayer::generate(|player_id| self.game_state.player(player_id).hand_len() as u32);
+        Some(alive1 * alive2 * (1 + cards.0 + cards.1))
+    }
+
     fn shuffle_actions(actions: &mut Self::Actions, rng: &mut ThreadRng) {
         actions.shuffle(rng);
     }
@@ -153,7 +160,7 @@ impl<S: NondetState> Game for GameStateWrapper<S> {
                 .unwrap_or(0) as i16;
             if index_from_move_chain == Some(0) {
                 -1100
-            } else if index_from_move_chain == Some(move_chain.len() - 1) {
+            } else if index_from_move_chain.is_some() && index_from_move_chain == move_chain.len().checked_sub(1) {
                 -1080
             } else if index_from_move_chain.is_some() {
                 -1060
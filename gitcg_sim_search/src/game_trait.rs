@@ -89,4 +89,10 @@ pub trait Game: ZobristHashable + Debug + Clone + Send + Sync {
     fn depth_extension(&self, action: Self::Action) -> u8 {
         0
     }
+
+    /// Estimated size of the remaining game for deciding whether the position can be solved exactly.
+    /// Smaller is easier to solve. `None` if the position should never be solved exactly.
+    fn endgame_size_estimate(&self) -> Option<u32> {
+        None
+    }
 }
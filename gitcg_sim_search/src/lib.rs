@@ -39,12 +39,25 @@ pub mod minimax;
 
 pub mod playout;
 
+/// Static evaluation functions shared by minimax and MCTS
+pub mod evaluator;
+pub use evaluator::{Evaluator, HeuristicEvaluator};
+
 pub mod training;
 
 pub mod search;
 pub use search::*;
 
+/// Time management for matches played with a clock
+pub mod time_manager;
+
+/// Opening book built from the moves of completed games
+pub mod opening_book;
+
 pub mod prelude {
     pub use crate::linked_list::*;
-    pub use crate::{EvalTrait, Game, GameTreeSearch, SearchCounter, SearchLimits, SearchResult};
+    pub use crate::{
+        EvalTrait, Evaluator, Game, GameTreeSearch, SearchCounter, SearchLimits, SearchObserver, SearchProgress,
+        SearchResult, StopSignal,
+    };
 }
@@ -42,7 +42,7 @@ impl<G: Game> NodeData<G> {
     }
 }
 
-impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G>> MCTS<G, E, S> {
+impl<G: Game, E: Evaluator<G>, S: SelectionPolicy<G>> MCTS<G, E, S> {
     pub fn dump_tree(
         &self,
         token: Token,
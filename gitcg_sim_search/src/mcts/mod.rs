@@ -9,8 +9,9 @@ use std::{
 };
 
 use crate::{
-    cons, linked_list, minimax::transposition_table::TTKey, transposition_table::CacheTable, Game, GameTreeSearch,
-    SearchCounter, SearchLimits, SearchResult, PV,
+    cons, linked_list, minimax::transposition_table::TTKey, transposition_table::CacheTable, AnalysisLine, Evaluator,
+    Game, GameTreeSearch, HeuristicEvaluator, SearchCounter, SearchLimits, SearchObserver, SearchProgress,
+    SearchResult, StopSignal, PV,
 };
 use atree::{Arena, Token};
 use gitcg_sim::{
@@ -22,9 +23,7 @@ use gitcg_sim::{
 #[cfg(not(feature = "no_parallel"))]
 use rayon::prelude::*;
 
-use self::policy::{
-    DefaultEvalPolicy, EvalPolicy, SelectionPolicy, SelectionPolicyChildContext, SelectionPolicyContext, UCB1,
-};
+use self::policy::{SelectionPolicy, SelectionPolicyChildContext, SelectionPolicyContext, UCB1};
 
 pub mod policy;
 
@@ -167,9 +166,14 @@ pub struct MCTSConfig {
     pub policy_bias: Option<f32>,
     pub debug: bool,
     pub limits: Option<SearchLimits>,
+    /// Number of iterations between progress reports, None to use the default.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub progress_iters: Option<u32>,
 }
 
 impl MCTSConfig {
+    pub const DEFAULT_PROGRESS_ITERS: u32 = 500;
+
     #[inline]
     pub(crate) fn policy_softmax(&self, v: f32) -> f32 {
         if let Some(a) = self.policy_bias {
@@ -186,10 +190,13 @@ pub struct SelfPlayDataPoint<G: Game> {
     pub state: G,
     pub action_weights: Vec<(G::Action, f32)>,
     pub depth: u8,
+    /// Final outcome for the player to move (1 for a win, 0.5 for a draw, 0 for a loss),
+    /// filled in once the game is over.
+    pub outcome: Option<f32>,
 }
 
 #[derive(Debug)]
-pub struct MCTS<G: Game, E: EvalPolicy<G> = DefaultEvalPolicy, S: SelectionPolicy<G> = UCB1> {
+pub struct MCTS<G: Game, E: Evaluator<G> = HeuristicEvaluator, S: SelectionPolicy<G> = UCB1> {
     pub config: MCTSConfig,
     pub maximize_player: PlayerId,
     pub tree: Arena<NodeData<G>>,
@@ -197,9 +204,10 @@ pub struct MCTS<G: Game, E: EvalPolicy<G> = DefaultEvalPolicy, S: SelectionPolic
     pub root: Option<(HashValue, Token)>,
     pub eval_policy: E,
     pub selection_policy: S,
+    pub stop_signal: StopSignal,
 }
 
-impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G> + Default> MCTS<G, E, S> {
+impl<G: Game, E: Evaluator<G>, S: SelectionPolicy<G> + Default> MCTS<G, E, S> {
     pub fn new_with_eval_policy(config: MCTSConfig, eval_policy: E) -> Self {
         let tree = Arena::<NodeData<G>>::new();
         Self {
@@ -210,11 +218,12 @@ impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G> + Default> MCTS<G, E, S> {
             root: None,
             eval_policy,
             selection_policy: Default::default(),
+            stop_signal: Default::default(),
         }
     }
 }
 
-impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G>> MCTS<G, E, S> {
+impl<G: Game, E: Evaluator<G>, S: SelectionPolicy<G>> MCTS<G, E, S> {
     pub fn new_with_eval_policy_and_selection_policy(config: MCTSConfig, eval_policy: E, selection_policy: S) -> Self {
         let tree = Arena::<NodeData<G>>::new();
         Self {
@@ -225,17 +234,18 @@ impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G>> MCTS<G, E, S> {
             root: None,
             eval_policy,
             selection_policy,
+            stop_signal: Default::default(),
         }
     }
 }
 
-impl<G: Game, E: EvalPolicy<G> + Default> MCTS<G, E> {
+impl<G: Game, E: Evaluator<G> + Default> MCTS<G, E> {
     pub fn new(config: MCTSConfig) -> Self {
         Self::new_with_eval_policy(config, Default::default())
     }
 }
 
-impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G>> MCTS<G, E, S> {
+impl<G: Game, E: Evaluator<G>, S: SelectionPolicy<G>> MCTS<G, E, S> {
     fn init(&mut self, init: G, maximize_player: PlayerId) -> Token {
         let hash = init.zobrist_hash();
         let root = NodeData::new(init, None);
@@ -459,7 +469,7 @@ impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G>> MCTS<G, E, S> {
             count += 1;
         }
         let winner = game.winner().unwrap_or_else(|| {
-            if game.eval(self.maximize_player) > Default::default() {
+            if self.eval_policy.evaluate(&game, self.maximize_player) > Default::default() {
                 self.maximize_player
             } else {
                 self.maximize_player.opposite()
@@ -616,13 +626,105 @@ impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G>> MCTS<G, E, S> {
                 state,
                 action_weights,
                 depth,
+                outcome: None,
             })
         });
     }
+
+    /// The visit distribution over the moves at the root of the last search.
+    #[cfg(feature = "training")]
+    pub fn root_visit_distribution(&self) -> Option<SelfPlayDataPoint<G>> {
+        let (_, root) = self.root?;
+        let node = self.tree.get(root)?;
+        let visits = node
+            .children(&self.tree)
+            .filter_map(|child| Some((child.data.action?, child.data.prop.n)))
+            .collect::<Vec<_>>();
+        let total = visits.iter().map(|(_, n)| n).sum::<u32>().max(1) as f32;
+        Some(SelfPlayDataPoint {
+            state: node.data.state.clone(),
+            action_weights: visits.into_iter().map(|(a, n)| (a, n as f32 / total)).collect(),
+            depth: 0,
+            outcome: None,
+        })
+    }
 }
 
-impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G>> GameTreeSearch<G> for MCTS<G, E, S> {
+impl<G: Game, E: Evaluator<G>, S: SelectionPolicy<G>> GameTreeSearch<G> for MCTS<G, E, S> {
     fn search(&mut self, position: &G, maximize_player: PlayerId) -> SearchResult<G> {
+        self.search_with_observer(position, maximize_player, &mut ())
+    }
+
+    fn search_with_observer(
+        &mut self,
+        position: &G,
+        maximize_player: PlayerId,
+        observer: &mut dyn SearchObserver<G>,
+    ) -> SearchResult<G> {
+        let res = self.search_impl(position, maximize_player, observer);
+        self.stop_signal.reset();
+        res
+    }
+
+    fn stop_signal(&self) -> Option<StopSignal> {
+        Some(self.stop_signal.clone())
+    }
+
+    fn analyze(&mut self, position: &G, maximize_player: PlayerId, multi_pv: usize) -> Vec<AnalysisLine<G>> {
+        self.search(position, maximize_player);
+        let Some((_, root)) = self.root else {
+            return vec![];
+        };
+        self.root_children_breakdown(root, multi_pv)
+    }
+
+    fn set_limits(&mut self, limits: Option<SearchLimits>) {
+        self.config.limits = limits;
+    }
+}
+
+impl<G: Game, E: Evaluator<G>, S: SelectionPolicy<G>> MCTS<G, E, S> {
+    /// Win ratio of the root for the maximizing player.
+    fn root_win_ratio(&self, root: Token) -> Option<f32> {
+        self.tree.get(root).map(|root_node| root_node.data.prop.ratio())
+    }
+
+    /// Visits and win ratios of the most visited children of the root, with their PVs.
+    fn root_children_breakdown(&self, root: Token, multi_pv: usize) -> Vec<AnalysisLine<G>> {
+        let Some(root_node) = self.tree.get(root) else {
+            return vec![];
+        };
+        let is_maximize = root_node.data.is_maximize(self.maximize_player);
+        let tt_hits: Rc<RefCell<u64>> = Rc::new(Default::default());
+        let mut lines: Vec<AnalysisLine<G>> = root_node
+            .children(&self.tree)
+            .filter_map(|child| {
+                let action = child.data.action?;
+                let (ratio, n) = child
+                    .data
+                    .ratio_with_transposition(is_maximize, &self.tt, tt_hits.clone());
+                let win_ratio = if is_maximize { ratio } else { 1.0 - ratio };
+                Some(AnalysisLine {
+                    pv: cons!(action, self.pv_rec(child, tt_hits.clone())),
+                    eval: None,
+                    visits: Some(n),
+                    win_ratio: Some(win_ratio),
+                })
+            })
+            .collect();
+        lines.sort_by_key(|line| std::cmp::Reverse(line.visits));
+        lines.truncate(multi_pv.max(1));
+        lines
+    }
+}
+
+impl<G: Game, E: Evaluator<G>, S: SelectionPolicy<G>> MCTS<G, E, S> {
+    fn search_impl(
+        &mut self,
+        position: &G,
+        maximize_player: PlayerId,
+        observer: &mut dyn SearchObserver<G>,
+    ) -> SearchResult<G> {
         if position.winner().is_some() {
             return Default::default();
         }
@@ -633,8 +735,15 @@ impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G>> GameTreeSearch<G> for MCT
 
         let time_limit_ms = self.config.limits.and_then(|l| l.max_time_ms).unwrap_or(600_000);
         let states_limit = self.config.limits.and_then(|l| l.max_positions).unwrap_or(u64::MAX);
+        let progress_iters = self
+            .config
+            .progress_iters
+            .unwrap_or(MCTSConfig::DEFAULT_PROGRESS_ITERS)
+            .max(1);
         let t0 = Instant::now();
         let mut states_visited = 0;
+        let mut iters = 0u32;
+        let mut next_progress_iters = progress_iters;
         let tt_hits = Rc::new(RefCell::new(0u64));
         let root = self.init(position.clone(), maximize_player);
         let mut last_print = t0;
@@ -651,12 +760,31 @@ impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G>> GameTreeSearch<G> for MCT
                     }
                 };
                 states_visited += dn;
+                iters += 1;
                 if states_visited >= states_limit {
                     break 'iter;
                 }
                 if t0.elapsed().as_millis() >= time_limit_ms {
                     break 'iter;
                 }
+                if self.stop_signal.is_stopped() {
+                    break 'iter;
+                }
+                if iters >= next_progress_iters {
+                    next_progress_iters += progress_iters;
+                    let pv = self.pv(root);
+                    observer.on_progress(&SearchProgress {
+                        depth: pv.len() as u8,
+                        pv,
+                        eval: None,
+                        win_ratio: self.root_win_ratio(root),
+                        counter: SearchCounter {
+                            states_visited,
+                            ..Default::default()
+                        },
+                        elapsed_ms: t0.elapsed().as_millis(),
+                    });
+                }
             }
             if self.config.debug && last_print.elapsed().as_millis() >= 500 {
                 last_print = Instant::now();
@@ -682,10 +810,16 @@ impl<G: Game, E: EvalPolicy<G>, S: SelectionPolicy<G>> GameTreeSearch<G> for MCT
             self.print_tree(root, 0, 2, 40 * self.config.random_playout_iters);
             println!("PV = {:?}", pv.into_iter().copied().collect::<Vec<_>>());
         }
-        SearchResult {
+        let res = SearchResult {
             pv,
             eval: Default::default(),
             counter,
-        }
+        };
+        let mut progress = SearchProgress::from_result(&res, t0.elapsed().as_millis());
+        progress.eval = None;
+        progress.win_ratio = self.root_win_ratio(root);
+        progress.depth = res.pv.len() as u8;
+        observer.on_progress(&progress);
+        res
     }
 }
@@ -4,20 +4,6 @@ use crate::training::policy::SelectionPolicyState;
 
 use super::*;
 
-pub trait EvalPolicy<G: Game>: Send + Sync {
-    fn evaluate(&self, state: &G, player_id: PlayerId) -> G::Eval;
-}
-
-#[derive(Default)]
-pub struct DefaultEvalPolicy();
-
-impl<G: Game> EvalPolicy<G> for DefaultEvalPolicy {
-    #[inline]
-    fn evaluate(&self, state: &G, player_id: PlayerId) -> G::Eval {
-        state.eval(player_id)
-    }
-}
-
 pub struct SelectionPolicyContext<'a, 'b, G: Game> {
     pub config: &'a MCTSConfig,
     pub parent: &'b NodeData<G>,
@@ -0,0 +1,337 @@
+use std::collections::HashMap;
+
+use gitcg_sim::prelude::{HashValue, PlayerId};
+use instant::Instant;
+
+use crate::*;
+
+/// Configuration for the exact endgame solver.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EndgameConfig {
+    /// Positions are solved when `Game::endgame_size_estimate` is at most this value.
+    pub max_size: u32,
+    /// Max. number of plies to prove a win or loss within.
+    pub max_plies: u8,
+    /// Max. number of positions to visit before giving up.
+    pub max_positions: u64,
+}
+
+impl Default for EndgameConfig {
+    fn default() -> Self {
+        Self {
+            max_size: Self::DEFAULT_MAX_SIZE,
+            max_plies: Self::DEFAULT_MAX_PLIES,
+            max_positions: Self::DEFAULT_MAX_POSITIONS,
+        }
+    }
+}
+
+impl EndgameConfig {
+    pub const DEFAULT_MAX_SIZE: u32 = 4;
+    pub const DEFAULT_MAX_PLIES: u8 = 24;
+    pub const DEFAULT_MAX_POSITIONS: u64 = 500_000;
+
+    /// Whether the position is small enough to be solved.
+    pub fn applies_to<G: Game>(&self, position: &G) -> bool {
+        position
+            .endgame_size_estimate()
+            .map(|size| size <= self.max_size)
+            .unwrap_or(false)
+    }
+}
+
+/// Game-theoretic value of a position for the maximizing player, assuming perfect information.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Proof {
+    /// Proven win within the number of plies (distance-to-win).
+    Win(u8),
+    /// Proven loss within the number of plies (distance-to-loss).
+    Loss(u8),
+    /// Not proven within the search limits.
+    Unknown,
+}
+
+impl Proof {
+    #[inline]
+    pub fn is_proven(self) -> bool {
+        !matches!(self, Self::Unknown)
+    }
+
+    /// Convert to an evaluation, where wins that take more plies are worth less.
+    pub fn eval<E: EvalTrait>(self) -> Option<E> {
+        let stepped = |plies: u8| (0..plies).fold(E::MAX, |e, _| e.plus_one_step());
+        match self {
+            Self::Win(plies) => Some(stepped(plies)),
+            Self::Loss(plies) => Some(-stepped(plies)),
+            Self::Unknown => None,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Outcome {
+    Win,
+    Loss,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct EndgameTTEntry<A> {
+    outcome: Outcome,
+    /// Plies searched for an unknown outcome, or plies within which the outcome is proven.
+    depth: u8,
+    best_move: Option<A>,
+}
+
+/// Exact solver for small endgames.
+/// Searches the game tree with perfect information to prove a win or loss for the maximizing player,
+/// using iterative deepening on the number of plies so that the shortest distance-to-win is found.
+pub struct EndgameSolver<G: Game> {
+    pub config: EndgameConfig,
+    tt: HashMap<HashValue, EndgameTTEntry<G::Action>>,
+    counter: SearchCounter,
+    aborted: bool,
+}
+
+impl<G: Game> EndgameSolver<G> {
+    pub fn new(config: EndgameConfig) -> Self {
+        Self {
+            config,
+            tt: Default::default(),
+            counter: Default::default(),
+            aborted: false,
+        }
+    }
+
+    /// Solve the position for `maximize_player`. The PV is empty if the position is not proven.
+    pub fn solve(&mut self, position: &G, maximize_player: PlayerId, stop_signal: &StopSignal) -> (Proof, PV<G>) {
+        self.tt.clear();
+        self.counter = Default::default();
+        self.aborted = false;
+        if let Some(winner) = position.winner() {
+            return (Self::terminal_proof(winner, maximize_player), linked_list![]);
+        }
+
+        for depth in 1..=self.config.max_plies {
+            let (outcome, pv) = self.solve_depth(position, maximize_player, depth, stop_signal);
+            if self.aborted {
+                break;
+            }
+            self.counter.last_depth = depth;
+            match outcome {
+                Outcome::Win => return (Proof::Win(depth), pv),
+                Outcome::Loss => return (Proof::Loss(depth), pv),
+                Outcome::Unknown => {}
+            }
+        }
+        (Proof::Unknown, linked_list![])
+    }
+
+    pub fn counter(&self) -> SearchCounter {
+        self.counter
+    }
+
+    fn terminal_proof(winner: PlayerId, maximize_player: PlayerId) -> Proof {
+        if winner == maximize_player {
+            Proof::Win(0)
+        } else {
+            Proof::Loss(0)
+        }
+    }
+
+    fn solve_depth(
+        &mut self,
+        position: &G,
+        maximize_player: PlayerId,
+        depth: u8,
+        stop_signal: &StopSignal,
+    ) -> (Outcome, PV<G>) {
+        if let Some(winner) = position.winner() {
+            let outcome = if winner == maximize_player {
+                Outcome::Win
+            } else {
+                Outcome::Loss
+            };
+            return (outcome, linked_list![]);
+        }
+        let Some(player) = position.to_move() else {
+            return (Outcome::Unknown, linked_list![]);
+        };
+        if depth == 0 {
+            return (Outcome::Unknown, linked_list![]);
+        }
+        if self.counter.states_visited >= self.config.max_positions || stop_signal.is_stopped() {
+            self.aborted = true;
+            return (Outcome::Unknown, linked_list![]);
+        }
+
+        let hash = position.zobrist_hash();
+        if let Some(entry) = self.tt.get(&hash) {
+            let usable = match entry.outcome {
+                Outcome::Unknown => entry.depth >= depth,
+                Outcome::Win | Outcome::Loss => entry.depth <= depth,
+            };
+            if usable {
+                self.counter.tt_hits += 1;
+                let pv = entry.best_move.map(|a| linked_list![a]).unwrap_or_default();
+                return (entry.outcome, pv);
+            }
+        }
+
+        // The player to move wins if any move wins, and loses if all moves lose.
+        let (good, bad) = if player == maximize_player {
+            (Outcome::Win, Outcome::Loss)
+        } else {
+            (Outcome::Loss, Outcome::Win)
+        };
+        let mut outcome = bad;
+        let mut best_pv = linked_list![];
+        for action in position.actions() {
+            let mut next = position.clone();
+            if next.advance(action).is_err() {
+                continue;
+            }
+            self.counter.states_visited += 1;
+            let (child_outcome, child_pv) = self.solve_depth(&next, maximize_player, depth - 1, stop_signal);
+            if self.aborted {
+                return (Outcome::Unknown, linked_list![]);
+            }
+            if child_outcome == good {
+                outcome = good;
+                best_pv = cons!(action, child_pv);
+                break;
+            }
+            if child_outcome == Outcome::Unknown {
+                outcome = Outcome::Unknown;
+            } else if outcome == bad && best_pv.is_empty() {
+                best_pv = cons!(action, child_pv);
+            }
+        }
+        if outcome == Outcome::Unknown {
+            best_pv = linked_list![];
+        }
+
+        self.tt.insert(
+            hash,
+            EndgameTTEntry {
+                outcome,
+                depth,
+                best_move: best_pv.head(),
+            },
+        );
+        (outcome, best_pv)
+    }
+}
+
+/// Run the endgame solver and convert a proven result to a search result.
+pub(crate) fn solve_endgame<G: Game>(
+    position: &G,
+    maximize_player: PlayerId,
+    config: EndgameConfig,
+    stop_signal: &StopSignal,
+    observer: &mut dyn SearchObserver<G>,
+) -> Option<SearchResult<G>> {
+    let t0 = Instant::now();
+    let mut solver = EndgameSolver::<G>::new(config);
+    let (proof, pv) = solver.solve(position, maximize_player, stop_signal);
+    let eval = proof.eval::<G::Eval>()?;
+    if pv.is_empty() {
+        return None;
+    }
+    let res = SearchResult::new(pv, eval, solver.counter());
+    observer.on_progress(&SearchProgress::from_result(&res, t0.elapsed().as_millis()));
+    Some(res)
+}
+
+#[cfg(test)]
+mod tests {
+    use gitcg_sim::{
+        deck::random_decklist,
+        prelude::*,
+        rand::{rngs::SmallRng, SeedableRng},
+        rule_based::RuleBasedSearch,
+    };
+
+    use super::*;
+
+    /// Play a game with the rule-based search until the position is small enough to be solved.
+    fn endgame_position(seed: u64) -> Option<GameStateWrapper<StandardNondetHandlerState>> {
+        let config = EndgameConfig {
+            max_size: 2,
+            ..Default::default()
+        };
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let decklists = ByPlayer::generate(|_| random_decklist(&mut rng));
+        let mut game = new_standard_game(decklists.as_ref(), rng);
+        let mut search = RuleBasedSearch::new(Default::default());
+        for _ in 0..1000 {
+            if game.winner().is_some() {
+                return None;
+            }
+            if config.applies_to(&game) {
+                return Some(game);
+            }
+            let player_id = game.to_move().unwrap();
+            let action = search.search(&game, player_id).pv.head().unwrap();
+            game.advance(action).unwrap();
+        }
+        None
+    }
+
+    #[test]
+    fn terminal_position_is_proven_at_distance_zero() {
+        let game = (0..20).find_map(endgame_position).expect("no endgame position found");
+        let mut game = game;
+        let mut search = RuleBasedSearch::new(Default::default());
+        while game.winner().is_none() {
+            let player_id = game.to_move().unwrap();
+            let action = search.search(&game, player_id).pv.head().unwrap();
+            game.advance(action).unwrap();
+        }
+        let winner = game.winner().unwrap();
+        let mut solver = EndgameSolver::new(Default::default());
+        let stop_signal = StopSignal::default();
+        assert_eq!(Proof::Win(0), solver.solve(&game, winner, &stop_signal).0);
+        assert_eq!(Proof::Loss(0), solver.solve(&game, winner.opposite(), &stop_signal).0);
+    }
+
+    #[test]
+    fn proven_pv_reaches_winner_within_distance() {
+        let config = EndgameConfig {
+            max_plies: 8,
+            ..Default::default()
+        };
+        let stop_signal = StopSignal::default();
+        for seed in 0..20 {
+            let Some(game) = endgame_position(seed) else { continue };
+            let player_id = game.to_move().unwrap();
+            let mut solver = EndgameSolver::new(config);
+            let (proof, pv) = solver.solve(&game, player_id, &stop_signal);
+            let (expected_winner, plies) = match proof {
+                Proof::Win(plies) => (player_id, plies),
+                Proof::Loss(plies) => (player_id.opposite(), plies),
+                Proof::Unknown => continue,
+            };
+            // Follow the proof: the winning side plays the PV move, the losing side tries every move.
+            let mut game = game.clone();
+            for _ in 0..plies {
+                if game.winner().is_some() {
+                    break;
+                }
+                let to_move = game.to_move().unwrap();
+                let (proof, pv) = solver.solve(&game, expected_winner, &stop_signal);
+                assert!(matches!(proof, Proof::Win(..)), "{proof:?}");
+                let action = if to_move == expected_winner {
+                    pv.head().unwrap()
+                } else {
+                    game.actions().into_iter().next().unwrap()
+                };
+                game.advance(action).unwrap();
+            }
+            assert_eq!(Some(expected_winner), game.winner());
+            assert!(!pv.is_empty());
+        }
+    }
+}
@@ -1,6 +1,8 @@
+pub mod endgame;
 pub mod search;
 pub mod transposition_table;
 pub mod types;
 
+pub use crate::minimax::endgame::{EndgameConfig, EndgameSolver, Proof};
 pub use crate::minimax::search::{MinimaxConfig, MinimaxSearch};
 pub use crate::minimax::types::*;
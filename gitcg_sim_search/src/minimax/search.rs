@@ -1,5 +1,8 @@
 use std::{
-    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc,
+    },
     time::Instant,
 };
 #[cfg(not(feature = "no_parallel"))]
@@ -12,7 +15,10 @@ use gitcg_sim::{
 
 use crate::*;
 
-use crate::minimax::transposition_table::{TTEntry, TTFlag, TT};
+use crate::minimax::{
+    endgame::{solve_endgame, EndgameConfig},
+    transposition_table::{TTEntry, TTFlag, TT},
+};
 
 fn widen_aspiration_window<G: Game>(
     value: G::Eval,
@@ -69,6 +75,8 @@ struct SearchContext<'b, G: Game> {
     pub target_round: u8,
     pub lazy_smp_index: Option<(ThreadId, &'b LazySMPState<'b>)>,
     pub tt: &'b TT<G::Eval, G::Action>,
+    pub stop_signal: &'b StopSignal,
+    pub evaluator: &'b dyn Evaluator<G>,
 }
 
 impl<'b, G: Game> SearchContext<'b, G> {
@@ -82,6 +90,10 @@ impl<'b, G: Game> SearchContext<'b, G> {
 
     #[inline]
     pub fn should_terminate(&self) -> bool {
+        if self.stop_signal.is_stopped() {
+            return true;
+        }
+
         let Some(limits) = &self.config.limits else {
             return false;
         };
@@ -113,13 +125,13 @@ impl<'b, G: Game> SearchContext<'b, G> {
 }
 
 #[inline]
-fn eval_position<G: Game>(game: &G, maximize_player: PlayerId) -> G::Eval {
+fn eval_position<G: Game>(game: &G, maximize_player: PlayerId, evaluator: &dyn Evaluator<G>) -> G::Eval {
     if G::PREPARE_FOR_EVAL {
         let mut game = game.clone();
         game.prepare_for_eval();
-        game.eval(maximize_player)
+        evaluator.evaluate(&game, maximize_player)
     } else {
-        game.eval(maximize_player)
+        evaluator.evaluate(game, maximize_player)
     }
 }
 
@@ -138,7 +150,7 @@ fn minimax<G: Game>(
         || ctx.lazy_smp_finished()
     {
         ctx.counter.evals += 1;
-        return (eval_position(game, maximize_player), linked_list![]);
+        return (eval_position(game, maximize_player, ctx.evaluator), linked_list![]);
     }
 
     if depth == 0 {
@@ -164,6 +176,7 @@ fn minimax<G: Game>(
                     maximize_player,
                     ctx.target_round,
                     ctx.config.static_search_max_iters,
+                    ctx.evaluator,
                 );
                 ctx.add_states_visited(n);
                 (eval, linked_list![])
@@ -342,7 +355,13 @@ fn tactical_search_iterative_deepening<G: Game>(
     eval
 }
 
-fn static_search<G: Game>(game: &G, maximize_player: PlayerId, target_round: u8, depth: u8) -> (G::Eval, u64) {
+fn static_search<G: Game>(
+    game: &G,
+    maximize_player: PlayerId,
+    target_round: u8,
+    depth: u8,
+    evaluator: &dyn Evaluator<G>,
+) -> (G::Eval, u64) {
     let mut game = game.clone();
     let mut count = 0u64;
     for _ in 0..depth {
@@ -359,7 +378,7 @@ fn static_search<G: Game>(game: &G, maximize_player: PlayerId, target_round: u8,
         count += 1
     }
 
-    (game.eval(maximize_player), count)
+    (evaluator.evaluate(&game, maximize_player), count)
 }
 
 fn probe_tt<G: Game>(
@@ -452,6 +471,8 @@ fn minimax_lazy_smp<G: Game>(
             start_time,
             lazy_smp_index: _,
             counter: _,
+            stop_signal,
+            evaluator,
         } = *ctx;
 
         let (total_counter, (eval, pv, search_counter)) = rayon::join(
@@ -481,6 +502,8 @@ fn minimax_lazy_smp<G: Game>(
                                 start_time,
                                 lazy_smp_index: Some((thread_id, &lazy_smp)),
                                 tt,
+                                stop_signal,
+                                evaluator,
                             };
                             minimax(
                                 &game_1,
@@ -504,6 +527,8 @@ fn minimax_lazy_smp<G: Game>(
                     start_time,
                     lazy_smp_index: None,
                     tt,
+                    stop_signal,
+                    evaluator,
                 };
                 let (eval, pv) = minimax(
                     game,
@@ -533,6 +558,7 @@ fn minimax_lazy_smp<G: Game>(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 #[inline]
 fn minimax_iterative_deepening_aspiration_windows<G: Game>(
     game: &G,
@@ -541,6 +567,9 @@ fn minimax_iterative_deepening_aspiration_windows<G: Game>(
     depth: u8,
     parallel: bool,
     config: MinimaxConfig,
+    stop_signal: &StopSignal,
+    evaluator: &dyn Evaluator<G>,
+    observer: &mut dyn SearchObserver<G>,
 ) -> SearchResult<G> {
     let full_window: (G::Eval, G::Eval) = (G::Eval::MIN, G::Eval::MAX);
     let mut ctx0 = SearchContext {
@@ -550,6 +579,8 @@ fn minimax_iterative_deepening_aspiration_windows<G: Game>(
         config,
         start_time: Instant::now(),
         target_round: game.round_number() + config.target_round_delta,
+        stop_signal,
+        evaluator,
     };
     const STEP: u8 = ITERATIVE_DEEPENING_STEP;
 
@@ -632,6 +663,14 @@ fn minimax_iterative_deepening_aspiration_windows<G: Game>(
             let pv_vec = pv.into_iter().copied().collect::<Vec<_>>();
             println!(" - Depth {current_depth:2}: Eval={eval:?}, PV={pv_vec:?}");
         }
+        observer.on_progress(&SearchProgress {
+            pv: pv.clone(),
+            eval: Some(eval),
+            win_ratio: None,
+            depth: current_depth,
+            counter: ctx0.counter,
+            elapsed_ms: ctx0.start_time.elapsed().as_millis(),
+        });
     }
 
     if pv.is_empty() {
@@ -644,6 +683,93 @@ fn minimax_iterative_deepening_aspiration_windows<G: Game>(
     SearchResult::new(pv, eval, ctx0.counter)
 }
 
+/// Multi-PV iterative deepening: every root move is searched at each depth.
+/// Once `multi_pv` lines have been found, the remaining moves are searched with the
+/// evaluation of the worst line as the lower bound, and moves that fail low are dropped.
+fn minimax_multi_pv<G: Game>(
+    game: &G,
+    tt: &TT<G::Eval, G::Action>,
+    maximize_player: PlayerId,
+    multi_pv: usize,
+    config: MinimaxConfig,
+    stop_signal: &StopSignal,
+    evaluator: &dyn Evaluator<G>,
+) -> Vec<AnalysisLine<G>> {
+    let mut ctx = SearchContext {
+        counter: SearchCounter::default(),
+        tt,
+        lazy_smp_index: None,
+        config,
+        start_time: Instant::now(),
+        target_round: game.round_number() + config.target_round_delta,
+        stop_signal,
+        evaluator,
+    };
+    let multi_pv = multi_pv.max(1);
+    let mut root_moves: Vec<(G::Action, G::Eval, PV<G>)> = game
+        .actions()
+        .into_iter()
+        .map(|action| (action, G::Eval::MIN, linked_list![]))
+        .collect();
+    let mut lines: Vec<(G::Eval, PV<G>)> = vec![];
+    let depth = std::cmp::max(1, config.depth);
+    let depth0 = std::cmp::min(ITERATIVE_DEEPENING_STEP, depth);
+    'iterative_deepening_loop: for current_depth in (depth0..=depth).step_by(ITERATIVE_DEEPENING_STEP as usize) {
+        let mut next_lines: Vec<(G::Eval, PV<G>)> = vec![];
+        for (action, eval, pv) in root_moves.iter_mut() {
+            if ctx.should_terminate() {
+                // Use the partial results only if no depth has been completed
+                if lines.is_empty() {
+                    lines = next_lines;
+                }
+                break 'iterative_deepening_loop;
+            }
+            let alpha = if next_lines.len() >= multi_pv {
+                next_lines[multi_pv - 1].0
+            } else {
+                G::Eval::MIN
+            };
+            let mut next = game.clone();
+            next.advance(*action).unwrap();
+            ctx.add_states_visited(1);
+            let pv_inner = pv.decons().map(|(_, rest)| rest).unwrap_or_default();
+            let (value, pv_rest) = minimax(
+                &next,
+                maximize_player,
+                (alpha, G::Eval::MAX),
+                current_depth - 1,
+                &pv_inner,
+                &mut ctx,
+                DepthTransitionState::Full,
+            );
+            *eval = value;
+            *pv = cons!(*action, pv_rest);
+            if value > alpha || next_lines.len() < multi_pv {
+                next_lines.push((value, pv.clone()));
+                next_lines.sort_by_key(|line| std::cmp::Reverse(line.0));
+                next_lines.truncate(multi_pv);
+            }
+        }
+        // Search the best moves of this depth first in the next iteration
+        root_moves.sort_by_key(|root_move| std::cmp::Reverse(root_move.1));
+        ctx.counter.last_depth = current_depth;
+        lines = next_lines;
+        if config.debug {
+            println!(" - Depth {current_depth:2}: Multi-PV={lines:?}");
+        }
+    }
+
+    lines
+        .into_iter()
+        .map(|(eval, pv)| AnalysisLine {
+            pv,
+            eval: Some(eval),
+            visits: None,
+            win_ratio: None,
+        })
+        .collect()
+}
+
 #[derive(Default, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MinimaxConfig {
@@ -655,30 +781,89 @@ pub struct MinimaxConfig {
     pub tt_size_mb: u32,
     pub limits: Option<SearchLimits>,
     pub debug: bool,
+    /// Solve small endgames exactly before falling back to the heuristic search.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub endgame: Option<EndgameConfig>,
 }
 
 pub struct MinimaxSearch<G: Game> {
     pub tt: TT<G::Eval, G::Action>,
     pub config: MinimaxConfig,
+    pub stop_signal: StopSignal,
+    pub evaluator: Arc<dyn Evaluator<G>>,
 }
 
 impl<G: Game> MinimaxSearch<G> {
     pub fn new(config: MinimaxConfig) -> Self {
+        Self::new_with_evaluator(config, Arc::new(HeuristicEvaluator))
+    }
+
+    pub fn new_with_evaluator(config: MinimaxConfig, evaluator: Arc<dyn Evaluator<G>>) -> Self {
         let tt = TT::<G::Eval, G::Action>::new(config.tt_size_mb);
-        Self { tt, config }
+        Self {
+            tt,
+            config,
+            stop_signal: Default::default(),
+            evaluator,
+        }
     }
 }
 
 impl<G: Game> GameTreeSearch<G> for MinimaxSearch<G> {
     fn search(&mut self, position: &G, maximize_player: PlayerId) -> SearchResult<G> {
-        minimax_iterative_deepening_aspiration_windows(
+        self.search_with_observer(position, maximize_player, &mut ())
+    }
+
+    fn search_with_observer(
+        &mut self,
+        position: &G,
+        maximize_player: PlayerId,
+        observer: &mut dyn SearchObserver<G>,
+    ) -> SearchResult<G> {
+        if let Some(endgame) = self.config.endgame.filter(|e| e.applies_to(position)) {
+            if let Some(res) = solve_endgame(position, maximize_player, endgame, &self.stop_signal, observer) {
+                if self.config.debug {
+                    println!(" - Endgame solved: Eval={:?}, PV={:?}", res.eval, res.pv);
+                }
+                self.stop_signal.reset();
+                return res;
+            }
+        }
+        let res = minimax_iterative_deepening_aspiration_windows(
             position,
             &self.tt,
             maximize_player,
             self.config.depth,
             self.config.parallel,
             self.config,
-        )
+            &self.stop_signal,
+            self.evaluator.as_ref(),
+            observer,
+        );
+        self.stop_signal.reset();
+        res
+    }
+
+    fn stop_signal(&self) -> Option<StopSignal> {
+        Some(self.stop_signal.clone())
+    }
+
+    fn analyze(&mut self, position: &G, maximize_player: PlayerId, multi_pv: usize) -> Vec<AnalysisLine<G>> {
+        let lines = minimax_multi_pv(
+            position,
+            &self.tt,
+            maximize_player,
+            multi_pv,
+            self.config,
+            &self.stop_signal,
+            self.evaluator.as_ref(),
+        );
+        self.stop_signal.reset();
+        lines
+    }
+
+    fn set_limits(&mut self, limits: Option<SearchLimits>) {
+        self.config.limits = limits;
     }
 }
 
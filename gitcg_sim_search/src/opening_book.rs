@@ -0,0 +1,232 @@
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
+
+use gitcg_sim::prelude::{HashValue, PlayerId};
+
+use crate::{
+    linked_list, AnalysisLine, Game, GameTreeSearch, SearchLimits, SearchObserver, SearchResult, StopSignal, ValueTrait,
+};
+
+/// Statistics of a move played from a book position.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BookMove<A> {
+    pub action: A,
+    pub visits: u32,
+    /// Total score for the player making the move: 2 for a win, 1 for a draw and 0 for a loss.
+    pub score: u32,
+}
+
+impl<A> BookMove<A> {
+    /// Average score between 0 (always lost) and 1 (always won).
+    pub fn score_rate(&self) -> f32 {
+        if self.visits == 0 {
+            return 0.0;
+        }
+        (self.score as f32) / (2.0 * self.visits as f32)
+    }
+}
+
+/// Opening book keyed by the Zobrist hash of the position as seen by the player to move.
+/// Built from the moves made in completed games.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OpeningBook<A> {
+    /// Positions after this round number are not recorded.
+    pub max_round: u8,
+    /// Minimum number of visits for a book move to be played.
+    pub min_visits: u32,
+    pub positions: HashMap<HashValue, Vec<BookMove<A>>>,
+}
+
+impl<A: ValueTrait> Default for OpeningBook<A> {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_ROUND)
+    }
+}
+
+impl<A: ValueTrait> OpeningBook<A> {
+    pub const DEFAULT_MAX_ROUND: u8 = 1;
+    pub const DEFAULT_MIN_VISITS: u32 = 4;
+
+    pub fn new(max_round: u8) -> Self {
+        Self {
+            max_round,
+            min_visits: Self::DEFAULT_MIN_VISITS,
+            positions: Default::default(),
+        }
+    }
+
+    /// The book key of a position: the Zobrist hash with the private information
+    /// of the opponent of the player to move hidden.
+    /// Returns `None` if the position is outside of the book.
+    pub fn key<G: Game<Action = A>>(&self, position: &G) -> Option<(HashValue, PlayerId)> {
+        if position.round_number() > self.max_round {
+            return None;
+        }
+        let player_id = position.to_move()?;
+        let mut position = position.clone();
+        position.hide_private_information(player_id.opposite());
+        Some((position.zobrist_hash(), player_id))
+    }
+
+    /// Record the moves of a completed game, given by the book key of the position
+    /// before each move and the move made.
+    pub fn record_game(&mut self, moves: &[((HashValue, PlayerId), A)], winner: Option<PlayerId>) {
+        for &((hash, player_id), action) in moves {
+            let score = match winner {
+                Some(w) if w == player_id => 2,
+                Some(..) => 0,
+                None => 1,
+            };
+            let book_moves = self.positions.entry(hash).or_default();
+            match book_moves.iter_mut().find(|m| m.action == action) {
+                Some(m) => {
+                    m.visits += 1;
+                    m.score += score;
+                }
+                None => book_moves.push(BookMove {
+                    action,
+                    visits: 1,
+                    score,
+                }),
+            }
+        }
+    }
+
+    /// Merge the statistics of another opening book into this one.
+    pub fn merge(&mut self, other: &Self) {
+        self.max_round = self.max_round.max(other.max_round);
+        for (hash, other_moves) in &other.positions {
+            let book_moves = self.positions.entry(*hash).or_default();
+            for other_move in other_moves {
+                match book_moves.iter_mut().find(|m| m.action == other_move.action) {
+                    Some(m) => {
+                        m.visits += other_move.visits;
+                        m.score += other_move.score;
+                    }
+                    None => book_moves.push(*other_move),
+                }
+            }
+        }
+    }
+
+    /// Select the book move for a position: the most visited move with at least `min_visits` visits,
+    /// with ties broken by the score rate.
+    pub fn lookup<G: Game<Action = A>>(&self, position: &G) -> Option<&BookMove<A>> {
+        let (hash, _) = self.key(position)?;
+        self.positions
+            .get(&hash)?
+            .iter()
+            .filter(|m| m.visits >= self.min_visits)
+            .max_by(|a, b| a.visits.cmp(&b.visits).then(a.score_rate().total_cmp(&b.score_rate())))
+    }
+}
+
+/// A search that plays moves from an opening book, falling back to another search
+/// for positions not covered by the book.
+pub struct OpeningBookSearch<G: Game, T: GameTreeSearch<G>> {
+    pub book: Arc<OpeningBook<G::Action>>,
+    pub search: T,
+    _marker: PhantomData<fn() -> G>,
+}
+
+impl<G: Game, T: GameTreeSearch<G>> OpeningBookSearch<G, T> {
+    pub fn new(book: Arc<OpeningBook<G::Action>>, search: T) -> Self {
+        Self {
+            book,
+            search,
+            _marker: PhantomData,
+        }
+    }
+
+    fn book_move(&self, position: &G) -> Option<SearchResult<G>> {
+        let book_move = self.book.lookup(position)?;
+        // Guard against hash collisions
+        if !position.actions().into_iter().any(|a| a == book_move.action) {
+            return None;
+        }
+        Some(SearchResult::new(
+            linked_list![book_move.action],
+            Default::default(),
+            Default::default(),
+        ))
+    }
+}
+
+impl<G: Game, T: GameTreeSearch<G>> GameTreeSearch<G> for OpeningBookSearch<G, T> {
+    fn search(&mut self, position: &G, maximize_player: PlayerId) -> SearchResult<G> {
+        if let Some(res) = self.book_move(position) {
+            return res;
+        }
+        self.search.search(position, maximize_player)
+    }
+
+    fn search_with_observer(
+        &mut self,
+        position: &G,
+        maximize_player: PlayerId,
+        observer: &mut dyn SearchObserver<G>,
+    ) -> SearchResult<G> {
+        if let Some(res) = self.book_move(position) {
+            return res;
+        }
+        self.search.search_with_observer(position, maximize_player, observer)
+    }
+
+    fn stop_signal(&self) -> Option<StopSignal> {
+        self.search.stop_signal()
+    }
+
+    fn analyze(&mut self, position: &G, maximize_player: PlayerId, multi_pv: usize) -> Vec<AnalysisLine<G>> {
+        self.search.analyze(position, maximize_player, multi_pv)
+    }
+
+    fn set_limits(&mut self, limits: Option<SearchLimits>) {
+        self.search.set_limits(limits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gitcg_sim::{
+        deck::random_decklist,
+        prelude::*,
+        rand::{rngs::SmallRng, SeedableRng},
+    };
+
+    use super::*;
+
+    fn initial_position() -> GameStateWrapper<StandardNondetHandlerState> {
+        let mut rng = SmallRng::seed_from_u64(100);
+        let decklists = ByPlayer::generate(|_| random_decklist(&mut rng));
+        new_standard_game(decklists.as_ref(), rng)
+    }
+
+    #[test]
+    fn most_visited_book_move_is_selected() {
+        let position = initial_position();
+        let mut book = OpeningBook::new(1);
+        book.min_visits = 2;
+        let key = book.key(&position).unwrap();
+        let actions: Vec<_> = position.actions().into_iter().collect();
+        let (a0, a1) = (actions[0], actions[1]);
+        assert_eq!(None, book.lookup(&position).map(|m| m.action));
+        book.record_game(&[(key, a0)], Some(key.1));
+        assert_eq!(None, book.lookup(&position).map(|m| m.action));
+        book.record_game(&[(key, a1)], Some(key.1));
+        book.record_game(&[(key, a1)], Some(key.1.opposite()));
+        book.record_game(&[(key, a0)], None);
+        book.record_game(&[(key, a0)], Some(key.1));
+        let book_move = book.lookup(&position).unwrap();
+        assert_eq!(a0, book_move.action);
+        assert_eq!(3, book_move.visits);
+        assert_eq!(5, book_move.score);
+    }
+
+    #[test]
+    fn positions_after_max_round_are_not_in_book() {
+        let position = initial_position();
+        let book = OpeningBook::new(0);
+        assert_eq!(None, book.key(&position));
+    }
+}
@@ -1,4 +1,11 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    mpsc::Sender,
+    Arc,
+};
+
 use gitcg_sim::prelude::PlayerId;
+use instant::Instant;
 
 use crate::{linked_list, EvalTrait, Game, LinkedList};
 
@@ -127,6 +134,126 @@ impl<G: Game> SearchResult<G> {
     }
 }
 
+/// One of the top moves of a position found by `GameTreeSearch::analyze`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnalysisLine<G: Game> {
+    /// Principal Variation starting with the analyzed move
+    pub pv: PV<G>,
+    /// Evaluation for the maximizing player. Not available for MCTS.
+    pub eval: Option<G::Eval>,
+    /// MCTS: Number of visits of the move.
+    pub visits: Option<u32>,
+    /// MCTS: Win ratio for the maximizing player.
+    pub win_ratio: Option<f32>,
+}
+
+impl<G: Game> AnalysisLine<G> {
+    pub fn from_result(result: &SearchResult<G>) -> Self {
+        Self {
+            pv: result.pv.clone(),
+            eval: Some(result.eval),
+            visits: None,
+            win_ratio: None,
+        }
+    }
+
+    /// One-line summary for analysis tables, with the PV truncated to `max_pv_len` moves.
+    pub fn summary(&self, max_pv_len: usize) -> String {
+        let mut parts = vec![];
+        if let Some(eval) = self.eval {
+            parts.push(format!("eval={eval:?}"));
+        }
+        if let Some(visits) = self.visits {
+            parts.push(format!("visits={visits}"));
+        }
+        if let Some(win_ratio) = self.win_ratio {
+            parts.push(format!("win={:.1}%", 1e2 * win_ratio));
+        }
+        let pv_part = self
+            .pv
+            .into_iter()
+            .take(max_pv_len)
+            .map(|a| format!("{a:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        parts.push(format!("PV=[{pv_part}]"));
+        parts.join(" ")
+    }
+}
+
+/// Intermediate result reported while a search is in progress.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SearchProgress<G: Game> {
+    /// Principal Variation found so far
+    pub pv: PV<G>,
+    /// Evaluation for the maximizing player. Not available for MCTS.
+    pub eval: Option<G::Eval>,
+    /// MCTS: Win ratio of the root for the maximizing player.
+    pub win_ratio: Option<f32>,
+    /// Last finished depth for iterative deepening, or length of the PV for MCTS.
+    pub depth: u8,
+    pub counter: SearchCounter,
+    pub elapsed_ms: u128,
+}
+
+impl<G: Game> SearchProgress<G> {
+    pub fn from_result(result: &SearchResult<G>, elapsed_ms: u128) -> Self {
+        Self {
+            pv: result.pv.clone(),
+            eval: Some(result.eval),
+            win_ratio: None,
+            depth: result.counter.last_depth,
+            counter: result.counter,
+            elapsed_ms,
+        }
+    }
+}
+
+/// Receives intermediate results (`SearchProgress`) during a search.
+pub trait SearchObserver<G: Game> {
+    fn on_progress(&mut self, progress: &SearchProgress<G>);
+}
+
+/// Ignores all progress reports.
+impl<G: Game> SearchObserver<G> for () {
+    #[inline]
+    fn on_progress(&mut self, _: &SearchProgress<G>) {}
+}
+
+/// Sends progress reports through a channel. Reports are dropped if the receiver has disconnected.
+impl<G: Game> SearchObserver<G> for Sender<SearchProgress<G>> {
+    fn on_progress(&mut self, progress: &SearchProgress<G>) {
+        let _ = self.send(progress.clone());
+    }
+}
+
+/// A flag shared across threads for stopping an ongoing search early.
+/// The search returns the best result found so far once stopped.
+#[derive(Debug, Default, Clone)]
+pub struct StopSignal(Arc<AtomicBool>);
+
+impl StopSignal {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Request the search to stop.
+    pub fn stop(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    #[inline]
+    pub fn is_stopped(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub fn reset(&self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
 pub trait GameTreeSearch<G: Game> {
     fn search(&mut self, position: &G, maximize_player: PlayerId) -> SearchResult<G>;
 
@@ -136,6 +263,59 @@ pub trait GameTreeSearch<G: Game> {
         position1.hide_private_information(maximize_player.opposite());
         self.search(&position1, maximize_player)
     }
+
+    /// Perform search and report intermediate results to the observer.
+    /// The default implementation only reports the final result.
+    fn search_with_observer(
+        &mut self,
+        position: &G,
+        maximize_player: PlayerId,
+        observer: &mut dyn SearchObserver<G>,
+    ) -> SearchResult<G> {
+        let t0 = Instant::now();
+        let res = self.search(position, maximize_player);
+        observer.on_progress(&SearchProgress::from_result(&res, t0.elapsed().as_millis()));
+        res
+    }
+
+    /// `search_with_observer` with hidden information taken into account.
+    fn search_hidden_with_observer(
+        &mut self,
+        position: &G,
+        maximize_player: PlayerId,
+        observer: &mut dyn SearchObserver<G>,
+    ) -> SearchResult<G> {
+        let mut position1 = position.clone();
+        position1.hide_private_information(maximize_player.opposite());
+        self.search_with_observer(&position1, maximize_player, observer)
+    }
+
+    /// Get the signal for stopping the search from another thread, if supported by this search.
+    /// The signal is reset when the search returns.
+    fn stop_signal(&self) -> Option<StopSignal> {
+        None
+    }
+
+    /// Search for the top `multi_pv` moves of the position, each with its own evaluation and PV.
+    /// Lines are sorted from the best move for the maximizing player.
+    /// The default implementation returns only the PV of a regular search.
+    fn analyze(&mut self, position: &G, maximize_player: PlayerId, _multi_pv: usize) -> Vec<AnalysisLine<G>> {
+        let res = self.search(position, maximize_player);
+        if res.pv.is_empty() {
+            return vec![];
+        }
+        vec![AnalysisLine::from_result(&res)]
+    }
+
+    /// Analyze the position with the private information of the opponent hidden.
+    fn analyze_hidden(&mut self, position: &G, maximize_player: PlayerId, multi_pv: usize) -> Vec<AnalysisLine<G>> {
+        let mut position1 = position.clone();
+        position1.hide_private_information(maximize_player.opposite());
+        self.analyze(&position1, maximize_player, multi_pv)
+    }
+
+    /// Override the search limits for subsequent searches, if supported by this search.
+    fn set_limits(&mut self, _limits: Option<SearchLimits>) {}
 }
 
 #[derive(Debug, Default, Copy, Clone)]
@@ -160,3 +340,124 @@ impl SearchLimits {
         false
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::mpsc::channel;
+
+    use gitcg_sim::{
+        deck::random_decklist,
+        prelude::*,
+        rand::{rngs::SmallRng, SeedableRng},
+    };
+
+    use super::*;
+    use crate::{
+        mcts::{CpuctConfig, MCTSConfig, MCTS},
+        minimax::{MinimaxConfig, MinimaxSearch},
+    };
+
+    fn initial_game() -> GameStateWrapper<StandardNondetHandlerState> {
+        let mut rng = SmallRng::seed_from_u64(100);
+        let decklists = ByPlayer::generate(|_| random_decklist(&mut rng));
+        new_standard_game(decklists.as_ref(), rng)
+    }
+
+    fn minimax_search<G: Game>() -> MinimaxSearch<G> {
+        MinimaxSearch::new(MinimaxConfig {
+            depth: 4,
+            tactical_depth: 2,
+            static_search_max_iters: 4,
+            target_round_delta: 1,
+            parallel: false,
+            tt_size_mb: 1,
+            limits: None,
+            debug: false,
+            endgame: None,
+        })
+    }
+
+    fn mcts_search<G: Game>() -> MCTS<G> {
+        MCTS::new(MCTSConfig {
+            cpuct: CpuctConfig::STANDARD,
+            tt_size_mb: 1,
+            parallel: false,
+            random_playout_iters: 1,
+            random_playout_cutoff: 10,
+            random_playout_bias: None,
+            policy_bias: None,
+            debug: false,
+            limits: Some(SearchLimits {
+                max_time_ms: None,
+                max_positions: Some(2_000),
+            }),
+            progress_iters: Some(5),
+        })
+    }
+
+    fn distinct_first_moves<G: Game>(lines: &[AnalysisLine<G>]) -> bool {
+        let heads: Vec<_> = lines.iter().map(|line| line.pv.head().unwrap()).collect();
+        heads.iter().enumerate().all(|(i, a)| !heads[..i].contains(a))
+    }
+
+    #[test]
+    fn minimax_reports_progress_for_each_depth() {
+        let game = initial_game();
+        let mut search = minimax_search();
+        let (mut tx, rx) = channel();
+        let res = search.search_with_observer(&game, game.to_move().unwrap(), &mut tx);
+        let reports: Vec<_> = rx.try_iter().collect();
+        assert!(!reports.is_empty());
+        assert!(reports.windows(2).all(|w| w[0].depth < w[1].depth));
+        assert!(reports.iter().all(|r| r.eval.is_some() && r.win_ratio.is_none()));
+        assert_eq!(res.pv.head(), reports.last().unwrap().pv.head());
+    }
+
+    #[test]
+    fn minimax_stop_signal_returns_a_move() {
+        let game = initial_game();
+        let mut search = minimax_search();
+        let stop_signal = search.stop_signal().unwrap();
+        stop_signal.stop();
+        let res = search.search(&game, game.to_move().unwrap());
+        assert!(res.pv.head().is_some());
+        assert!(!stop_signal.is_stopped());
+    }
+
+    #[test]
+    fn mcts_reports_progress_every_n_iterations() {
+        let game = initial_game();
+        let mut search = mcts_search();
+        let (mut tx, rx) = channel();
+        let res = search.search_with_observer(&game, game.to_move().unwrap(), &mut tx);
+        let reports: Vec<_> = rx.try_iter().collect();
+        assert!(reports.len() >= 2);
+        assert!(reports
+            .windows(2)
+            .all(|w| w[0].counter.states_visited <= w[1].counter.states_visited));
+        assert!(reports.iter().all(|r| r.eval.is_none() && r.win_ratio.is_some()));
+        assert_eq!(res.pv.head(), reports.last().unwrap().pv.head());
+    }
+
+    #[test]
+    fn minimax_analysis_lines_are_sorted() {
+        let game = initial_game();
+        let mut search = minimax_search();
+        let n_actions = game.actions().len();
+        let lines = search.analyze(&game, game.to_move().unwrap(), 3);
+        assert_eq!(n_actions.min(3), lines.len());
+        assert!(lines.windows(2).all(|w| w[0].eval >= w[1].eval));
+        assert!(distinct_first_moves(&lines));
+    }
+
+    #[test]
+    fn mcts_analysis_lines_are_sorted_by_visits() {
+        let game = initial_game();
+        let mut search = mcts_search();
+        let lines = search.analyze(&game, game.to_move().unwrap(), 3);
+        assert!(!lines.is_empty() && lines.len() <= 3);
+        assert!(lines.windows(2).all(|w| w[0].visits >= w[1].visits));
+        assert!(lines.iter().all(|line| line.win_ratio.is_some()));
+        assert!(distinct_first_moves(&lines));
+    }
+}
@@ -0,0 +1,215 @@
+use std::marker::PhantomData;
+
+use gitcg_sim::prelude::PlayerId;
+use instant::Instant;
+
+use crate::{
+    linked_list, AnalysisLine, Game, GameTreeSearch, SearchLimits, SearchObserver, SearchProgress, SearchResult,
+    StopSignal,
+};
+
+/// Match clock settings: total thinking time per player and the time added after each move.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeControl {
+    pub total_time_ms: u128,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub increment_ms: u128,
+}
+
+/// Time allocated for a single move.
+/// The search may stop after `soft_ms` once the PV is stable and must stop at `hard_ms`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct MoveTime {
+    pub soft_ms: u128,
+    pub hard_ms: u128,
+}
+
+/// Allocates thinking time for each move from one player's match clock.
+#[derive(Debug, Clone)]
+pub struct TimeManager {
+    pub control: TimeControl,
+    pub remaining_ms: u128,
+}
+
+impl TimeManager {
+    /// Rounds a game is expected to last, used for estimating the number of moves to go.
+    pub const EXPECTED_ROUNDS: u8 = 10;
+    /// Expected number of decisions per player in a round.
+    pub const MOVES_PER_ROUND: u128 = 6;
+    pub const MIN_MOVES_TO_GO: u128 = 8;
+    /// Number of available actions considered to be of typical complexity.
+    pub const TYPICAL_ACTIONS: f64 = 8.0;
+    /// Max. factor of the soft limit that the hard limit can be extended to.
+    pub const MAX_EXTENSION: u128 = 3;
+    /// Soft limit extension per PV change between iterations.
+    pub const PV_INSTABILITY_EXTENSION: f64 = 0.5;
+    pub const MIN_MOVE_TIME_MS: u128 = 5;
+
+    pub fn new(control: TimeControl) -> Self {
+        Self {
+            control,
+            remaining_ms: control.total_time_ms,
+        }
+    }
+
+    /// Allocate time for a move, based on the remaining time, the round number
+    /// and the number of available actions.
+    pub fn allocate(&self, round_number: u8, action_count: usize) -> MoveTime {
+        let TimeControl { increment_ms, .. } = self.control;
+        let remaining_ms = self.remaining_ms;
+        let rounds_to_go = Self::EXPECTED_ROUNDS.saturating_sub(round_number) as u128;
+        let moves_to_go = (rounds_to_go * Self::MOVES_PER_ROUND).max(Self::MIN_MOVES_TO_GO);
+        let base_ms = (remaining_ms / moves_to_go + 3 * increment_ms / 4) as f64;
+        let complexity = ((action_count as f64) / Self::TYPICAL_ACTIONS).sqrt().clamp(0.5, 2.0);
+        // Never use more than a quarter of the remaining clock on a single move.
+        let max_ms = (remaining_ms / 4 + increment_ms).max(Self::MIN_MOVE_TIME_MS);
+        let soft_ms = ((base_ms * complexity) as u128).clamp(Self::MIN_MOVE_TIME_MS, max_ms);
+        let hard_ms = (Self::MAX_EXTENSION * soft_ms).min(max_ms);
+        MoveTime { soft_ms, hard_ms }
+    }
+
+    /// Update the clock after a move has been made.
+    pub fn on_move_finished(&mut self, elapsed_ms: u128) {
+        self.remaining_ms = self.remaining_ms.saturating_sub(elapsed_ms) + self.control.increment_ms;
+    }
+}
+
+/// Observer that stops the search once the soft limit is reached and the PV is stable.
+/// Each change of the best move between progress reports extends the soft limit up to the hard limit.
+struct TimeManagerObserver<'a, G: Game> {
+    inner: &'a mut dyn SearchObserver<G>,
+    stop_signal: Option<StopSignal>,
+    move_time: MoveTime,
+    last_best: Option<G::Action>,
+    instability: f64,
+}
+
+impl<'a, G: Game> SearchObserver<G> for TimeManagerObserver<'a, G> {
+    fn on_progress(&mut self, progress: &SearchProgress<G>) {
+        self.inner.on_progress(progress);
+        let best = progress.pv.head();
+        if self.last_best.is_some() && best != self.last_best {
+            self.instability += 1.0;
+        } else {
+            self.instability *= 0.5;
+        }
+        self.last_best = best;
+
+        let Some(stop_signal) = &self.stop_signal else {
+            return;
+        };
+        let MoveTime { soft_ms, hard_ms } = self.move_time;
+        let extension = 1.0 + TimeManager::PV_INSTABILITY_EXTENSION * self.instability;
+        let budget_ms = ((soft_ms as f64 * extension) as u128).min(hard_ms);
+        if progress.elapsed_ms >= budget_ms {
+            stop_signal.stop();
+        }
+    }
+}
+
+/// A search that manages its per-move time limits from a match clock (`TimeControl`).
+/// Forced moves (only one available action) are played without searching.
+pub struct TimeManagedSearch<G: Game, T: GameTreeSearch<G>> {
+    pub search: T,
+    pub time_manager: TimeManager,
+    _marker: PhantomData<fn() -> G>,
+}
+
+impl<G: Game, T: GameTreeSearch<G>> TimeManagedSearch<G, T> {
+    pub fn new(search: T, control: TimeControl) -> Self {
+        Self {
+            search,
+            time_manager: TimeManager::new(control),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<G: Game, T: GameTreeSearch<G>> GameTreeSearch<G> for TimeManagedSearch<G, T> {
+    fn search(&mut self, position: &G, maximize_player: PlayerId) -> SearchResult<G> {
+        self.search_with_observer(position, maximize_player, &mut ())
+    }
+
+    fn search_with_observer(
+        &mut self,
+        position: &G,
+        maximize_player: PlayerId,
+        observer: &mut dyn SearchObserver<G>,
+    ) -> SearchResult<G> {
+        let t0 = Instant::now();
+        let actions: Vec<G::Action> = position.actions().into_iter().collect();
+        if let [action] = actions[..] {
+            let res = SearchResult::new(linked_list![action], Default::default(), Default::default());
+            self.time_manager.on_move_finished(t0.elapsed().as_millis());
+            return res;
+        }
+
+        let move_time = self.time_manager.allocate(position.round_number(), actions.len());
+        self.search.set_limits(Some(SearchLimits {
+            max_time_ms: Some(move_time.hard_ms),
+            max_positions: None,
+        }));
+        let mut tm_observer = TimeManagerObserver {
+            inner: observer,
+            stop_signal: self.search.stop_signal(),
+            move_time,
+            last_best: None,
+            instability: 0.0,
+        };
+        let res = self
+            .search
+            .search_with_observer(position, maximize_player, &mut tm_observer);
+        self.time_manager.on_move_finished(t0.elapsed().as_millis());
+        res
+    }
+
+    fn stop_signal(&self) -> Option<StopSignal> {
+        self.search.stop_signal()
+    }
+
+    fn analyze(&mut self, position: &G, maximize_player: PlayerId, multi_pv: usize) -> Vec<AnalysisLine<G>> {
+        self.search.analyze(position, maximize_player, multi_pv)
+    }
+
+    fn set_limits(&mut self, limits: Option<SearchLimits>) {
+        self.search.set_limits(limits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTROL: TimeControl = TimeControl {
+        total_time_ms: 60_000,
+        increment_ms: 1_000,
+    };
+
+    #[test]
+    fn more_time_for_more_actions() {
+        let tm = TimeManager::new(CONTROL);
+        let few = tm.allocate(1, 2);
+        let many = tm.allocate(1, 24);
+        assert!(few.soft_ms < many.soft_ms);
+        assert!(few.soft_ms <= few.hard_ms);
+        assert!(many.soft_ms <= many.hard_ms);
+    }
+
+    #[test]
+    fn allocation_never_exceeds_quarter_of_clock() {
+        let mut tm = TimeManager::new(CONTROL);
+        tm.remaining_ms = 400;
+        let move_time = tm.allocate(9, 30);
+        assert!(move_time.hard_ms <= 400 / 4 + CONTROL.increment_ms);
+    }
+
+    #[test]
+    fn clock_is_charged_and_incremented() {
+        let mut tm = TimeManager::new(CONTROL);
+        tm.on_move_finished(5_000);
+        assert_eq!(56_000, tm.remaining_ms);
+        tm.on_move_finished(100_000);
+        assert_eq!(CONTROL.increment_ms, tm.remaining_ms);
+    }
+}
@@ -0,0 +1,526 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Seek, Write},
+    path::{Path, PathBuf},
+};
+
+use gitcg_sim::{deck::Decklist, prelude::*};
+use serde::{Deserialize, Serialize};
+use zip::{write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::{
+    mcts::SelfPlayDataPoint,
+    training::{
+        as_slice::AsSlice,
+        features::FeatureSchema,
+        policy::{PolicyNetwork, N_OUT},
+    },
+};
+
+/// Decks and random seed of a self-play game.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GameMetadata {
+    pub decks: ByPlayer<Decklist>,
+    pub seed: u64,
+}
+
+/// One position of a self-play game.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DatasetRow {
+    /// Index into `DatasetShard::games`.
+    pub game: u32,
+    pub round_number: u8,
+    pub depth: u8,
+    pub to_move: PlayerId,
+    /// Game state features from the perspective of the player to move (see `FeatureSchema::player_features`).
+    pub game_state_features: Vec<f32>,
+    /// MCTS visit distribution in terms of the input features (see `PolicyNetwork::policy_target`).
+    pub input_features: [f32; N_OUT],
+    /// MCTS visit distribution over the actions at the root.
+    pub visits: Vec<(Input, f32)>,
+    /// Final outcome for the player to move, see `SelfPlayDataPoint::outcome`.
+    pub outcome: Option<f32>,
+}
+
+impl DatasetRow {
+    pub fn new<S: NondetState>(
+        schema: FeatureSchema,
+        game: u32,
+        data_point: &SelfPlayDataPoint<GameStateWrapper<S>>,
+    ) -> Option<Self> {
+        let state = &data_point.state;
+        let to_move = state.to_move()?;
+        Some(Self {
+            game,
+            round_number: state.game_state.round_number(),
+            depth: data_point.depth,
+            to_move,
+            game_state_features: schema.player_features(&state.game_state, to_move),
+            input_features: PolicyNetwork::policy_target(&data_point.action_weights).as_slice(),
+            visits: data_point.action_weights.clone(),
+            outcome: data_point.outcome,
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct ShardMetadata {
+    version: u32,
+    /// Missing for shards written before `FeatureSchema` was added
+    #[serde(default = "default_feature_schema")]
+    feature_schema: u32,
+    n_in: usize,
+    n_out: usize,
+    games: Vec<GameMetadata>,
+    visit_actions: Vec<Input>,
+}
+
+fn default_feature_schema() -> u32 {
+    FeatureSchema::V1.version()
+}
+
+/// Self-play training data stored column-wise as a `.npz` file that can also be opened using `numpy.load`.
+///
+/// Entries (`n` rows, `m` visited actions in total):
+/// - `game_state_features.npy`: `f32` (n, number of features of the `FeatureSchema`)
+/// - `input_features.npy`: `f32` (n, N_OUT)
+/// - `outcome.npy`: `f32` (n), NaN if unknown
+/// - `game.npy`: `u32` (n), `round_number.npy`, `depth.npy`, `to_move.npy`: `u8` (n)
+/// - `visit_offsets.npy`: `u32` (n + 1), `visit_weights.npy`: `f32` (m): visits of row `i` are
+///   `visit_offsets[i]..visit_offsets[i + 1]`
+/// - `metadata.json`: format version, feature schema version, feature sizes, per-game metadata and the visited actions (m)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DatasetShard {
+    pub feature_schema: FeatureSchema,
+    pub games: Vec<GameMetadata>,
+    pub rows: Vec<DatasetRow>,
+}
+
+impl DatasetShard {
+    pub const VERSION: u32 = 1;
+
+    pub fn new(feature_schema: FeatureSchema) -> Self {
+        Self {
+            feature_schema,
+            ..Default::default()
+        }
+    }
+
+    /// Add a finished game. Data points without a player to move are skipped.
+    pub fn push_game<S: NondetState>(
+        &mut self,
+        metadata: GameMetadata,
+        data_points: &[SelfPlayDataPoint<GameStateWrapper<S>>],
+    ) {
+        let game = self.games.len() as u32;
+        self.games.push(metadata);
+        self.rows.extend(
+            data_points
+                .iter()
+                .filter_map(|data_point| DatasetRow::new(self.feature_schema, game, data_point)),
+        );
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| e.to_string())?;
+        Self::read(BufReader::new(file))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let file = File::create(path).map_err(|e| e.to_string())?;
+        self.write(BufWriter::new(file))
+    }
+
+    pub fn read<R: Read + Seek>(reader: R) -> Result<Self, String> {
+        let mut archive = ZipArchive::new(reader).map_err(|e| e.to_string())?;
+        let metadata: ShardMetadata = {
+            let entry = archive.by_name("metadata.json").map_err(|e| e.to_string())?;
+            serde_json::from_reader(entry).map_err(|e| e.to_string())?
+        };
+        if metadata.version != Self::VERSION {
+            return Err(format!("unsupported dataset version: {}", metadata.version));
+        }
+        let feature_schema = FeatureSchema::from_version(metadata.feature_schema)
+            .ok_or_else(|| format!("unsupported feature schema: {}", metadata.feature_schema))?;
+        let n_in = feature_schema.n_features();
+        if (metadata.n_in, metadata.n_out) != (n_in, N_OUT) {
+            return Err(format!(
+                "dataset features {}->{} do not match {n_in}->{N_OUT}",
+                metadata.n_in, metadata.n_out
+            ));
+        }
+
+        let mut column = |name: &str, dtype: Dtype, row_len: usize| -> Result<Vec<u8>, String> {
+            let mut entry = archive.by_name(&format!("{name}.npy")).map_err(|e| e.to_string())?;
+            let (entry_dtype, shape, data) = read_npy(&mut entry)?;
+            if entry_dtype != dtype || shape.get(1).copied().unwrap_or(1) != row_len {
+                return Err(format!("{name}: unexpected dtype or shape"));
+            }
+            Ok(data)
+        };
+        let features = f32s(&column("game_state_features", Dtype::F32, n_in)?);
+        let input_features = f32s(&column("input_features", Dtype::F32, N_OUT)?);
+        let outcome = f32s(&column("outcome", Dtype::F32, 1)?);
+        let game = u32s(&column("game", Dtype::U32, 1)?);
+        let round_number = column("round_number", Dtype::U8, 1)?;
+        let depth = column("depth", Dtype::U8, 1)?;
+        let to_move = column("to_move", Dtype::U8, 1)?;
+        let visit_offsets = u32s(&column("visit_offsets", Dtype::U32, 1)?);
+        let visit_weights = f32s(&column("visit_weights", Dtype::F32, 1)?);
+
+        let n = game.len();
+        let m = visit_weights.len();
+        if features.len() != n * n_in
+            || input_features.len() != n * N_OUT
+            || [outcome.len(), round_number.len(), depth.len(), to_move.len()] != [n; 4]
+            || visit_offsets.len() != n + 1
+            || metadata.visit_actions.len() != m
+            || visit_offsets.windows(2).any(|w| w[0] > w[1])
+            || visit_offsets.last().map(|&o| o as usize) != Some(m)
+            || game.iter().any(|&g| g as usize >= metadata.games.len())
+        {
+            return Err("inconsistent column lengths".to_string());
+        }
+
+        let rows = (0..n)
+            .map(|i| {
+                let visits = (visit_offsets[i] as usize..visit_offsets[i + 1] as usize)
+                    .map(|j| (metadata.visit_actions[j], visit_weights[j]))
+                    .collect();
+                let mut row = DatasetRow {
+                    game: game[i],
+                    round_number: round_number[i],
+                    depth: depth[i],
+                    to_move: if to_move[i] == 0 {
+                        PlayerId::PlayerFirst
+                    } else {
+                        PlayerId::PlayerSecond
+                    },
+                    game_state_features: features[i * n_in..(i + 1) * n_in].to_vec(),
+                    input_features: [0.0; N_OUT],
+                    visits,
+                    outcome: Some(outcome[i]).filter(|v| !v.is_nan()),
+                };
+                row.input_features
+                    .copy_from_slice(&input_features[i * N_OUT..(i + 1) * N_OUT]);
+                row
+            })
+            .collect();
+        Ok(Self {
+            feature_schema,
+            games: metadata.games,
+            rows,
+        })
+    }
+
+    pub fn write<W: Write + Seek>(&self, writer: W) -> Result<(), String> {
+        let rows = &self.rows;
+        let n = rows.len();
+        let mut visit_offsets = vec![0u32];
+        for row in rows {
+            visit_offsets.push(visit_offsets[visit_offsets.len() - 1] + row.visits.len() as u32);
+        }
+        let visits = || rows.iter().flat_map(|row| row.visits.iter());
+        let columns: [(&str, Dtype, Vec<usize>, Vec<u8>); 9] = [
+            (
+                "game_state_features",
+                Dtype::F32,
+                vec![n, self.feature_schema.n_features()],
+                f32_bytes(rows.iter().flat_map(|row| row.game_state_features.iter().copied())),
+            ),
+            (
+                "input_features",
+                Dtype::F32,
+                vec![n, N_OUT],
+                f32_bytes(rows.iter().flat_map(|row| row.input_features)),
+            ),
+            (
+                "outcome",
+                Dtype::F32,
+                vec![n],
+                f32_bytes(rows.iter().map(|row| row.outcome.unwrap_or(f32::NAN))),
+            ),
+            (
+                "game",
+                Dtype::U32,
+                vec![n],
+                rows.iter().flat_map(|row| row.game.to_le_bytes()).collect(),
+            ),
+            (
+                "round_number",
+                Dtype::U8,
+                vec![n],
+                rows.iter().map(|row| row.round_number).collect(),
+            ),
+            ("depth", Dtype::U8, vec![n], rows.iter().map(|row| row.depth).collect()),
+            (
+                "to_move",
+                Dtype::U8,
+                vec![n],
+                rows.iter().map(|row| row.to_move as u8).collect(),
+            ),
+            (
+                "visit_offsets",
+                Dtype::U32,
+                vec![n + 1],
+                visit_offsets.iter().flat_map(|o| o.to_le_bytes()).collect(),
+            ),
+            (
+                "visit_weights",
+                Dtype::F32,
+                vec![visit_offsets[n] as usize],
+                f32_bytes(visits().map(|(_, w)| *w)),
+            ),
+        ];
+        let metadata = ShardMetadata {
+            version: Self::VERSION,
+            feature_schema: self.feature_schema.version(),
+            n_in: self.feature_schema.n_features(),
+            n_out: N_OUT,
+            games: self.games.clone(),
+            visit_actions: visits().map(|(action, _)| *action).collect(),
+        };
+
+        let mut zip = ZipWriter::new(writer);
+        let options = FileOptions::default().compression_method(CompressionMethod::Stored);
+        for (name, dtype, shape, data) in columns {
+            zip.start_file(format!("{name}.npy"), options)
+                .map_err(|e| e.to_string())?;
+            write_npy(&mut zip, dtype, &shape, &data).map_err(|e| e.to_string())?;
+        }
+        zip.start_file("metadata.json", options).map_err(|e| e.to_string())?;
+        serde_json::to_writer(&mut zip, &metadata).map_err(|e| e.to_string())?;
+        zip.finish()
+            .map_err(|e| e.to_string())?
+            .flush()
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Writes the games into numbered shards (`shard-00000.npz`, `shard-00001.npz`, ...) of roughly
+/// `shard_size` rows each. Games are never split across shards.
+#[derive(Debug)]
+pub struct DatasetWriter {
+    pub dir: PathBuf,
+    pub shard_size: usize,
+    next_shard: usize,
+    shard: DatasetShard,
+}
+
+impl DatasetWriter {
+    pub fn new(dir: &Path, shard_size: usize, feature_schema: FeatureSchema) -> Result<Self, String> {
+        std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        let next_shard = shard_paths(dir)?.len();
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            shard_size,
+            next_shard,
+            shard: DatasetShard::new(feature_schema),
+        })
+    }
+
+    /// Add a finished game, writing the current shard once it is full.
+    pub fn push_game<S: NondetState>(
+        &mut self,
+        metadata: GameMetadata,
+        data_points: &[SelfPlayDataPoint<GameStateWrapper<S>>],
+    ) -> Result<(), String> {
+        self.shard.push_game(metadata, data_points);
+        if self.shard.rows.len() >= self.shard_size {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Write the current shard if it is not empty. Returns the path of the written shard.
+    pub fn flush(&mut self) -> Result<Option<PathBuf>, String> {
+        if self.shard.rows.is_empty() {
+            return Ok(None);
+        }
+        let path = self.dir.join(format!("shard-{:05}.npz", self.next_shard));
+        let shard = DatasetShard::new(self.shard.feature_schema);
+        std::mem::replace(&mut self.shard, shard).save(&path)?;
+        self.next_shard += 1;
+        Ok(Some(path))
+    }
+}
+
+/// Paths of the `.npz` shards in `dir`, sorted by name.
+pub fn shard_paths(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut paths = std::fs::read_dir(dir)
+        .map_err(|e| e.to_string())?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "npz"))
+        .collect::<Vec<_>>();
+    paths.sort();
+    Ok(paths)
+}
+
+/// Load all shards written by `DatasetWriter` into `dir`.
+pub fn load_dataset(dir: &Path) -> Result<Vec<DatasetShard>, String> {
+    shard_paths(dir)?
+        .iter()
+        .map(|path| DatasetShard::load(path).map_err(|e| format!("{}: {e}", path.display())))
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Dtype {
+    F32,
+    U32,
+    U8,
+}
+
+impl Dtype {
+    fn descr(self) -> &'static str {
+        match self {
+            Dtype::F32 => "<f4",
+            Dtype::U32 => "<u4",
+            Dtype::U8 => "|u1",
+        }
+    }
+
+    fn size(self) -> usize {
+        match self {
+            Dtype::F32 | Dtype::U32 => 4,
+            Dtype::U8 => 1,
+        }
+    }
+}
+
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+/// Write a C-order `.npy` (version 1.0) array.
+fn write_npy<W: Write>(writer: &mut W, dtype: Dtype, shape: &[usize], data: &[u8]) -> std::io::Result<()> {
+    let shape_str = match shape {
+        [n] => format!("({n},)"),
+        _ => format!(
+            "({})",
+            shape.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(", ")
+        ),
+    };
+    let mut header = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {shape_str}, }}",
+        dtype.descr()
+    );
+    // Pad so that the data is 64-byte aligned
+    let unpadded = NPY_MAGIC.len() + 2 + 2 + header.len() + 1;
+    header.extend(std::iter::repeat_n(' ', (64 - unpadded % 64) % 64));
+    header.push('\n');
+    writer.write_all(NPY_MAGIC)?;
+    writer.write_all(&[1, 0])?;
+    writer.write_all(&(header.len() as u16).to_le_bytes())?;
+    writer.write_all(header.as_bytes())?;
+    writer.write_all(data)
+}
+
+/// Read a `.npy` array written by `write_npy`.
+fn read_npy<R: Read>(reader: &mut R) -> Result<(Dtype, Vec<usize>, Vec<u8>), String> {
+    let mut prefix = [0u8; 10];
+    reader.read_exact(&mut prefix).map_err(|e| e.to_string())?;
+    if &prefix[..6] != NPY_MAGIC || prefix[6] != 1 {
+        return Err("not a version 1 .npy file".to_string());
+    }
+    let mut header = vec![0u8; u16::from_le_bytes([prefix[8], prefix[9]]) as usize];
+    reader.read_exact(&mut header).map_err(|e| e.to_string())?;
+    let header = String::from_utf8(header).map_err(|e| e.to_string())?;
+    let dtype = [Dtype::F32, Dtype::U32, Dtype::U8]
+        .into_iter()
+        .find(|dtype| header.contains(&format!("'descr': '{}'", dtype.descr())))
+        .ok_or_else(|| format!("unsupported .npy header: {header}"))?;
+    if !header.contains("'fortran_order': False") {
+        return Err("Fortran order .npy arrays are not supported".to_string());
+    }
+    let shape = header
+        .split_once("'shape': (")
+        .and_then(|(_, rest)| rest.split_once(')'))
+        .ok_or_else(|| format!("invalid .npy header: {header}"))?
+        .0
+        .split(',')
+        .map(str::trim)
+        .filter(|d| !d.is_empty())
+        .map(|d| d.parse::<usize>().map_err(|e| e.to_string()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut data = vec![0u8; shape.iter().product::<usize>() * dtype.size()];
+    reader.read_exact(&mut data).map_err(|e| e.to_string())?;
+    Ok((dtype, shape, data))
+}
+
+fn f32_bytes<I: Iterator<Item = f32>>(values: I) -> Vec<u8> {
+    values.flat_map(f32::to_le_bytes).collect()
+}
+
+fn f32s(data: &[u8]) -> Vec<f32> {
+    data.chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+fn u32s(data: &[u8]) -> Vec<u32> {
+    data.chunks_exact(4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use gitcg_sim::{
+        deck::random_decklist,
+        rand::{rngs::SmallRng, SeedableRng},
+    };
+
+    use super::*;
+
+    #[test]
+    fn shard_round_trip() {
+        let mut rng = SmallRng::seed_from_u64(100);
+        let decks = ByPlayer::generate(|_| random_decklist(&mut rng));
+        let feature_schema = FeatureSchema::V2;
+        let row = |game, i: usize, visits| DatasetRow {
+            game,
+            round_number: i as u8,
+            depth: 1,
+            to_move: if i.is_multiple_of(2) {
+                PlayerId::PlayerFirst
+            } else {
+                PlayerId::PlayerSecond
+            },
+            game_state_features: vec![i as f32; feature_schema.n_features()],
+            input_features: [0.5; N_OUT],
+            visits,
+            outcome: if i == 2 { None } else { Some(1.0) },
+        };
+        let end_round = Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound);
+        let switch = Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::SwitchCharacter(1));
+        let shard = DatasetShard {
+            feature_schema,
+            games: vec![
+                GameMetadata {
+                    decks: decks.clone(),
+                    seed: 1,
+                },
+                GameMetadata { decks, seed: 2 },
+            ],
+            rows: vec![
+                row(0, 0, vec![(end_round, 0.25), (switch, 0.75)]),
+                row(0, 1, vec![]),
+                row(1, 2, vec![(switch, 1.0)]),
+            ],
+        };
+        let mut buf = Cursor::new(vec![]);
+        shard.write(&mut buf).unwrap();
+        let loaded = DatasetShard::read(Cursor::new(buf.into_inner())).unwrap();
+        assert_eq!(shard, loaded);
+    }
+
+    #[test]
+    fn npy_header_is_aligned() {
+        let mut buf = vec![];
+        write_npy(&mut buf, Dtype::U8, &[3, 2], &[1, 2, 3, 4, 5, 6]).unwrap();
+        assert!((buf.len() - 6).is_multiple_of(64));
+        let (dtype, shape, data) = read_npy(&mut buf.as_slice()).unwrap();
+        assert_eq!((dtype, shape, data), (Dtype::U8, vec![3, 2], vec![1, 2, 3, 4, 5, 6]));
+    }
+}
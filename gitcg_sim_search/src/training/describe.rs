@@ -0,0 +1,81 @@
+/// Name and index of a feature in the `AsSlice` layout of a feature struct.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FeatureDescriptor {
+    /// Path of the field, e.g. `p1.inactive_chars[0].hp`
+    pub name: String,
+    pub index: usize,
+}
+
+/// Names every element of a feature struct of `f32`s.
+/// Indices are computed from the field offsets, so they match the `AsSlice` layout by construction.
+pub trait DescribeFeatures {
+    fn describe_into(prefix: &str, offset: usize, out: &mut Vec<FeatureDescriptor>);
+
+    /// Descriptors for all features, sorted by index.
+    fn describe() -> Vec<FeatureDescriptor> {
+        let mut out = vec![];
+        Self::describe_into("", 0, &mut out);
+        out.sort_by_key(|d| d.index);
+        out
+    }
+}
+
+impl DescribeFeatures for f32 {
+    fn describe_into(prefix: &str, offset: usize, out: &mut Vec<FeatureDescriptor>) {
+        out.push(FeatureDescriptor {
+            name: prefix.to_string(),
+            index: offset,
+        });
+    }
+}
+
+impl<T: DescribeFeatures, const N: usize> DescribeFeatures for [T; N] {
+    fn describe_into(prefix: &str, offset: usize, out: &mut Vec<FeatureDescriptor>) {
+        let stride = std::mem::size_of::<T>() / std::mem::size_of::<f32>();
+        for i in 0..N {
+            T::describe_into(&format!("{prefix}[{i}]"), offset + i * stride, out);
+        }
+    }
+}
+
+#[doc(hidden)]
+#[inline]
+pub fn describe_field<S, F: DescribeFeatures>(
+    _: impl Fn(&S) -> &F,
+    prefix: &str,
+    name: &str,
+    offset: usize,
+    out: &mut Vec<FeatureDescriptor>,
+) {
+    let name = if prefix.is_empty() {
+        name.to_string()
+    } else {
+        format!("{prefix}.{name}")
+    };
+    F::describe_into(&name, offset, out);
+}
+
+#[macro_export]
+#[doc(hidden)]
+macro_rules! impl_describe_features {
+    ($type: ty { $($field: ident),* $(,)? }) => {
+        impl $crate::training::describe::DescribeFeatures for $type {
+            fn describe_into(
+                prefix: &str,
+                offset: usize,
+                out: &mut Vec<$crate::training::describe::FeatureDescriptor>,
+            ) {
+                $(
+                    $crate::training::describe::describe_field(
+                        |s: &Self| &s.$field,
+                        prefix,
+                        stringify!($field),
+                        offset + std::mem::offset_of!($type, $field) / std::mem::size_of::<f32>(),
+                        out,
+                    );
+                )*
+            }
+        }
+    };
+}
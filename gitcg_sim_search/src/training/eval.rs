@@ -1,11 +1,12 @@
 use std::ops::Neg;
 
 use crate::{
+    evaluator::{Evaluator, LinearEvaluator},
     linked_list,
-    mcts::{policy::EvalPolicy, MCTS},
+    mcts::MCTS,
     minimax::Eval,
     training::{as_slice::AsSlice, features::Features},
-    Game, GameTreeSearch, SearchResult,
+    GameTreeSearch, SearchResult,
 };
 use gitcg_sim::{
     prelude::*,
@@ -60,11 +61,9 @@ impl SelfPlayModel {
     }
 }
 
-impl<S: NondetState> EvalPolicy<GameStateWrapper<S>> for SelfPlayModel {
-    fn evaluate(&self, state: &GameStateWrapper<S>, player_id: PlayerId) -> <GameStateWrapper<S> as Game>::Eval {
-        let v = SelfPlayModel::evaluate(self, state).0;
-        let v = if player_id == self.player_id { v } else { 1.0 - v };
-        Eval::from_eval((v * 1e3) as i16)
+impl<S: NondetState> Evaluator<GameStateWrapper<S>> for SelfPlayModel {
+    fn evaluate(&self, state: &GameStateWrapper<S>, player_id: PlayerId) -> Eval {
+        LinearEvaluator::evaluate_with_weights(&self.weights, self.player_id, state, player_id)
     }
 }
 
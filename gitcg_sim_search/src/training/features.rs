@@ -1,6 +1,12 @@
 use std::ops::Add;
 
-use crate::impl_as_slice;
+use crate::{
+    impl_as_slice, impl_describe_features,
+    training::{
+        as_slice::AsSlice,
+        describe::{DescribeFeatures, FeatureDescriptor},
+    },
+};
 use gitcg_sim::{
     enum_map::Enum,
     prelude::{card_defs::*, tcg_model::*, *},
@@ -101,6 +107,23 @@ pub struct GameStateFeatures<T> {
 impl_as_slice!(PlayerStateFeatures<f32>, f32);
 impl_as_slice!(GameStateFeatures<f32>, f32);
 
+impl_describe_features!(TurnFeatures<f32> { own_turn, opp_ended_round });
+impl_describe_features!(DiceFeatures<f32> { on_count, off_count });
+impl_describe_features!(TeamStatusFeatures<f32> { status_count, summon_count, support_count });
+impl_describe_features!(CharStatusFeatures<f32> { equip_count, status_count });
+impl_describe_features!(CharFeatures<f32> { is_active, is_alive, hp, energy, applied_count, status });
+impl_describe_features!(CanPerformFeatures<f32> { switch, card, skill });
+impl_describe_features!(PlayerStateFeatures<f32> {
+    can_perform,
+    turn,
+    switch_is_fast_action,
+    dice,
+    hand_count,
+    team,
+    chars
+});
+impl_describe_features!(GameStateFeatures<f32> { p1, p2 });
+
 #[repr(C)]
 #[derive(Default, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -140,6 +163,55 @@ impl_as_slice!(ExpressCharFeatures<f32>, f32);
 impl_as_slice!(ExpressPlayerStateFeatures<f32>, f32);
 impl_as_slice!(ExpressGameStateFeatures<f32>, f32);
 
+impl_describe_features!(ExpressCharFeatures<f32> { has_applied, hp, energy, status_count });
+impl_describe_features!(ExpressPlayerStateFeatures<f32> {
+    turn,
+    dice,
+    hand_count,
+    team_status_count,
+    active_char,
+    inactive_chars,
+    char_ids
+});
+impl_describe_features!(ExpressGameStateFeatures<f32> { p1, p2 });
+
+/// Capacities of the per-ID encodings, in chunks of 32 IDs (for serde compatibility).
+/// Kept above the number of IDs so that adding cards does not change the layout.
+pub const N_CARD_ID_CHUNKS: usize = 5;
+pub const N_STATUS_ID_CHUNKS: usize = 5;
+pub const N_SUMMON_ID_CHUNKS: usize = 2;
+pub const N_SUPPORT_ID_CHUNKS: usize = 1;
+
+#[repr(C)]
+#[derive(Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CardPlayerStateFeatures<T> {
+    /// Number of copies of each `CardId` in hand
+    pub hand: [[T; 32]; N_CARD_ID_CHUNKS],
+    /// Equipped `StatusId`s of each character
+    pub equipment: [[[T; 32]; N_STATUS_ID_CHUNKS]; N_CHARS],
+    /// Usages of each `SummonId` (1 for summons without usages)
+    pub summons: [[T; 32]; N_SUMMON_ID_CHUNKS],
+    /// Usages of each `SupportId` (1 for supports without usages), summed over the support slots
+    pub supports: [[T; 32]; N_SUPPORT_ID_CHUNKS],
+}
+
+/// `ExpressGameStateFeatures` followed by the identities of the cards in hand, equipment, summons and supports.
+#[repr(C)]
+#[derive(Default, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExtendedGameStateFeatures<T> {
+    pub express: ExpressGameStateFeatures<T>,
+    pub p1: CardPlayerStateFeatures<T>,
+    pub p2: CardPlayerStateFeatures<T>,
+}
+
+impl_as_slice!(CardPlayerStateFeatures<f32>, f32);
+impl_as_slice!(ExtendedGameStateFeatures<f32>, f32);
+
+impl_describe_features!(CardPlayerStateFeatures<f32> { hand, equipment, summons, supports });
+impl_describe_features!(ExtendedGameStateFeatures<f32> { express, p1, p2 });
+
 trait BoolValue {
     fn bv(self) -> f32;
 }
@@ -338,6 +410,122 @@ pub mod game_state_features {
             p2: express_player_state_features(game_state, PlayerId::PlayerSecond),
         }
     }
+
+    #[inline]
+    fn add_by_id<const N: usize>(arr: &mut [[f32; 32]; N], id: usize, value: f32) {
+        debug_assert!(id < 32 * N, "add_by_id: ID {id} out of range");
+        if let Some(v) = arr.get_mut(id / 32).map(|chunk| &mut chunk[id % 32]) {
+            *v += value;
+        }
+    }
+
+    fn card_player_state_features<P: GameStateParams>(
+        game_state: &GameState<P>,
+        player_id: PlayerId,
+    ) -> CardPlayerStateFeatures<f32> {
+        let mut features: CardPlayerStateFeatures<f32> = Default::default();
+        for card_id in game_state.player(player_id).hand() {
+            add_by_id(&mut features.hand, card_id.into_usize(), 1.0);
+        }
+        for entry in game_state.status_collection(player_id).iter_entries() {
+            let usages = entry.state.usages().max(1) as f32;
+            match entry.key {
+                StatusKey::Equipment(char_idx, _, status_id) if (char_idx as usize) < N_CHARS => {
+                    add_by_id(&mut features.equipment[char_idx as usize], status_id.into_usize(), 1.0);
+                }
+                StatusKey::Summon(summon_id) => add_by_id(&mut features.summons, summon_id.into_usize(), usages),
+                StatusKey::Support(_, support_id) => add_by_id(&mut features.supports, support_id.into_usize(), usages),
+                _ => {}
+            }
+        }
+        features
+    }
+
+    /// Features for `FeatureSchema::V2`.
+    pub fn card_features<P: GameStateParams>(game_state: &GameState<P>) -> ExtendedGameStateFeatures<f32> {
+        ExtendedGameStateFeatures {
+            express: features(game_state),
+            p1: card_player_state_features(game_state, PlayerId::PlayerFirst),
+            p2: card_player_state_features(game_state, PlayerId::PlayerSecond),
+        }
+    }
+}
+
+/// Version of the game state features. Weights and datasets are tied to the schema they were created with,
+/// so new schemas are added as new versions instead of changing existing ones.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum FeatureSchema {
+    /// `Features` (`ExpressGameStateFeatures`), used by the policy network and the TDL evaluator.
+    #[default]
+    V1,
+    /// `ExtendedFeatures`: `V1` followed by the per-card features (`CardPlayerStateFeatures`).
+    V2,
+}
+
+impl FeatureSchema {
+    pub const LATEST: Self = Self::V2;
+
+    pub fn version(self) -> u32 {
+        match self {
+            Self::V1 => 1,
+            Self::V2 => 2,
+        }
+    }
+
+    pub fn from_version(version: u32) -> Option<Self> {
+        match version {
+            1 => Some(Self::V1),
+            2 => Some(Self::V2),
+            _ => None,
+        }
+    }
+
+    /// Length of the feature vector.
+    pub fn n_features(self) -> usize {
+        match self {
+            Self::V1 => <Features as AsSlice<f32>>::LENGTH,
+            Self::V2 => <ExtendedFeatures as AsSlice<f32>>::LENGTH,
+        }
+    }
+
+    pub fn features<P: GameStateParams>(self, game_state: &GameState<P>) -> Vec<f32> {
+        match self {
+            Self::V1 => game_state_features::features(game_state).as_slice().to_vec(),
+            Self::V2 => game_state_features::card_features(game_state).as_slice().to_vec(),
+        }
+    }
+
+    /// Name and index of each feature.
+    pub fn describe(self) -> Vec<FeatureDescriptor> {
+        match self {
+            Self::V1 => Features::describe(),
+            Self::V2 => ExtendedFeatures::describe(),
+        }
+    }
+
+    /// Features from the perspective of `player_id` (transposed if `player_id` is `PlayerSecond`).
+    pub fn player_features<P: GameStateParams>(self, game_state: &GameState<P>, player_id: PlayerId) -> Vec<f32> {
+        if player_id == PlayerId::PlayerFirst {
+            return self.features(game_state);
+        }
+        let mut game_state = game_state.clone();
+        game_state.transpose_in_place();
+        self.features(&game_state)
+    }
+}
+
+impl std::str::FromStr for FeatureSchema {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let version = s.trim_start_matches(['v', 'V']);
+        version
+            .parse::<u32>()
+            .ok()
+            .and_then(Self::from_version)
+            .ok_or_else(|| format!("Invalid feature schema: {s}"))
+    }
 }
 
 #[repr(C)]
@@ -359,8 +547,7 @@ pub struct CastSkillFeatures<T> {
 }
 
 /// Struct for the feature vector describing a player action.
-/// Invariant: For any given player action, only ONE field of the features is non-zero.
-/// TODO write a property test on this invariant
+/// Invariant: For any given player action, at most ONE field of the features is non-zero.
 #[repr(C)]
 #[derive(Default, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -376,6 +563,10 @@ impl_as_slice!(InputFeatures<f32>, f32);
 
 impl_as_slice!(InputFeatures<GameStateFeatures<f32>>, f32);
 
+impl_describe_features!(PlayCardFeatures<f32> { event_or_other, support, weapon_or_artifact });
+impl_describe_features!(CastSkillFeatures<f32> { normal_attack, elemental_skill, elemental_burst });
+impl_describe_features!(InputFeatures<f32> { end_round, switch, elemental_tuning, cast_skill, play_card });
+
 macro_rules! vf {
     ($T: ident, $f: ident : $value: expr) => {
         $T {
@@ -452,14 +643,51 @@ pub mod input_features {
 
 pub type Features = ExpressGameStateFeatures<f32>;
 
+pub type ExtendedFeatures = ExtendedGameStateFeatures<f32>;
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::training::as_slice::AsSlice;
+    use gitcg_sim::{
+        deck::random_decklist,
+        generator::{arb_reachable_game_state, arb_reachable_game_state_wrapper_with_action},
+        rand::{rngs::SmallRng, SeedableRng},
+    };
     use proptest::prelude::*;
 
     type Slice = <InputFeatures<f32> as AsSlice<f32>>::Slice;
 
+    #[test]
+    fn test_card_features_extend_v1() {
+        let mut rng = SmallRng::seed_from_u64(100);
+        let decklists = ByPlayer::generate(|_| random_decklist(&mut rng));
+        let game = new_standard_game(decklists.as_ref(), rng);
+        let gs = &game.game_state;
+        let v1 = FeatureSchema::V1.features(gs);
+        let v2 = FeatureSchema::V2.features(gs);
+        assert_eq!(v1.len(), FeatureSchema::V1.n_features());
+        assert_eq!(v2.len(), FeatureSchema::V2.n_features());
+        assert_eq!(&v2[..v1.len()], &v1[..]);
+
+        let card_features = game_state_features::card_features(gs);
+        let hand_count: f32 = card_features.p1.hand.iter().flatten().sum();
+        assert_eq!(hand_count, gs.player(PlayerId::PlayerFirst).hand_len() as f32);
+        for card_id in gs.player(PlayerId::PlayerFirst).hand() {
+            let n = card_id.into_usize();
+            assert!(card_features.p1.hand[n / 32][n % 32] >= 1.0);
+        }
+    }
+
+    #[test]
+    fn test_feature_schema_versions() {
+        for schema in [FeatureSchema::V1, FeatureSchema::V2] {
+            assert_eq!(FeatureSchema::from_version(schema.version()), Some(schema));
+            assert_eq!(format!("v{}", schema.version()).parse::<FeatureSchema>(), Ok(schema));
+        }
+        assert_eq!(FeatureSchema::from_version(0), None);
+    }
+
     proptest! {
         #[test]
         fn test_input_features_as_slice_roundtrip(slice in any::<Slice>()) {
@@ -468,4 +696,124 @@ mod test {
             assert_eq!(slice1, slice);
         }
     }
+
+    fn assert_describes_layout(descriptors: &[FeatureDescriptor], length: usize) {
+        assert_eq!(descriptors.len(), length);
+        for (i, d) in descriptors.iter().enumerate() {
+            assert_eq!(d.index, i, "{}", d.name);
+        }
+        let names: std::collections::HashSet<_> = descriptors.iter().map(|d| &d.name).collect();
+        assert_eq!(names.len(), length);
+    }
+
+    #[test]
+    fn test_describe_covers_layout() {
+        assert_describes_layout(&Features::describe(), <Features as AsSlice<f32>>::LENGTH);
+        assert_describes_layout(
+            &ExtendedFeatures::describe(),
+            <ExtendedFeatures as AsSlice<f32>>::LENGTH,
+        );
+        assert_describes_layout(
+            &GameStateFeatures::<f32>::describe(),
+            <GameStateFeatures<f32> as AsSlice<f32>>::LENGTH,
+        );
+        assert_describes_layout(
+            &InputFeatures::<f32>::describe(),
+            <InputFeatures<f32> as AsSlice<f32>>::LENGTH,
+        );
+    }
+
+    #[test]
+    fn test_describe_is_stable() {
+        let v1 = FeatureSchema::V1.describe();
+        assert_eq!(v1.len(), 812);
+        assert_eq!(v1[0].name, "p1.turn.own_turn");
+        assert_eq!(v1[5].name, "p1.team_status_count");
+        assert_eq!(v1[6].name, "p1.active_char.has_applied");
+        assert_eq!(v1[811].name, "p2.char_ids[2][3][31]");
+        let v2 = FeatureSchema::V2.describe();
+        assert_eq!(
+            &v2[..v1.len()].iter().map(|d| &d.name[..]).collect::<Vec<_>>()[..5],
+            [
+                "express.p1.turn.own_turn",
+                "express.p1.turn.opp_ended_round",
+                "express.p1.dice.on_count",
+                "express.p1.dice.off_count",
+                "express.p1.hand_count",
+            ]
+        );
+        assert_eq!(v2[812].name, "p1.hand[0][0]");
+        let names: Vec<_> = InputFeatures::<f32>::describe().into_iter().map(|d| d.name).collect();
+        assert_eq!(
+            names,
+            [
+                "end_round",
+                "switch[0]",
+                "switch[1]",
+                "switch[2]",
+                "elemental_tuning",
+                "cast_skill.normal_attack",
+                "cast_skill.elemental_skill",
+                "cast_skill.elemental_burst",
+                "play_card.event_or_other",
+                "play_card.support",
+                "play_card.weapon_or_artifact[0]",
+                "play_card.weapon_or_artifact[1]",
+                "play_card.weapon_or_artifact[2]",
+            ]
+        );
+    }
+
+    fn swap_players(features: ExtendedFeatures) -> ExtendedFeatures {
+        ExtendedFeatures {
+            express: ExpressGameStateFeatures {
+                p1: features.express.p2,
+                p2: features.express.p1,
+            },
+            p1: features.p2,
+            p2: features.p1,
+        }
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig {
+            cases: 500,
+            ..ProptestConfig::default()
+        })]
+
+        #[test]
+        fn test_features_are_bounded(gs in arb_reachable_game_state()) {
+            for (d, v) in FeatureSchema::V2.describe().iter().zip(FeatureSchema::V2.features(&gs)) {
+                prop_assert!(v.is_finite() && (0.0..=100.0).contains(&v), "{} = {}", d.name, v);
+            }
+        }
+
+        #[test]
+        fn test_features_are_transpose_symmetric(gs in arb_reachable_game_state()) {
+            let mut transposed = gs.clone();
+            transposed.transpose_in_place();
+            let f = game_state_features::card_features(&gs);
+            let f_t = game_state_features::card_features(&transposed);
+            prop_assert_eq!(swap_players(f).as_slice().to_vec(), f_t.as_slice().to_vec());
+            prop_assert_eq!(
+                FeatureSchema::V2.player_features(&gs, PlayerId::PlayerSecond),
+                FeatureSchema::V2.features(&transposed)
+            );
+        }
+
+        #[test]
+        fn test_features_are_stable(gs in arb_reachable_game_state()) {
+            let f1 = FeatureSchema::V2.features(&gs);
+            let mut gs1 = gs.clone();
+            gs1.rehash();
+            prop_assert_eq!(f1, FeatureSchema::V2.features(&gs1));
+        }
+
+        #[test]
+        fn test_input_features_at_most_one_non_zero((_, input) in arb_reachable_game_state_wrapper_with_action()) {
+            let features = input_features::input_features(input, 1.0f32).as_slice();
+            prop_assert!(features.iter().filter(|&&v| v != 0.0).count() <= 1, "{:?}", input);
+            prop_assert!(features.iter().all(|&v| v == 0.0 || v == 1.0));
+        }
+    }
 }
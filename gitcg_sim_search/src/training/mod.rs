@@ -1,5 +1,8 @@
 pub mod as_slice;
 
+/// Names and indices of the features, for inspecting trained weights
+pub mod describe;
+
 pub mod features;
 
 #[cfg(feature = "training")]
@@ -7,6 +10,16 @@ pub mod eval;
 
 pub mod policy;
 
+/// Policy network weights loaded from a file and evaluated without `dfdx`
+pub mod runtime_model;
+
+/// Combined policy and value network for the AlphaZero-style training loop
+pub mod policy_value;
+
+/// Sharded `.npz` export of self-play data, for running data generation and training as separate jobs
+#[cfg(feature = "training")]
+pub mod dataset;
+
 #[cfg(not(feature = "hidden_layer"))]
 pub(crate) mod hard_coded_model;
 
@@ -2,12 +2,14 @@
 use dfdx::prelude::*;
 #[cfg(feature = "training")]
 use std::path::PathBuf;
+use std::{path::Path, sync::Arc};
 
 use crate::{
     mcts::policy::*,
     training::{
         as_slice::*,
         features::{Features, InputFeatures},
+        runtime_model::RuntimeModel,
     },
     Game,
 };
@@ -31,6 +33,8 @@ pub struct PolicyNetwork {
     #[allow(dead_code)]
     /// Use hard-coded model
     hard_coded: bool,
+    /// Weights loaded at runtime, used instead of the hard-coded model
+    runtime: Option<Arc<RuntimeModel>>,
     #[cfg(feature = "training")]
     pub dev: Cpu,
     #[cfg(feature = "training")]
@@ -49,7 +53,10 @@ impl<T: Copy> TensorWrapper<T> {
 #[cfg(not(feature = "training"))]
 impl PolicyNetwork {
     pub fn new_hard_coded() -> Self {
-        Self { hard_coded: true }
+        Self {
+            hard_coded: true,
+            runtime: None,
+        }
     }
 
     pub fn new() -> Self {
@@ -57,7 +64,25 @@ impl PolicyNetwork {
     }
 
     pub fn eval(&self, x_slice: &[f32; N_IN]) -> TensorWrapper<[f32; N_OUT]> {
-        TensorWrapper(evaluate_hard_coded_policy(x_slice))
+        match &self.runtime {
+            Some(model) => TensorWrapper(model.eval(x_slice)),
+            None => TensorWrapper(evaluate_hard_coded_policy(x_slice)),
+        }
+    }
+}
+
+impl PolicyNetwork {
+    /// Use weights loaded at runtime (see `RuntimeModel`) instead of the hard-coded model.
+    pub fn from_runtime_model(model: RuntimeModel) -> Self {
+        Self {
+            runtime: Some(Arc::new(model)),
+            ..Self::new_hard_coded()
+        }
+    }
+
+    /// Load the weights file saved by `RuntimeModel::save`.
+    pub fn from_weights_file(path: &Path) -> Result<Self, String> {
+        Ok(Self::from_runtime_model(RuntimeModel::load(path)?))
     }
 }
 
@@ -67,6 +92,7 @@ impl PolicyNetwork {
         let dev = Cpu::default();
         Self {
             hard_coded: false,
+            runtime: None,
             dev: dev.clone(),
             model: dev.build_module::<Model, f32>(),
         }
@@ -76,6 +102,7 @@ impl PolicyNetwork {
         let dev = Cpu::default();
         Self {
             hard_coded: true,
+            runtime: None,
             dev: dev.clone(),
             model: dev.build_module::<Model, f32>(),
         }
@@ -122,6 +149,11 @@ impl PolicyNetwork {
     }
 
     pub fn eval(&self, x_slice: &[f32; N_IN]) -> Tensor<Rank1<N_OUT>, f32, Cpu> {
+        if let Some(runtime) = &self.runtime {
+            let mut y = self.dev.zeros();
+            y.copy_from(&runtime.eval(x_slice));
+            return y;
+        }
         let model = &self.model;
         let mut x = self.alloc_x::<1>();
         x.copy_from(x_slice);
@@ -135,6 +167,47 @@ impl PolicyNetwork {
     pub fn save_npz(&mut self, path: &PathBuf) -> Result<(), String> {
         self.model.save(path).map_err(|e| e.to_string())
     }
+
+    /// Copy the `dfdx` model weights for inference without the `training` feature.
+    #[cfg(not(feature = "hidden_layer"))]
+    pub fn to_runtime_model(&self) -> RuntimeModel {
+        use super::runtime_model::DenseLayer;
+        let (lin, _) = &self.model;
+        let weight = lin.weight.clone().reshape::<Rank1<{ N_IN * N_OUT }>>().array();
+        RuntimeModel {
+            layers: vec![DenseLayer {
+                n_in: N_IN,
+                n_out: N_OUT,
+                weight: weight.to_vec(),
+                bias: lin.bias.array().to_vec(),
+            }],
+        }
+    }
+
+    /// Copy the `dfdx` model weights for inference without the `training` feature.
+    #[cfg(feature = "hidden_layer")]
+    pub fn to_runtime_model(&self) -> RuntimeModel {
+        use super::runtime_model::DenseLayer;
+        let (lin1, _, lin2, _) = &self.model;
+        let weight1 = lin1.weight.clone().reshape::<Rank1<{ N_IN * N_HIDDEN }>>().array();
+        let weight2 = lin2.weight.clone().reshape::<Rank1<{ N_HIDDEN * N_OUT }>>().array();
+        RuntimeModel {
+            layers: vec![
+                DenseLayer {
+                    n_in: N_IN,
+                    n_out: N_HIDDEN,
+                    weight: weight1.to_vec(),
+                    bias: lin1.bias.array().to_vec(),
+                },
+                DenseLayer {
+                    n_in: N_HIDDEN,
+                    n_out: N_OUT,
+                    weight: weight2.to_vec(),
+                    bias: lin2.bias.array().to_vec(),
+                },
+            ],
+        }
+    }
 }
 
 impl Default for PolicyNetwork {
@@ -161,6 +234,27 @@ impl PolicyNetwork {
         input_features::input_features(action, 1f32).as_slice()
     }
 
+    /// Training target for the policy from the weights of the available actions,
+    /// where each input feature is the average weight of the actions having that feature.
+    pub fn policy_target(action_weights: &[(Action, f32)]) -> InputFeatures<f32> {
+        let mut weighted_features = <InputFeatures<f32> as AsSlice<f32>>::Slice::default();
+        let avg_w = action_weights.iter().copied().map(|(_, w)| w).sum::<f32>() / (weighted_features.len() as f32);
+        let action_features = action_weights
+            .iter()
+            .map(|&(action, weight)| (Self::features_slice(action), weight))
+            .collect::<SmallVec<[_; 16]>>();
+        for (i, wi) in weighted_features.iter_mut().enumerate() {
+            let mut tot_dot = 0.0;
+            let mut tot_weight = 0.0;
+            for (features, weight) in action_features.iter() {
+                tot_weight += features[i] * weight;
+                tot_dot += features[i];
+            }
+            *wi = if tot_dot < 1e-3 { avg_w } else { tot_weight / tot_dot };
+        }
+        InputFeatures::from_slice(weighted_features)
+    }
+
     pub(crate) fn action_value_hard_coded(action: Action, y: &[f32; N_OUT]) -> f32 {
         let w = Self::features_slice(action);
         w.iter().zip(y).map(|(wi, yi)| wi * yi).sum()
@@ -0,0 +1,240 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+    sync::Arc,
+};
+
+use gitcg_sim::{prelude::*, smallvec::SmallVec};
+
+use crate::{
+    evaluator::Evaluator,
+    mcts::policy::{SelectionPolicy, SelectionPolicyChildContext, SelectionPolicyContext},
+    minimax::Eval,
+    training::{
+        as_slice::AsSlice,
+        features::{game_state_features, Features},
+        policy::{PolicyNetwork, SelectionPolicyState, N_IN, N_OUT},
+        runtime_model::{read_header, DenseLayer},
+    },
+    Game,
+};
+
+/// Policy and value network with a shared trunk, as trained by the AlphaZero-style self-play loop.
+/// Inputs are the game state features from the perspective of the player to move
+/// (transposed if the player to move is `PlayerSecond`).
+/// The policy head predicts the MCTS visit distribution in terms of the input features,
+/// and the value head predicts the probability that the player to move wins.
+///
+/// File format (little-endian): magic `GCPV`, `u32` version, followed by the trunk, policy and value layers
+/// (see `RuntimeModel`).
+#[derive(Debug, Clone)]
+pub struct PolicyValueNetwork {
+    pub trunk: Arc<DenseLayer>,
+    pub policy: Arc<DenseLayer>,
+    pub value: Arc<DenseLayer>,
+}
+
+impl PolicyValueNetwork {
+    pub const MAGIC: [u8; 4] = *b"GCPV";
+    pub const VERSION: u32 = 1;
+    /// Evaluation per unit of logit of the win probability, see `SelfPlayModel::EVAL_SCALING`.
+    pub const EVAL_SCALING: f32 = 100.0 / 1.25;
+
+    pub fn new(trunk: DenseLayer, policy: DenseLayer, value: DenseLayer) -> Result<Self, String> {
+        if trunk.n_in != N_IN {
+            return Err(format!("trunk has {} inputs, expected {N_IN}", trunk.n_in));
+        }
+        if policy.n_in != trunk.n_out || value.n_in != trunk.n_out {
+            return Err(format!(
+                "head inputs {} and {} do not match the trunk output {}",
+                policy.n_in, value.n_in, trunk.n_out
+            ));
+        }
+        if policy.n_out != N_OUT || value.n_out != 1 {
+            return Err(format!(
+                "head outputs {} and {} do not match {N_OUT} and 1",
+                policy.n_out, value.n_out
+            ));
+        }
+        Ok(Self {
+            trunk: Arc::new(trunk),
+            policy: Arc::new(policy),
+            value: Arc::new(value),
+        })
+    }
+
+    /// Game state features from the perspective of `player_id`.
+    pub fn features<P: GameStateParams>(game_state: &GameState<P>, player_id: PlayerId) -> [f32; N_IN] {
+        if player_id == PlayerId::PlayerFirst {
+            return <Features as AsSlice<f32>>::as_slice(game_state_features::features(game_state));
+        }
+        let mut game_state = game_state.clone();
+        game_state.transpose_in_place();
+        <Features as AsSlice<f32>>::as_slice(game_state_features::features(&game_state))
+    }
+
+    /// Evaluate the policy and the value heads.
+    pub fn eval(&self, input: &[f32; N_IN]) -> ([f32; N_OUT], f32) {
+        let (mut hidden, mut y) = (vec![], vec![]);
+        self.trunk.forward(input, &mut hidden);
+        self.policy.forward(&hidden, &mut y);
+        let mut policy = [0f32; N_OUT];
+        policy.copy_from_slice(&y);
+        self.value.forward(&hidden, &mut y);
+        (policy, y[0])
+    }
+
+    /// Win probability of the player whose perspective the input features are from.
+    pub fn value(&self, input: &[f32; N_IN]) -> f32 {
+        let (mut hidden, mut y) = (vec![], vec![]);
+        self.trunk.forward(input, &mut hidden);
+        self.value.forward(&hidden, &mut y);
+        y[0]
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| e.to_string())?;
+        Self::read(&mut BufReader::new(file))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let file = File::create(path).map_err(|e| e.to_string())?;
+        let mut writer = BufWriter::new(file);
+        self.write(&mut writer).map_err(|e| e.to_string())?;
+        writer.flush().map_err(|e| e.to_string())
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, String> {
+        read_header(reader, Self::MAGIC, Self::VERSION)?;
+        let trunk = DenseLayer::read(reader)?;
+        let policy = DenseLayer::read(reader)?;
+        let value = DenseLayer::read(reader)?;
+        Self::new(trunk, policy, value)
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&Self::MAGIC)?;
+        writer.write_all(&Self::VERSION.to_le_bytes())?;
+        self.trunk.write(writer)?;
+        self.policy.write(writer)?;
+        self.value.write(writer)
+    }
+}
+
+impl<S: NondetState> Evaluator<GameStateWrapper<S>> for PolicyValueNetwork {
+    fn evaluate(&self, state: &GameStateWrapper<S>, player_id: PlayerId) -> Eval {
+        if let Some(winner) = state.winner() {
+            return if winner == player_id {
+                Eval::win(0)
+            } else {
+                Eval::lose(0)
+            };
+        }
+        let Some(to_move) = state.to_move() else {
+            return state.eval(player_id);
+        };
+        let p = self
+            .value(&Self::features(&state.game_state, to_move))
+            .clamp(1e-4, 1.0 - 1e-4);
+        let v = Self::EVAL_SCALING * (p / (1.0 - p)).ln();
+        let v = if player_id == to_move { v } else { -v };
+        Eval::from_eval(v as i16)
+    }
+}
+
+impl<S: NondetState> SelectionPolicy<GameStateWrapper<S>> for PolicyValueNetwork {
+    type State = SelectionPolicyState;
+
+    fn on_parent<F: FnOnce() -> <GameStateWrapper<S> as Game>::Actions>(
+        &self,
+        ctx: &SelectionPolicyContext<GameStateWrapper<S>>,
+        children: F,
+    ) -> Self::State {
+        let parent = ctx.parent;
+        let to_move = parent.state.to_move().unwrap_or(PlayerId::PlayerFirst);
+        let (y, _) = self.eval(&Self::features(&parent.state.game_state, to_move));
+        let mut denominator = 1e-5;
+        let evals: SmallVec<_> = children()
+            .iter()
+            .map(|&action| {
+                let v = ctx
+                    .config
+                    .policy_softmax(PolicyNetwork::action_value_hard_coded(action, &y));
+                denominator += v;
+                v
+            })
+            .collect();
+        let n = parent.prop.n + 1;
+        SelectionPolicyState {
+            puct_mult: Self::cpuct(ctx, parent.prop.n) * (n as f32).sqrt(),
+            evals,
+            denominator,
+        }
+    }
+
+    fn policy(
+        &self,
+        _: &SelectionPolicyContext<GameStateWrapper<S>>,
+        cctx: &SelectionPolicyChildContext<GameStateWrapper<S>, Self::State>,
+    ) -> f32 {
+        cctx.state.evals[cctx.index] / cctx.state.denominator
+    }
+
+    fn uct_child(
+        &self,
+        _: &SelectionPolicyContext<GameStateWrapper<S>>,
+        cctx: &SelectionPolicyChildContext<GameStateWrapper<S>, Self::State>,
+        policy_value: f32,
+    ) -> f32 {
+        let n = cctx.child.prop.n;
+        let fpu = if n < 1 { 1.0 } else { 0.0 };
+        policy_value * cctx.state.puct_mult / ((n + 1) as f32) + fpu
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gitcg_sim::{
+        deck::random_decklist,
+        rand::{rngs::SmallRng, Rng, SeedableRng},
+    };
+
+    use super::*;
+
+    fn random_layer<R: Rng>(rng: &mut R, n_in: usize, n_out: usize) -> DenseLayer {
+        let weight = (0..n_in * n_out).map(|_| rng.gen_range(-0.1..0.1)).collect();
+        let bias = (0..n_out).map(|_| rng.gen_range(-0.1..0.1)).collect();
+        DenseLayer::new(n_in, n_out, weight, bias).unwrap()
+    }
+
+    fn random_network(seed: u64) -> PolicyValueNetwork {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let trunk = random_layer(&mut rng, N_IN, 8);
+        let policy = random_layer(&mut rng, 8, N_OUT);
+        let value = random_layer(&mut rng, 8, 1);
+        PolicyValueNetwork::new(trunk, policy, value).unwrap()
+    }
+
+    #[test]
+    fn round_trip_preserves_outputs() {
+        let network = random_network(1);
+        let mut buf = vec![];
+        network.write(&mut buf).unwrap();
+        let loaded = PolicyValueNetwork::read(&mut buf.as_slice()).unwrap();
+        let input = [0.5f32; N_IN];
+        assert_eq!(network.eval(&input), loaded.eval(&input));
+        assert!(PolicyValueNetwork::read(&mut &buf[..buf.len() - 4]).is_err());
+    }
+
+    #[test]
+    fn value_evaluation_is_zero_sum() {
+        let network = random_network(2);
+        let mut rng = SmallRng::seed_from_u64(100);
+        let decklists = ByPlayer::generate(|_| random_decklist(&mut rng));
+        let game = new_standard_game(decklists.as_ref(), rng);
+        let e1 = network.evaluate(&game, PlayerId::PlayerFirst);
+        let e2 = network.evaluate(&game, PlayerId::PlayerSecond);
+        assert_eq!(e1, -e2);
+    }
+}
@@ -0,0 +1,271 @@
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Write},
+    path::Path,
+};
+
+use super::policy::{N_IN, N_OUT};
+
+/// Fully connected layer followed by a sigmoid activation.
+/// `weight` is stored row-major with `n_out` rows of `n_in` columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DenseLayer {
+    pub n_in: usize,
+    pub n_out: usize,
+    pub weight: Vec<f32>,
+    pub bias: Vec<f32>,
+}
+
+impl DenseLayer {
+    /// Maximum number of inputs or outputs of a layer read from a file.
+    /// Checked before allocating the weights so that corrupted headers are rejected.
+    pub const MAX_SIZE: usize = 4096;
+
+    pub fn new(n_in: usize, n_out: usize, weight: Vec<f32>, bias: Vec<f32>) -> Result<Self, String> {
+        if weight.len() != n_in * n_out || bias.len() != n_out {
+            return Err(format!(
+                "layer {n_in}x{n_out}: expected {} weights and {n_out} biases, got {} and {}",
+                n_in * n_out,
+                weight.len(),
+                bias.len()
+            ));
+        }
+        Ok(Self {
+            n_in,
+            n_out,
+            weight,
+            bias,
+        })
+    }
+
+    pub(crate) fn read<R: Read>(reader: &mut R) -> Result<Self, String> {
+        let n_in = read_u32(reader)? as usize;
+        let n_out = read_u32(reader)? as usize;
+        if n_in > Self::MAX_SIZE || n_out > Self::MAX_SIZE {
+            return Err(format!(
+                "layer {n_in}x{n_out}: size exceeds the maximum of {}",
+                Self::MAX_SIZE
+            ));
+        }
+        let weight = read_f32s(reader, n_in * n_out)?;
+        let bias = read_f32s(reader, n_out)?;
+        Self::new(n_in, n_out, weight, bias)
+    }
+
+    pub(crate) fn write<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&(self.n_in as u32).to_le_bytes())?;
+        writer.write_all(&(self.n_out as u32).to_le_bytes())?;
+        for v in self.weight.iter().chain(&self.bias) {
+            writer.write_all(&v.to_le_bytes())?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn forward(&self, input: &[f32], output: &mut Vec<f32>) {
+        output.clear();
+        output.extend(self.weight.chunks_exact(self.n_in).zip(&self.bias).map(|(row, &b)| {
+            let s = b + row.iter().zip(input).map(|(w, x)| w * x).sum::<f32>();
+            1.0 / (1.0 + (-s).exp())
+        }));
+    }
+}
+
+/// Policy network weights loaded at runtime and evaluated in plain Rust, without `dfdx`.
+/// Supports the linear model and the model with hidden layers.
+///
+/// File format (little-endian):
+/// - magic `GCPN`, `u32` version, `u32` number of layers
+/// - for each layer: `u32` inputs, `u32` outputs, `f32` weights (outputs x inputs), `f32` biases
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeModel {
+    pub layers: Vec<DenseLayer>,
+}
+
+impl RuntimeModel {
+    pub const MAGIC: [u8; 4] = *b"GCPN";
+    pub const VERSION: u32 = 1;
+    /// Maximum number of layers read from a file.
+    pub const MAX_LAYERS: u32 = 16;
+
+    pub fn new(layers: Vec<DenseLayer>) -> Result<Self, String> {
+        let (Some(first), Some(last)) = (layers.first(), layers.last()) else {
+            return Err("model has no layers".to_string());
+        };
+        if first.n_in != N_IN || last.n_out != N_OUT {
+            return Err(format!(
+                "model shape {}->{} does not match the features {N_IN}->{N_OUT}",
+                first.n_in, last.n_out
+            ));
+        }
+        if let Some(w) = layers.windows(2).find(|w| w[0].n_out != w[1].n_in) {
+            return Err(format!("layer sizes {} and {} do not match", w[0].n_out, w[1].n_in));
+        }
+        Ok(Self { layers })
+    }
+
+    /// The model compiled into `hard_coded_model.rs` (or `hard_coded_model_hidden_layer.rs`).
+    #[cfg(not(feature = "hidden_layer"))]
+    pub fn from_hard_coded() -> Self {
+        use super::hard_coded_model::{LIN_BIAS, LIN_WEIGHT};
+        Self {
+            layers: vec![DenseLayer {
+                n_in: N_IN,
+                n_out: N_OUT,
+                weight: LIN_WEIGHT.to_vec(),
+                bias: LIN_BIAS.to_vec(),
+            }],
+        }
+    }
+
+    /// The model compiled into `hard_coded_model.rs` (or `hard_coded_model_hidden_layer.rs`).
+    #[cfg(feature = "hidden_layer")]
+    pub fn from_hard_coded() -> Self {
+        use super::hard_coded_model_hidden_layer::{LIN_BIAS1, LIN_BIAS2, LIN_WEIGHT1, LIN_WEIGHT2};
+        let n_hidden = LIN_BIAS1.len();
+        Self {
+            layers: vec![
+                DenseLayer {
+                    n_in: LIN_WEIGHT1.len() / n_hidden,
+                    n_out: n_hidden,
+                    weight: LIN_WEIGHT1.to_vec(),
+                    bias: LIN_BIAS1.to_vec(),
+                },
+                DenseLayer {
+                    n_in: n_hidden,
+                    n_out: N_OUT,
+                    weight: LIN_WEIGHT2.to_vec(),
+                    bias: LIN_BIAS2.to_vec(),
+                },
+            ],
+        }
+    }
+
+    pub fn eval(&self, input: &[f32; N_IN]) -> [f32; N_OUT] {
+        let mut x = input.to_vec();
+        let mut y = Vec::new();
+        for layer in &self.layers {
+            layer.forward(&x, &mut y);
+            std::mem::swap(&mut x, &mut y);
+        }
+        let mut output = [0f32; N_OUT];
+        output.copy_from_slice(&x);
+        output
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let file = File::open(path).map_err(|e| e.to_string())?;
+        Self::read(&mut BufReader::new(file))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let file = File::create(path).map_err(|e| e.to_string())?;
+        let mut writer = BufWriter::new(file);
+        self.write(&mut writer).map_err(|e| e.to_string())?;
+        writer.flush().map_err(|e| e.to_string())
+    }
+
+    pub fn read<R: Read>(reader: &mut R) -> Result<Self, String> {
+        read_header(reader, Self::MAGIC, Self::VERSION)?;
+        let n_layers = read_u32(reader)?;
+        if n_layers > Self::MAX_LAYERS {
+            return Err(format!(
+                "model has {n_layers} layers, exceeding the maximum of {}",
+                Self::MAX_LAYERS
+            ));
+        }
+        let layers = (0..n_layers)
+            .map(|_| DenseLayer::read(reader))
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::new(layers)
+    }
+
+    pub fn write<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&Self::MAGIC)?;
+        writer.write_all(&Self::VERSION.to_le_bytes())?;
+        writer.write_all(&(self.layers.len() as u32).to_le_bytes())?;
+        for layer in &self.layers {
+            layer.write(writer)?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn read_header<R: Read>(reader: &mut R, magic: [u8; 4], version: u32) -> Result<(), String> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf).map_err(|e| e.to_string())?;
+    if buf != magic {
+        return Err("not a weights file of the expected model".to_string());
+    }
+    let file_version = read_u32(reader)?;
+    if file_version != version {
+        return Err(format!("unsupported weights file version: {file_version}"));
+    }
+    Ok(())
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> Result<u32, String> {
+    let mut buf = [0u8; 4];
+    reader.read_exact(&mut buf).map_err(|e| e.to_string())?;
+    Ok(u32::from_le_bytes(buf))
+}
+
+fn read_f32s<R: Read>(reader: &mut R, len: usize) -> Result<Vec<f32>, String> {
+    let mut buf = vec![0u8; 4 * len];
+    reader.read_exact(&mut buf).map_err(|e| e.to_string())?;
+    Ok(buf
+        .chunks_exact(4)
+        .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+        .collect())
+}
+
+#[cfg(all(test, not(feature = "hidden_layer")))]
+mod tests {
+    use super::*;
+    use crate::training::policy::evaluate_hard_coded_policy;
+
+    #[test]
+    fn round_trip_matches_hard_coded_model() {
+        let model = RuntimeModel::from_hard_coded();
+        let mut buf = vec![];
+        model.write(&mut buf).unwrap();
+        let loaded = RuntimeModel::read(&mut buf.as_slice()).unwrap();
+        assert_eq!(model, loaded);
+
+        let mut input = [0f32; N_IN];
+        for (i, xi) in input.iter_mut().enumerate() {
+            *xi = ((i * 37) % 11) as f32 / 11.0;
+        }
+        let expected = evaluate_hard_coded_policy(&input);
+        let actual = loaded.eval(&input);
+        for (a, b) in expected.iter().zip(actual.iter()) {
+            assert!((a - b).abs() < 1e-5, "{expected:?} != {actual:?}");
+        }
+    }
+
+    #[test]
+    fn truncated_or_mismatched_files_are_rejected() {
+        let model = RuntimeModel::from_hard_coded();
+        let mut buf = vec![];
+        model.write(&mut buf).unwrap();
+        assert!(RuntimeModel::read(&mut &buf[..buf.len() - 1]).is_err());
+        buf[0] = b'X';
+        assert!(RuntimeModel::read(&mut buf.as_slice()).is_err());
+
+        let small = DenseLayer::new(2, 1, vec![0.0; 2], vec![0.0]).unwrap();
+        assert!(RuntimeModel::new(vec![small]).is_err());
+    }
+
+    #[test]
+    fn oversized_headers_are_rejected_before_allocating() {
+        let header = |n_layers: u32, n_in: u32, n_out: u32| {
+            let mut buf = RuntimeModel::MAGIC.to_vec();
+            for v in [RuntimeModel::VERSION, n_layers, n_in, n_out] {
+                buf.extend_from_slice(&v.to_le_bytes());
+            }
+            buf
+        };
+        assert!(RuntimeModel::read(&mut header(u32::MAX, 1, 1).as_slice()).is_err());
+        assert!(RuntimeModel::read(&mut header(1, u32::MAX, u32::MAX).as_slice()).is_err());
+        assert!(RuntimeModel::read(&mut header(1, N_IN as u32, u32::MAX).as_slice()).is_err());
+    }
+}
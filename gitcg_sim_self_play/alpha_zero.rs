@@ -0,0 +1,287 @@
+use std::path::PathBuf;
+
+use dfdx::{optim::Sgd, prelude::*};
+use gitcg_sim::{
+    prelude::*,
+    rand::{rngs::SmallRng, seq::SliceRandom, thread_rng, Rng},
+};
+use gitcg_sim_cli_utils::{
+    cli_args::SearchOpts,
+    match_round::{iterate_match, IterateMatchOpts},
+};
+use gitcg_sim_search::{
+    mcts::{MCTSConfig, MCTS},
+    prelude::*,
+    training::{
+        dataset::{load_dataset, DatasetRow, DatasetWriter},
+        features::FeatureSchema,
+        policy::{N_IN, N_OUT},
+        policy_value::PolicyValueNetwork,
+        runtime_model::DenseLayer,
+    },
+};
+use rayon::prelude::*;
+use serde_json::json;
+use structopt::StructOpt;
+
+use crate::export::{new_game, play_recorded_game, MAX_STEPS};
+
+const N_HIDDEN: usize = 128;
+const BATCH_SIZE: usize = 64;
+
+/// Shared trunk followed by the policy head and the value head.
+type PolicyValueModel = (
+    (Linear<N_IN, N_HIDDEN>, Sigmoid),
+    SplitInto<((Linear<N_HIDDEN, N_OUT>, Sigmoid), (Linear<N_HIDDEN, 1>, Sigmoid))>,
+);
+
+type BuiltModel = <PolicyValueModel as BuildOnDevice<Cpu, f32>>::Built;
+
+type NetworkMCTS = MCTS<GameStateWrapper<StandardNondetHandlerState>, PolicyValueNetwork, PolicyValueNetwork>;
+
+#[derive(Debug, StructOpt, Clone)]
+pub struct AlphaZeroOpts {
+    #[structopt(long = "--training-rounds", default_value = "100")]
+    pub training_rounds: u32,
+    #[structopt(
+        long = "--games-per-round",
+        default_value = "32",
+        help = "Self-play games per training round"
+    )]
+    pub games_per_round: u32,
+    #[structopt(long = "--mcts-time-limit-ms", default_value = "100")]
+    pub mcts_time_limit_ms: u128,
+    #[structopt(
+        long = "--epochs",
+        default_value = "4",
+        help = "Passes over the data of each training round"
+    )]
+    pub epochs: u32,
+    #[structopt(long = "--learning-rate", default_value = "0.01")]
+    pub learning_rate: f64,
+    #[structopt(
+        long = "--arena-games",
+        default_value = "20",
+        help = "Games between the new and the previous network after each training round"
+    )]
+    pub arena_games: u32,
+    #[structopt(
+        long = "--gate-threshold",
+        default_value = "0.55",
+        help = "Min. score rate against the previous network for the new network to be accepted"
+    )]
+    pub gate_threshold: f32,
+    #[structopt(long = "--load-weights", help = "Initial policy/value network weights")]
+    pub load_weights: Option<PathBuf>,
+    #[structopt(long = "--save-weights", help = "Save the accepted policy/value network weights")]
+    pub save_weights: Option<PathBuf>,
+    #[structopt(
+        long = "--dataset",
+        help = "Train on an exported dataset (see the export subcommand) before the first round"
+    )]
+    pub dataset: Option<PathBuf>,
+    #[structopt(
+        long = "--export-dir",
+        help = "Also write the self-play data as a dataset into this directory"
+    )]
+    pub export_dir: Option<PathBuf>,
+}
+
+/// Training example: (features, visit distribution, final outcome), from the perspective of the player to move.
+struct TrainingExample {
+    features: [f32; N_IN],
+    policy: [f32; N_OUT],
+    value: f32,
+}
+
+impl TrainingExample {
+    fn new(row: &DatasetRow) -> Option<Self> {
+        Some(Self {
+            features: row.game_state_features.as_slice().try_into().ok()?,
+            policy: row.input_features,
+            value: row.outcome?,
+        })
+    }
+}
+
+fn dense_layer<const I: usize, const O: usize>(lin: &modules::Linear<I, O, f32, Cpu>) -> DenseLayer {
+    DenseLayer::new(I, O, lin.weight.as_vec(), lin.bias.as_vec()).expect("dense_layer: invalid shape")
+}
+
+fn to_network(model: &BuiltModel) -> PolicyValueNetwork {
+    let ((trunk, _), SplitInto(((policy, _), (value, _)))) = model;
+    PolicyValueNetwork::new(dense_layer(trunk), dense_layer(policy), dense_layer(value))
+        .expect("to_network: invalid shape")
+}
+
+fn load_network(model: &mut BuiltModel, network: &PolicyValueNetwork) {
+    fn copy_layer<const I: usize, const O: usize>(lin: &mut modules::Linear<I, O, f32, Cpu>, layer: &DenseLayer) {
+        lin.weight.copy_from(&layer.weight);
+        lin.bias.copy_from(&layer.bias);
+    }
+    let ((trunk, _), SplitInto(((policy, _), (value, _)))) = model;
+    copy_layer(trunk, &network.trunk);
+    copy_layer(policy, &network.policy);
+    copy_layer(value, &network.value);
+}
+
+fn new_search(config: MCTSConfig, network: &PolicyValueNetwork) -> NetworkMCTS {
+    MCTS::new_with_eval_policy_and_selection_policy(config, network.clone(), network.clone())
+}
+
+/// Train on the examples for a number of epochs. Returns the mean loss of the last epoch.
+fn train(
+    model: &mut BuiltModel,
+    opt: &mut Sgd<BuiltModel, f32, Cpu>,
+    dev: &Cpu,
+    examples: &mut [TrainingExample],
+    epochs: u32,
+) -> f32 {
+    let mut grads = model.alloc_grads();
+    let mut rng = thread_rng();
+    let mut mean_loss = 0.0;
+    for _ in 0..epochs {
+        examples.shuffle(&mut rng);
+        let mut total_loss = 0.0;
+        let mut batches = 0;
+        for batch in examples.chunks_exact(BATCH_SIZE) {
+            let mut x: Tensor<Rank2<BATCH_SIZE, N_IN>, f32, _> = dev.zeros();
+            let mut y_policy: Tensor<Rank2<BATCH_SIZE, N_OUT>, f32, _> = dev.zeros();
+            let mut y_value: Tensor<Rank2<BATCH_SIZE, 1>, f32, _> = dev.zeros();
+            x.copy_from(&batch.iter().flat_map(|e| e.features).collect::<Vec<_>>());
+            y_policy.copy_from(&batch.iter().flat_map(|e| e.policy).collect::<Vec<_>>());
+            y_value.copy_from(&batch.iter().map(|e| e.value).collect::<Vec<_>>());
+
+            let (policy, value) = model.forward_mut(x.traced(grads));
+            let loss = (policy - y_policy).square().mean() + (value - y_value).square().mean();
+            total_loss += loss.array();
+            batches += 1;
+            grads = loss.backward();
+            opt.update(model, &grads).unwrap();
+            model.zero_grads(&mut grads);
+        }
+        mean_loss = total_loss / (batches.max(1) as f32);
+    }
+    mean_loss
+}
+
+/// AlphaZero-style loop: self-play with MCTS guided by the current network, train the shared-trunk
+/// policy/value network on the (features, visit distribution, outcome) triples, and accept the new
+/// network only if it beats the previous one in an arena.
+pub fn main_alpha_zero(deck: SearchOpts, opts: AlphaZeroOpts) -> Result<(), std::io::Error> {
+    let config = MCTSConfig {
+        cpuct: deck.search.cpuct_config(),
+        random_playout_iters: deck.search.mcts_random_playout_iters.unwrap_or(1),
+        random_playout_bias: deck.search.mcts_random_playout_bias,
+        random_playout_cutoff: deck.search.mcts_random_playout_max_steps.unwrap_or(0),
+        policy_bias: deck.search.mcts_policy_bias,
+        tt_size_mb: deck.search.tt_size_mb.unwrap_or(32),
+        limits: Some(SearchLimits {
+            max_time_ms: Some(opts.mcts_time_limit_ms),
+            max_positions: None,
+        }),
+        debug: false,
+        parallel: false,
+        progress_iters: None,
+    };
+
+    let dev = Cpu::default();
+    let mut model = dev.build_module::<PolicyValueModel, f32>();
+    if let Some(path) = &opts.load_weights {
+        let network = PolicyValueNetwork::load(path).map_err(std::io::Error::other)?;
+        load_network(&mut model, &network);
+    }
+    let mut opt = Sgd::new(
+        &model,
+        SgdConfig {
+            lr: opts.learning_rate,
+            momentum: Some(Momentum::Classic(0.5)),
+            weight_decay: None,
+        },
+    );
+    if let Some(dir) = &opts.dataset {
+        let shards = load_dataset(dir).map_err(std::io::Error::other)?;
+        if let Some(shard) = shards.iter().find(|shard| shard.feature_schema != FeatureSchema::V1) {
+            let msg = format!("dataset has feature schema {:?}, expected V1", shard.feature_schema);
+            return Err(std::io::Error::other(msg));
+        }
+        let mut examples: Vec<TrainingExample> = shards
+            .iter()
+            .flat_map(|shard| shard.rows.iter().filter_map(TrainingExample::new))
+            .collect();
+        let loss = train(&mut model, &mut opt, &dev, &mut examples, opts.epochs);
+        println!("{}", json!({ "dataset_examples": examples.len(), "loss": loss }));
+    }
+    let mut writer = match &opts.export_dir {
+        Some(dir) => Some(DatasetWriter::new(dir, 10_000, FeatureSchema::V1).map_err(std::io::Error::other)?),
+        None => None,
+    };
+
+    let mut current = to_network(&model);
+    let mut seed_gen = thread_rng();
+    let make_game = |mut rng: SmallRng| new_game(&deck, rng.gen()).expect("make_game: failed to load decks").1;
+
+    for round in 0..opts.training_rounds {
+        let seeds: Vec<u64> = (0..opts.games_per_round).map(|_| seed_gen.gen()).collect();
+        let games = seeds
+            .into_par_iter()
+            .map(|seed| {
+                let (metadata, initial) = new_game(&deck, seed)?;
+                let mut searches = ByPlayer::generate(|_| new_search(config, &current));
+                Ok((metadata, play_recorded_game(initial, &mut searches)))
+            })
+            .collect::<Result<Vec<_>, std::io::Error>>()?;
+        let mut examples: Vec<TrainingExample> = games
+            .iter()
+            .flat_map(|(_, data_points)| data_points)
+            .filter_map(|data_point| TrainingExample::new(&DatasetRow::new(FeatureSchema::V1, 0, data_point)?))
+            .collect();
+        let n_examples = examples.len();
+        if let Some(writer) = &mut writer {
+            for (metadata, data_points) in games {
+                writer
+                    .push_game(metadata, &data_points)
+                    .map_err(std::io::Error::other)?;
+            }
+        }
+
+        let previous_model = model.clone();
+        let loss = train(&mut model, &mut opt, &dev, &mut examples, opts.epochs);
+        let candidate = to_network(&model);
+
+        let make_search = || ByPlayer(new_search(config, &candidate), new_search(config, &current));
+        let (_, score_rate, _) = iterate_match(
+            &make_search,
+            &make_game,
+            IterateMatchOpts {
+                rounds: opts.arena_games,
+                steps: MAX_STEPS,
+                random_seed: seed_gen.gen(),
+                ..Default::default()
+            },
+        );
+        let accepted = score_rate >= opts.gate_threshold;
+        if accepted {
+            current = candidate;
+            if let Some(path) = &opts.save_weights {
+                current.save(path).map_err(std::io::Error::other)?;
+            }
+        } else {
+            model = previous_model;
+        }
+        println!(
+            "{}",
+            json!({
+                "round": round,
+                "examples": n_examples,
+                "loss": loss,
+                "arena_score_rate": score_rate,
+                "accepted": accepted,
+            })
+        );
+    }
+    if let Some(writer) = &mut writer {
+        writer.flush().map_err(std::io::Error::other)?;
+    }
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use std::{path::PathBuf, sync::Mutex};
+
+use gitcg_sim::{
+    prelude::*,
+    rand::{rngs::SmallRng, thread_rng, Rng, SeedableRng},
+};
+use gitcg_sim_cli_utils::cli_args::SearchOpts;
+use gitcg_sim_search::{
+    mcts::{policy::SelectionPolicy, MCTSConfig, SelfPlayDataPoint, MCTS},
+    prelude::*,
+    training::{
+        dataset::{DatasetWriter, GameMetadata},
+        features::FeatureSchema,
+    },
+};
+use rayon::prelude::*;
+use serde_json::json;
+use structopt::StructOpt;
+
+pub const MAX_STEPS: u32 = 300;
+
+#[derive(Debug, StructOpt, Clone)]
+pub struct ExportOpts {
+    #[structopt(long = "--output-dir", help = "Directory to write the dataset shards into")]
+    pub output_dir: PathBuf,
+    #[structopt(long = "--games", default_value = "100")]
+    pub games: u32,
+    #[structopt(
+        long = "--shard-size",
+        default_value = "10000",
+        help = "Min. number of positions per shard"
+    )]
+    pub shard_size: usize,
+    #[structopt(long = "--mcts-time-limit-ms", default_value = "100")]
+    pub mcts_time_limit_ms: u128,
+    #[structopt(
+        long = "--feature-schema",
+        default_value = "1",
+        help = "Version of the game state features"
+    )]
+    pub feature_schema: FeatureSchema,
+}
+
+/// Initial game state for a self-play game from the random seed, along with the metadata for the dataset.
+pub fn new_game(deck: &SearchOpts, seed: u64) -> Result<(GameMetadata, GameStateWrapper), std::io::Error> {
+    let mut deck = deck.clone();
+    deck.seed = Some(seed);
+    let (d1, d2) = deck.decks()?;
+    let game = deck.standard_game(Some(SmallRng::seed_from_u64(seed)))?;
+    let metadata = GameMetadata {
+        decks: ByPlayer(d1, d2),
+        seed,
+    };
+    Ok((metadata, game))
+}
+
+/// Play a game using MCTS and record the root visit distribution of each move,
+/// with the outcome filled in from the perspective of the player to move.
+pub fn play_recorded_game<
+    S: NondetState,
+    E: Evaluator<GameStateWrapper<S>>,
+    P: SelectionPolicy<GameStateWrapper<S>>,
+>(
+    initial: GameStateWrapper<S>,
+    searches: &mut ByPlayer<MCTS<GameStateWrapper<S>, E, P>>,
+) -> Vec<SelfPlayDataPoint<GameStateWrapper<S>>> {
+    let mut game = initial;
+    let mut data_points = vec![];
+    for _ in 0..MAX_STEPS {
+        if game.winner().is_some() {
+            break;
+        }
+        let player_id = game.to_move().unwrap();
+        let search = &mut searches[player_id];
+        let res = search.search_hidden(&game, player_id);
+        let Some(action) = res.pv.head() else { break };
+        data_points.extend(search.root_visit_distribution());
+        if game.advance(action).is_err() {
+            break;
+        }
+    }
+
+    let winner = game.winner();
+    for data_point in data_points.iter_mut() {
+        let Some(to_move) = data_point.state.to_move() else {
+            continue;
+        };
+        data_point.outcome = Some(match winner {
+            Some(w) if w == to_move => 1.0,
+            Some(..) => 0.0,
+            None => 0.5,
+        });
+    }
+    data_points
+}
+
+/// Generate self-play games using MCTS and write them as a dataset for training in a separate job
+/// (see `AlphaZeroOpts::dataset`).
+pub fn main_export(deck: SearchOpts, opts: ExportOpts) -> Result<(), std::io::Error> {
+    let config = MCTSConfig {
+        cpuct: deck.search.cpuct_config(),
+        random_playout_iters: deck.search.mcts_random_playout_iters.unwrap_or(1),
+        random_playout_bias: deck.search.mcts_random_playout_bias,
+        random_playout_cutoff: deck.search.mcts_random_playout_max_steps.unwrap_or(20),
+        policy_bias: deck.search.mcts_policy_bias,
+        tt_size_mb: deck.search.tt_size_mb.unwrap_or(32),
+        limits: Some(SearchLimits {
+            max_time_ms: Some(opts.mcts_time_limit_ms),
+            max_positions: None,
+        }),
+        debug: false,
+        parallel: false,
+        progress_iters: None,
+    };
+    let writer =
+        DatasetWriter::new(&opts.output_dir, opts.shard_size, opts.feature_schema).map_err(std::io::Error::other)?;
+    let writer = Mutex::new(writer);
+    let mut seed_gen = thread_rng();
+    let seeds: Vec<u64> = (0..opts.games).map(|_| seed_gen.gen()).collect();
+    seeds.into_par_iter().try_for_each(|seed| {
+        let (metadata, initial) = new_game(&deck, seed)?;
+        let mut searches: ByPlayer<MCTS<_>> = ByPlayer::generate(|_| MCTS::new(config));
+        let data_points = play_recorded_game(initial, &mut searches);
+        println!("{}", json!({ "seed": seed, "data_points": data_points.len() }));
+        writer
+            .lock()
+            .unwrap()
+            .push_game(metadata, &data_points)
+            .map_err(std::io::Error::other)
+    })?;
+    writer.into_inner().unwrap().flush().map_err(std::io::Error::other)?;
+    Ok(())
+}
@@ -13,8 +13,8 @@ use std::{
 use dfdx::{optim::Sgd, prelude::*};
 use gitcg_sim::rand::{rngs::SmallRng, thread_rng, Rng, SeedableRng};
 use gitcg_sim_search::training::features::game_state_features;
-use gitcg_sim_search::training::features::input_features::input_features;
 use gitcg_sim_search::{
+    evaluator::LinearEvaluator,
     mcts::{MCTSConfig, SelfPlayDataPoint, MCTS},
     playout::Playout,
     prelude::*,
@@ -35,6 +35,11 @@ use gitcg_sim::prelude::*;
 use gitcg_sim_cli_utils::cli_args::SearchOpts;
 use gitcg_sim_search::training::{eval::*, policy::N_IN};
 
+mod alpha_zero;
+mod export;
+use alpha_zero::{main_alpha_zero, AlphaZeroOpts};
+use export::{main_export, ExportOpts};
+
 #[derive(Debug, StructOpt, Copy, Clone)]
 pub struct Regularization {
     #[structopt(long = "--l1-regularization", help = "L1 regularization coefficient")]
@@ -76,6 +81,11 @@ pub struct TDLOpts {
     pub beta: f32,
     #[structopt(flatten)]
     pub regularization: Regularization,
+    #[structopt(
+        long = "--save-weights",
+        help = "Save the weights of player 1 for the linear evaluator (JSON) at each log print"
+    )]
+    pub save_weights: Option<std::path::PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -94,6 +104,11 @@ pub struct PolicyOpts {
     pub save_npz: Option<std::path::PathBuf>,
     #[structopt(long = "--load-npz")]
     pub load_npz: Option<std::path::PathBuf>,
+    #[structopt(
+        long = "--save-weights",
+        help = "Save the weights for inference without the training feature (see --policy-weights)"
+    )]
+    pub save_weights: Option<std::path::PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
@@ -113,6 +128,20 @@ pub enum SelfPlayOpts {
         #[structopt(flatten)]
         policy: PolicyOpts,
     },
+    #[structopt(help = "Run AlphaZero-style training for the policy/value network")]
+    AlphaZero {
+        #[structopt(flatten)]
+        search: SearchOpts,
+        #[structopt(flatten)]
+        alpha_zero: AlphaZeroOpts,
+    },
+    #[structopt(help = "Generate self-play data and write it as a dataset")]
+    Export {
+        #[structopt(flatten)]
+        search: SearchOpts,
+        #[structopt(flatten)]
+        export: ExportOpts,
+    },
 }
 
 /// Generate a playout using a search. Ends at game end (`Break(..)` returned, winner decided or `max_iters` reached).
@@ -257,6 +286,12 @@ fn main_tdl(mut deck: SearchOpts, opts: TDLOpts) -> Result<(), std::io::Error> {
             &opts.regularization,
             debug,
         );
+        if let (Some(path), true) = (&opts.save_weights, i % opts.log_iters == 0) {
+            let weights = searches.borrow()[PlayerId::PlayerFirst].model.weights;
+            LinearEvaluator::new(weights, PlayerId::PlayerFirst)
+                .save(path)
+                .map_err(std::io::Error::other)?;
+        }
     }
     Ok(())
 }
@@ -301,26 +336,13 @@ fn generate_data_points_mcts<I: FnMut() -> GameStateWrapper<S>, S: NondetState>(
                 ..
             } in vec
             {
-                let mut weighted_features = <InputFeatures<f32> as AsSlice<f32>>::Slice::default();
-                let avg_w =
-                    action_weights.iter().copied().map(|(_, w)| w).sum::<f32>() / (weighted_features.len() as f32);
-                for (i, wi) in weighted_features.iter_mut().enumerate() {
-                    let mut unit: <InputFeatures<f32> as AsSlice<f32>>::Slice = Default::default();
-                    unit[i] = 1.0;
-                    let mut tot_dot = 0.0;
-                    let mut tot_weight = 0.0;
-                    for (act, weight) in action_weights.iter().copied() {
-                        let input_features = input_features(act, 1.0).as_slice();
-                        let dot: f32 = unit.iter().copied().zip(input_features).map(|(a, b)| a * b).sum();
-                        tot_weight += dot * weight;
-                        tot_dot += dot;
-                    }
-                    *wi = if tot_dot < 1e-3 { avg_w } else { tot_weight / tot_dot };
-                }
-
                 let game_state_features = game_state_features::features(&gs.game_state);
-                tx.send((game_state_features, InputFeatures::from_slice(weighted_features), depth))
-                    .unwrap();
+                tx.send((
+                    game_state_features,
+                    PolicyNetwork::policy_target(&action_weights),
+                    depth,
+                ))
+                .unwrap();
             }
             ControlFlow::Continue(())
         });
@@ -352,6 +374,7 @@ fn main_policy(deck: SearchOpts, opts: PolicyOpts) -> Result<(), std::io::Error>
         }),
         debug: false,
         parallel: true,
+        progress_iters: None,
     };
 
     let games = AtomicU32::new(0);
@@ -448,6 +471,9 @@ fn main_policy(deck: SearchOpts, opts: PolicyOpts) -> Result<(), std::io::Error>
             if let Some(npz_path) = &opts.save_npz {
                 model.save_npz(npz_path).unwrap();
             }
+            if let Some(weights_path) = &opts.save_weights {
+                model.to_runtime_model().save(weights_path).unwrap();
+            }
         }
     };
     let tx = &tx;
@@ -467,5 +493,10 @@ fn main() -> Result<(), std::io::Error> {
     match opts {
         SelfPlayOpts::TDL { search: deck, tdl } => main_tdl(deck, tdl),
         SelfPlayOpts::Policy { search: deck, policy } => main_policy(deck, policy),
+        SelfPlayOpts::AlphaZero {
+            search: deck,
+            alpha_zero,
+        } => main_alpha_zero(deck, alpha_zero),
+        SelfPlayOpts::Export { search: deck, export } => main_export(deck, export),
     }
 }
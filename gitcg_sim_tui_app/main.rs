@@ -5,7 +5,14 @@ use crossterm::{
 };
 use grid::{GridConstraint, GridLayout};
 use std::collections::HashMap;
-use std::{cmp::max, collections::VecDeque, io, time::Duration};
+use std::{
+    cmp::max,
+    collections::VecDeque,
+    io,
+    sync::mpsc::{channel, Receiver},
+    thread::JoinHandle,
+    time::Duration,
+};
 use structopt::StructOpt;
 use tui::{
     backend::{Backend, CrosstermBackend},
@@ -31,7 +38,7 @@ use gitcg_sim::{
     smallvec::SmallVec,
 };
 use gitcg_sim_cli_utils::cli_args::{GenericSearch, SearchOpts};
-use gitcg_sim_search::prelude::*;
+use gitcg_sim_search::{prelude::*, AnalysisLine};
 
 mod grid;
 
@@ -123,18 +130,131 @@ enum RectKey {
     Summon(SummonId),
 }
 
+type SearchGame = GameStateWrapper<StandardNondetHandlerState>;
+
+/// Result of a search running on a background thread.
+enum SearchOutcome {
+    /// Move chosen by the opponent search.
+    Move(SearchResult<SearchGame>),
+    /// Analysis of the player's position requested by `App::analyze`.
+    Analysis(Vec<AnalysisLine<SearchGame>>),
+}
+
+/// Opponent search or analysis running on a background thread.
+struct PendingSearch {
+    pub handle: JoinHandle<(GenericSearch<StandardNondetHandlerState>, SearchOutcome)>,
+    pub progress: Receiver<SearchProgress<SearchGame>>,
+    pub last_progress: Option<SearchProgress<SearchGame>>,
+    pub stop_signal: Option<StopSignal>,
+    /// Set when the position has changed since the search started, so its outcome must be discarded.
+    pub cancelled: bool,
+}
+
+impl PendingSearch {
+    fn spawn(mut search: GenericSearch<StandardNondetHandlerState>, game: SearchGame, player_id: PlayerId) -> Self {
+        let (mut tx, rx) = channel();
+        let stop_signal = search.stop_signal();
+        let handle = std::thread::spawn(move || {
+            let res = search.search_hidden_with_observer(&game, player_id, &mut tx);
+            (search, SearchOutcome::Move(res))
+        });
+        Self {
+            handle,
+            progress: rx,
+            last_progress: None,
+            stop_signal,
+            cancelled: false,
+        }
+    }
+
+    fn spawn_analysis(
+        mut search: GenericSearch<StandardNondetHandlerState>,
+        game: SearchGame,
+        player_id: PlayerId,
+    ) -> Self {
+        let (_, rx) = channel();
+        let stop_signal = search.stop_signal();
+        let handle = std::thread::spawn(move || {
+            let lines = search.analyze_hidden(&game, player_id, ANALYSIS_MULTI_PV);
+            (search, SearchOutcome::Analysis(lines))
+        });
+        Self {
+            handle,
+            progress: rx,
+            last_progress: None,
+            stop_signal,
+            cancelled: false,
+        }
+    }
+
+    fn force_move(&self) {
+        if let Some(stop_signal) = &self.stop_signal {
+            stop_signal.stop();
+        }
+    }
+
+    /// Stop the search and discard its outcome.
+    fn cancel(&mut self) {
+        self.cancelled = true;
+        self.force_move();
+    }
+
+    fn status(&self) -> String {
+        let force_part = if self.stop_signal.is_some() {
+            " Press 'f' to force a move."
+        } else {
+            ""
+        };
+        let Some(SearchProgress {
+            pv,
+            eval,
+            win_ratio,
+            depth,
+            counter,
+            elapsed_ms,
+        }) = &self.last_progress
+        else {
+            return format!("Opponent thinking...{force_part}");
+        };
+        const PV_LEN: usize = 4;
+        let pv_part = pv
+            .into_iter()
+            .take(PV_LEN)
+            .map(|a| format!("{a:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let eval_part = match (eval, win_ratio) {
+            (Some(eval), _) => format!("eval={eval:?}"),
+            (None, Some(win_ratio)) => format!("win={:.1}%", 1e2 * win_ratio),
+            (None, None) => "eval=?".to_string(),
+        };
+        format!(
+            "Opponent thinking... depth={depth} {eval_part} states={} time={:.1}s PV=[{pv_part}].{force_part}",
+            counter.states_visited,
+            (*elapsed_ms as f64) * 1e-3,
+        )
+    }
+}
+
 struct App<B: Backend> {
     pub terminal: Terminal<B>,
     pub rects: HashMap<(PlayerId, RectKey), Rect>,
     pub game: GameStateWrapper<StandardNondetHandlerState, WithVecEventLog>,
-    pub search: GenericSearch<StandardNondetHandlerState>,
+    /// None while the search or analysis is running in `pending_search`.
+    pub search: Option<GenericSearch<StandardNondetHandlerState>>,
+    pub pending_search: Option<PendingSearch>,
     pub actions: SmallVec<[Input; 16]>,
     pub status: String,
     pub action_row_index: usize,
     pub scroll_y: i16,
     pub anim: VecDeque<Animation>,
+    /// Analysis table for the current position, shown below the log.
+    pub analysis: Vec<String>,
 }
 
+const ANALYSIS_MULTI_PV: usize = 4;
+const ANALYSIS_PV_LEN: usize = 3;
+
 const ANIM_SLEEP: u64 = 500;
 const DEFAULT_SLEEP: u64 = 50;
 
@@ -148,15 +268,11 @@ impl<B: Backend> App<B> {
             match self.game.to_move().unwrap() {
                 PlayerId::PlayerSecond => {
                     self.actions = action_list![];
-                    self.status = "Opponent moving...".to_string();
-                    let game1 = self.game.clone().with_log::<NullEventLog, ()>(());
-                    let res = self.search.search_hidden(&game1, PlayerId::PlayerSecond);
-                    let pv = res.pv;
-                    let input = pv.head().unwrap();
-                    advance_and_add_logs(input, &mut self.game, &mut self.anim, &self.rects);
+                    self.update_opponent_search();
                     true
                 }
                 PlayerId::PlayerFirst => {
+                    self.update_analysis();
                     let Self { game, .. } = self;
                     let game_state = &mut game.game_state;
                     let players = (
@@ -201,6 +317,37 @@ impl<B: Backend> App<B> {
         res || !self.anim.is_empty()
     }
 
+    /// Start the opponent search on a background thread, poll its progress and perform the move once finished.
+    fn update_opponent_search(&mut self) {
+        let pending = match self.pending_search.take() {
+            Some(pending) => pending,
+            None => {
+                let search = self.search.take().expect("search must be available");
+                let game1 = self.game.clone().with_log::<NullEventLog, ()>(());
+                PendingSearch::spawn(search, game1, PlayerId::PlayerSecond)
+            }
+        };
+        let mut pending = pending;
+        while let Ok(progress) = pending.progress.try_recv() {
+            pending.last_progress = Some(progress);
+        }
+        self.status = pending.status();
+        if !pending.handle.is_finished() {
+            self.pending_search = Some(pending);
+            return;
+        }
+
+        let (search, outcome) = pending.handle.join().expect("search thread panicked");
+        self.search = Some(search);
+        let SearchOutcome::Move(res) = outcome else {
+            // Analysis cancelled by the player's move: start the opponent search on the next update.
+            return;
+        };
+        self.analysis.clear();
+        let input = res.pv.head().unwrap();
+        advance_and_add_logs(input, &mut self.game, &mut self.anim, &self.rects);
+    }
+
     fn render(&mut self) -> Result<u64, io::Error> {
         let mut _animated = !self.anim.is_empty();
         let scroll_y_value = self.scroll_y;
@@ -213,6 +360,7 @@ impl<B: Backend> App<B> {
             action_row_index,
             anim,
             rects,
+            analysis,
             ..
         } = self;
         let game_state = &game.game_state;
@@ -305,7 +453,7 @@ impl<B: Backend> App<B> {
 
             Self::render_dice(f, game_state.player(PlayerId::PlayerFirst), dice_chunk);
 
-            Self::render_log(f, game_state, ui_chunks[2], scroll_y);
+            Self::render_log(f, game_state, analysis, ui_chunks[2], scroll_y);
 
             if !anim.is_empty() {
                 let top = anim.pop_front().unwrap();
@@ -647,6 +795,7 @@ impl<B: Backend> App<B> {
     fn render_log<P: GameStateParams<EventLog = VecEventLog>>(
         f: &mut Frame<B>,
         game_state: &GameState<P>,
+        analysis: &[String],
         rect: Rect,
         scroll_y: &i16,
     ) {
@@ -671,6 +820,10 @@ impl<B: Backend> App<B> {
                 }
             }
             log_lines.push(String::default());
+            if !analysis.is_empty() {
+                log_lines.push("Analysis:".to_string());
+                log_lines.extend(analysis.iter().cloned());
+            }
             let log_lines = truncate_with_scroll(&log_lines, (log_lines.len() as i16) + *scroll_y + 2, rect.height);
             log_lines.join("\n")
         };
@@ -678,19 +831,68 @@ impl<B: Backend> App<B> {
         f.render_widget(log_body, rect);
     }
 
+    /// Start analyzing the current position from the player's perspective with the opponent's search
+    /// on a background thread. The lines are shown by `App::update_analysis` once finished.
+    fn analyze(&mut self) {
+        let Some(search) = self.search.take() else { return };
+        let game = self.game.clone().with_log::<NullEventLog, ()>(());
+        self.pending_search = Some(PendingSearch::spawn_analysis(search, game, PlayerId::PlayerFirst));
+        self.analysis = vec!["Analyzing...".to_string()];
+    }
+
+    /// Poll the analysis started by `App::analyze` and show its lines once finished.
+    fn update_analysis(&mut self) {
+        let Some(pending) = self.pending_search.take() else {
+            return;
+        };
+        if !pending.handle.is_finished() {
+            self.pending_search = Some(pending);
+            return;
+        }
+        let (search, outcome) = pending.handle.join().expect("search thread panicked");
+        self.search = Some(search);
+        let SearchOutcome::Analysis(lines) = outcome else {
+            return;
+        };
+        if pending.cancelled {
+            return;
+        }
+        self.analysis = lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| format!("{:2}. {}", i + 1, line.summary(ANALYSIS_PV_LEN)))
+            .collect();
+    }
+
     fn keyboard(&mut self, skip_keys: bool) -> crossterm::Result<bool> {
         // let winner_found = self.game.to_move().is_some();
         if skip_keys {
             if let Event::Key(kc) = read()? {
-                if let KeyCode::Char('q') = kc.code {
-                    return Ok(true);
+                match kc.code {
+                    KeyCode::Char('q') => {
+                        if let Some(pending) = &self.pending_search {
+                            pending.force_move();
+                        }
+                        return Ok(true);
+                    }
+                    KeyCode::Char('f') => {
+                        if let Some(pending) = &self.pending_search {
+                            pending.force_move();
+                        }
+                    }
+                    _ => (),
                 }
             }
         } else if let Event::Key(kc) = read()? {
             if self.game.to_move() == Some(PlayerId::PlayerFirst) {
                 let mut input = None;
                 match kc.code {
-                    KeyCode::Char('q') => return Ok(true),
+                    KeyCode::Char('q') => {
+                        if let Some(pending) = &mut self.pending_search {
+                            pending.cancel();
+                        }
+                        return Ok(true);
+                    }
                     KeyCode::Char(c) if ('1'..='9').contains(&c) => {
                         let act_idx = (c as usize) - ('1' as usize);
                         let acts = &self.actions;
@@ -712,6 +914,7 @@ impl<B: Backend> App<B> {
                             self.action_row_index += 1
                         }
                     }
+                    KeyCode::Char('a') => self.analyze(),
                     KeyCode::Char(_) => {}
                     KeyCode::Down => {
                         self.scroll_y += 1;
@@ -733,6 +936,10 @@ impl<B: Backend> App<B> {
                 }
 
                 if let Some(input) = input {
+                    if let Some(pending) = &mut self.pending_search {
+                        pending.cancel();
+                    }
+                    self.analysis.clear();
                     advance_and_add_logs(input, &mut self.game, &mut self.anim, &self.rects);
                 }
             }
@@ -1017,13 +1224,15 @@ pub fn main() -> Result<(), io::Error> {
         let mut app_state = App {
             terminal,
             game: game.with_log(Default::default()),
-            search,
+            search: Some(search),
+            pending_search: None,
             status: "".to_string(),
             actions: action_list![],
             action_row_index: 0,
             scroll_y: 0,
             anim: Default::default(),
             rects: Default::default(),
+            analysis: Default::default(),
         };
 
         loop {
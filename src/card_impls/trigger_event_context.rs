@@ -1,6 +1,7 @@
 use crate::tcg_model::*;
 use crate::types::char_state::{AppliedEffectResult, CharState};
 use crate::types::command::*;
+use crate::types::game_state::RoundUsageKey;
 
 impl<'a, 'b, 'c, 'd, 'v, T> TriggerEventContext<'a, 'b, 'c, 'd, 'v, T> {
     #[inline]
@@ -17,6 +18,22 @@ impl<'a, 'b, 'c, 'd, 'v, T> TriggerEventContext<'a, 'b, 'c, 'd, 'v, T> {
         self.out_cmds.push((*self.ctx_for_dmg, cmd));
     }
 
+    /// Whether a "once per round" limit shared across instances has been used this round,
+    /// including by the statuses that responded earlier to the same event.
+    #[inline]
+    pub fn is_used_this_round(&self, key: RoundUsageKey) -> bool {
+        self.c.src_player_state.used_this_round.contains(&key)
+            || self
+                .out_cmds
+                .iter()
+                .any(|(_, cmd)| matches!(cmd, Command::MarkUsedThisRound(k) if *k == key))
+    }
+
+    #[inline]
+    pub fn mark_used_this_round(&mut self, key: RoundUsageKey) {
+        self.add_cmd(Command::MarkUsedThisRound(key));
+    }
+
     #[inline]
     pub fn active_char_idx(&self) -> u8 {
         self.c.src_player_state.active_char_idx
@@ -0,0 +1,48 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Abyss Herald: Wicked Torrents",
+    elem: Element::Hydro,
+    weapon: WeaponType::Other,
+    faction: Faction::Monster,
+    max_health: 6,
+    max_energy: 2,
+    skills: list8![
+        SkillId::RipplingBlades,
+        SkillId::VortexEdict,
+        SkillId::CurseOfTheUndercurrent,
+    ],
+    passive: Some(Passive::new("Watery Rebirth").status(StatusId::WateryRebirth)),
+};
+
+pub const RIPPLING_BLADES: Skill = skill_na("Rippling Blades", Element::Hydro, 2, DealDMGType::Physical);
+
+pub const VORTEX_EDICT: Skill = Skill {
+    name: "Vortex Edict",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Hydro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Hydro, 2, 1)),
+    ..Skill::new()
+};
+
+pub const CURSE_OF_THE_UNDERCURRENT: Skill = Skill {
+    name: "Curse of the Undercurrent",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Hydro, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Hydro, 4, 0)),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::RipplingBlades, RIPPLING_BLADES),
+    (SkillId::VortexEdict, VORTEX_EDICT),
+    (SkillId::CurseOfTheUndercurrent, CURSE_OF_THE_UNDERCURRENT),
+];
+
+pub mod watery_rebirth {
+    use super::*;
+
+    pub const S: Status = Status::new_indef("Watery Rebirth", StatusAttachMode::Character);
+
+    pub const I: ReviveOnDefeat = ReviveOnDefeat::new(4);
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Abyss Lector: Fathomless Flames",
+    elem: Element::Pyro,
+    weapon: WeaponType::Other,
+    faction: Faction::Monster,
+    max_health: 6,
+    max_energy: 2,
+    skills: list8![SkillId::FlameOfSalvation, SkillId::SearingPrecept, SkillId::OminousStar,],
+    passive: Some(Passive::new("Fiery Rebirth").status(StatusId::FieryRebirth)),
+};
+
+pub const FLAME_OF_SALVATION: Skill = skill_na("Flame of Salvation", Element::Pyro, 1, DealDMGType::PYRO);
+
+pub const SEARING_PRECEPT: Skill = Skill {
+    name: "Searing Precept",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Pyro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Pyro, 3, 0)),
+    ..Skill::new()
+};
+
+pub const OMINOUS_STAR: Skill = Skill {
+    name: "Ominous Star",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Pyro, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Pyro, 3, 0)),
+    summon: Some(SummonSpec::One(SummonId::DarkfireFurnace)),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::FlameOfSalvation, FLAME_OF_SALVATION),
+    (SkillId::SearingPrecept, SEARING_PRECEPT),
+    (SkillId::OminousStar, OMINOUS_STAR),
+];
+
+pub mod fiery_rebirth {
+    use super::*;
+
+    pub const S: Status = Status::new_indef("Fiery Rebirth", StatusAttachMode::Character);
+
+    pub const I: ReviveOnDefeat = ReviveOnDefeat::new(3);
+}
+
+pub mod darkfire_furnace {
+    use super::*;
+
+    pub const S: Status =
+        Status::new_summon_usages("Darkfire Furnace", 2).talent_usages_increase(CharId::AbyssLectorFathomlessFlames, 1);
+
+    pub const I: EndPhaseDealDMG = EndPhaseDealDMG(deal_elem_dmg(Element::Pyro, 1, 1));
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Abyss Lector: Violet Lightning",
+    elem: Element::Electro,
+    weapon: WeaponType::Other,
+    faction: Faction::Monster,
+    max_health: 6,
+    max_energy: 2,
+    skills: list8![
+        SkillId::VoidThunderblade,
+        SkillId::ThunderingShackles,
+        SkillId::VeilOfVioletLightning,
+    ],
+    passive: Some(Passive::new("Electric Rebirth").status(StatusId::ElectricRebirth)),
+};
+
+pub const VOID_THUNDERBLADE: Skill = skill_na("Void Thunderblade", Element::Electro, 1, DealDMGType::ELECTRO);
+
+pub const THUNDERING_SHACKLES: Skill = Skill {
+    name: "Thundering Shackles",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Electro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Electro, 3, 0)),
+    ..Skill::new()
+};
+
+pub const VEIL_OF_VIOLET_LIGHTNING: Skill = Skill {
+    name: "Veil of Violet Lightning",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Electro, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Electro, 2, 0)),
+    apply: Some(StatusId::VioletVeil),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::VoidThunderblade, VOID_THUNDERBLADE),
+    (SkillId::ThunderingShackles, THUNDERING_SHACKLES),
+    (SkillId::VeilOfVioletLightning, VEIL_OF_VIOLET_LIGHTNING),
+];
+
+pub mod electric_rebirth {
+    use super::*;
+
+    pub const S: Status = Status::new_indef("Electric Rebirth", StatusAttachMode::Character);
+
+    pub const I: ReviveOnDefeat = ReviveOnDefeat::new(3);
+}
+
+pub mod violet_veil {
+    use super::*;
+
+    pub const S: Status = Status::new_shield_points("Violet Veil", StatusAttachMode::Team, 2, None)
+        .talent_usages_increase(CharId::AbyssLectorVioletLightning, 1);
+
+    pub const I: EmptyStatusImpl = EmptyStatusImpl();
+}
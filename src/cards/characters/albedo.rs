@@ -49,6 +49,7 @@ impl SkillImpl for RiteOfProgenitureTectonicTide {
         &self,
         _: &PlayerState,
         status_collection: &StatusCollection,
+        _: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
@@ -0,0 +1,84 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Alhaitham",
+    elem: Element::Dendro,
+    weapon: WeaponType::Sword,
+    faction: Faction::Sumeru,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![
+        SkillId::ForeignRockblade,
+        SkillId::UniversalityAnElaborationOnForm,
+        SkillId::ParticularFieldFettersOfPhenomena,
+    ],
+    passive: None,
+};
+
+pub const FOREIGN_ROCKBLADE: Skill = skill_na("Foreign Rockblade", Element::Dendro, 2, DealDMGType::Physical);
+
+pub const UNIVERSALITY_AN_ELABORATION_ON_FORM: Skill = Skill {
+    name: "Universality: An Elaboration on Form",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Dendro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Dendro, 2, 0)),
+    apply: Some(StatusId::ChiselLightMirror),
+    ..Skill::new()
+};
+
+pub const PARTICULAR_FIELD_FETTERS_OF_PHENOMENA: Skill = Skill {
+    name: "Particular Field: Fetters of Phenomena",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Dendro, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Dendro, 4, 0)),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::ForeignRockblade, FOREIGN_ROCKBLADE),
+    (
+        SkillId::UniversalityAnElaborationOnForm,
+        UNIVERSALITY_AN_ELABORATION_ON_FORM,
+    ),
+    (
+        SkillId::ParticularFieldFettersOfPhenomena,
+        PARTICULAR_FIELD_FETTERS_OF_PHENOMENA,
+    ),
+];
+
+pub mod chisel_light_mirror {
+    use super::*;
+
+    pub const S: Status = Status::new_usages("Chisel-Light Mirror", StatusAttachMode::Character, 2, Some(3));
+
+    decl_status_impl_type!(ChiselLightMirror, I);
+    impl StatusImpl for ChiselLightMirror {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::OutgoingDMG | RespondsTo::TriggerEvent]
+        }
+
+        fn responds_to_triggers(&self) -> EnumSet<EventId> {
+            enum_set![EventId::EndPhase]
+        }
+
+        fn outgoing_dmg(&self, e: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if e.skill_id() == Some(SkillId::ParticularFieldFettersOfPhenomena) {
+                dmg.dmg += e.eff_state.usages();
+                return Some(AppliedEffectResult::DeleteSelf);
+            }
+            if dmg.infuse(DealDMGType::DENDRO) {
+                return Some(AppliedEffectResult::NoChange);
+            }
+            None
+        }
+
+        fn trigger_event(&self, e: &mut TriggerEventContext<EventId>) -> Option<AppliedEffectResult> {
+            let EventId::EndPhase = e.event_id else { return None };
+            let char_idx = e.status_key.char_idx()?;
+            if e.c.character_state(char_idx)?.has_talent_equipped() {
+                return None;
+            }
+            Some(AppliedEffectResult::ConsumeUsage)
+        }
+    }
+}
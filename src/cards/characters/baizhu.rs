@@ -0,0 +1,68 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Baizhu",
+    elem: Element::Dendro,
+    weapon: WeaponType::Catalyst,
+    faction: Faction::Liyue,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![
+        SkillId::TheClassicsOfAcupuncture,
+        SkillId::UniversalDiagnosis,
+        SkillId::HolisticRevivification,
+    ],
+    passive: None,
+};
+
+pub const THE_CLASSICS_OF_ACUPUNCTURE: Skill =
+    skill_na("The Classics of Acupuncture", Element::Dendro, 1, DealDMGType::DENDRO);
+
+pub const UNIVERSAL_DIAGNOSIS: Skill = Skill {
+    name: "Universal Diagnosis",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Dendro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Dendro, 1, 0)),
+    summon: Some(SummonSpec::One(SummonId::GossamerSprite)),
+    ..Skill::new()
+};
+
+pub const HOLISTIC_REVIVIFICATION: Skill = Skill {
+    name: "Holistic Revivification",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Dendro, 4, 0, 2),
+    apply: Some(StatusId::PulsingClarity),
+    commands: list8![Command::Heal(1, CmdCharIdx::All)],
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::TheClassicsOfAcupuncture, THE_CLASSICS_OF_ACUPUNCTURE),
+    (SkillId::UniversalDiagnosis, UNIVERSAL_DIAGNOSIS),
+    (SkillId::HolisticRevivification, HOLISTIC_REVIVIFICATION),
+];
+
+pub mod gossamer_sprite {
+    use super::*;
+
+    pub const S: Status = Status::new_summon_usages("Gossamer Sprite", 1);
+
+    pub const I: EndPhaseCommands = EndPhaseCommands(list8![
+        Command::DealDMG(deal_elem_dmg(Element::Dendro, 1, 0)),
+        Command::Heal(1, CmdCharIdx::Active),
+    ]);
+}
+
+pub mod pulsing_clarity {
+    use super::*;
+
+    pub const S: Status =
+        Status::new_usages("Pulsing Clarity", StatusAttachMode::Team, 2, None).casted_by_character(CharId::Baizhu);
+
+    decl_status_impl_type!(PulsingClarity, I);
+    trigger_event_impl!(PulsingClarity, [EndPhase], |e| {
+        let heal = if e.c.has_talent_equipped() { 2 } else { 1 };
+        e.add_cmd(Command::Heal(heal, CmdCharIdx::All));
+        Some(AppliedEffectResult::ConsumeUsage)
+    });
+}
@@ -30,7 +30,7 @@ pub const SHINING_MIRACLE: Skill = Skill {
     name: "Shining Miracle♪",
     skill_type: SkillType::ElementalBurst,
     cost: cost_elem(Element::Hydro, 3, 0, 3),
-    commands: list8![Command::HealAll(4),],
+    commands: list8![Command::Heal(4, CmdCharIdx::All),],
     ..Skill::new()
 };
 
@@ -78,7 +78,7 @@ pub mod melody_loop {
         }
 
         fn trigger_event(&self, e: &mut TriggerEventContext) -> Option<AppliedEffectResult> {
-            e.add_cmd(Command::HealAll(1));
+            e.add_cmd(Command::Heal(1, CmdCharIdx::All));
             e.add_cmd(Command::ApplyElementToSelf(Element::Hydro));
             Some(AppliedEffectResult::ConsumeUsage)
         }
@@ -48,14 +48,16 @@ impl SkillImpl for FloralBrush {
         &self,
         src_player: &PlayerState,
         _: &StatusCollection,
+        _: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
-        if !src_player.active_character_has_talent_equipped() {
+        const ROUND_USAGE_KEY: RoundUsageKey = RoundUsageKey::Card(CardId::FloralSidewinder);
+        if !src_player.active_character_has_talent_equipped() || src_player.is_used_this_round(ROUND_USAGE_KEY) {
             return;
         }
-        // TODO need to check once per round
-        cmds.insert(0, (*ctx, Command::ApplyStatusToTeam(StatusId::Sprout)))
+        cmds.insert(0, (*ctx, Command::MarkUsedThisRound(ROUND_USAGE_KEY)));
+        cmds.insert(1, (*ctx, Command::ApplyStatusToTeam(StatusId::Sprout)))
     }
 }
 
@@ -62,7 +62,7 @@ pub mod pactsworn_pathclearer {
         }
 
         fn responds_to_triggers(&self) -> EnumSet<EventId> {
-            enum_set![EventId::EndPhase]
+            enum_set![EventId::EndPhase | EventId::TalentEquipped]
         }
 
         fn responds_to_events(&self) -> XEventMask {
@@ -79,13 +79,21 @@ pub mod pactsworn_pathclearer {
                 dmg.dmg += 2;
                 changed = true;
             }
+            if level >= 2 && e.has_talent_equipped() && e.skill_id() == Some(SkillId::SecretRiteChasmicSoulfarer) {
+                dmg.dmg += 1;
+                changed = true;
+            }
 
             changed.then_some(AppliedEffectResult::NoChange)
         }
 
-        // TODO need level decrease check for equipping Talent Card
+        /// Indwelling Level +1 at the End Phase and when the Talent card is equipped on the attached character.
         fn trigger_event(&self, e: &mut TriggerEventContext) -> Option<AppliedEffectResult> {
-            let EventId::EndPhase = e.event_id else { return None };
+            match e.event_id {
+                EventId::EndPhase => {}
+                EventId::TalentEquipped if e.c.ctx.src.card_selected_char_idx() == e.status_key.char_idx() => {}
+                _ => return None,
+            }
             let level = e.c.eff_state.counter();
             Some(AppliedEffectResult::SetCounter(increase_indwelling_level(level, 1)))
         }
@@ -0,0 +1,110 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Dehya",
+    elem: Element::Pyro,
+    weapon: WeaponType::Claymore,
+    faction: Faction::Sumeru,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![SkillId::SandstormAssault, SkillId::MoltenInferno, SkillId::LeonineBite,],
+    passive: None,
+};
+
+pub const SANDSTORM_ASSAULT: Skill = skill_na("Sandstorm Assault", Element::Pyro, 2, DealDMGType::Physical);
+
+pub const MOLTEN_INFERNO: Skill = Skill {
+    name: "Molten Inferno",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Pyro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Pyro, 1, 0)),
+    summon: Some(SummonSpec::One(SummonId::FierySanctumField)),
+    ..Skill::new()
+};
+
+pub const LEONINE_BITE: Skill = Skill {
+    name: "Leonine Bite",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Pyro, 4, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Pyro, 3, 0)),
+    apply: Some(StatusId::IncinerationDrive),
+    ..Skill::new()
+};
+
+pub const INCINERATION_DRIVE: Skill = Skill {
+    name: "Incineration Drive",
+    skill_type: SkillType::ElementalBurst,
+    cost: Cost::ZERO,
+    deal_dmg: Some(deal_elem_dmg(Element::Pyro, 3, 0)),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 4] = [
+    (SkillId::SandstormAssault, SANDSTORM_ASSAULT),
+    (SkillId::MoltenInferno, MOLTEN_INFERNO),
+    (SkillId::LeonineBite, LEONINE_BITE),
+    (SkillId::IncinerationDrive, INCINERATION_DRIVE),
+];
+
+pub mod fiery_sanctum_field {
+    use super::*;
+
+    pub const S: Status = Status::new_summon_usages("Fiery Sanctum Field", 3)
+        .casted_by_character(CharId::Dehya)
+        .counter(CounterSpec::new("Dehya Takes DMG", 0));
+
+    /// Reduces DMG taken by the active character once per round.
+    /// When Dehya is on standby with at least 7 HP, she takes 1 Piercing DMG afterwards.
+    pub struct FierySanctumFieldReduceDMG();
+    impl StatusImpl for FierySanctumFieldReduceDMG {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::IncomingDMG | RespondsTo::TriggerXEvent]
+        }
+
+        fn responds_to_events(&self) -> XEventMask {
+            xevent_mask::DMG_INCOMING
+        }
+
+        fn incoming_dmg(&self, e: &StatusImplContext, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if !e.eff_state.can_use_once_per_round() {
+                return None;
+            }
+            let player = e.src_player_state;
+            let dehya_takes_dmg = player
+                .char_states
+                .enumerate_valid()
+                .any(|(i, c)| c.char_id == CharId::Dehya && i != player.active_char_idx && c.hp() >= 7);
+            if dehya_takes_dmg {
+                dmg.try_reduce(1, AppliedEffectResult::SetCounterAndConsumeOncePerRound(1))
+            } else {
+                dmg.try_reduce(1, AppliedEffectResult::ConsumeOncePerRound)
+            }
+        }
+
+        fn trigger_xevent(&self, e: &mut TriggerEventContext<XEvent>) -> Option<AppliedEffectResult> {
+            if !e.is_received_dmg() || e.c.eff_state.counter() == 0 {
+                return None;
+            }
+            let (char_idx, _) = e.find_chararacter_for(|c| c.char_id == CharId::Dehya)?;
+            e.out_cmds.push((
+                CommandContext::new(e.ctx_for_dmg.src_player_id, CommandSource::Character { char_idx }, None),
+                Command::TakeDMG(DealDMG::new(DealDMGType::Piercing, 1, 0)),
+            ));
+            Some(AppliedEffectResult::SetCounter(0))
+        }
+    }
+
+    decl_summon_impl_type!(FierySanctumField, I);
+    compose_status_impls!(FierySanctumField(
+        EndPhaseDealDMG(deal_elem_dmg(Element::Pyro, 1, 0)),
+        FierySanctumFieldReduceDMG(),
+    ));
+}
+
+pub mod incineration_drive {
+    use super::*;
+
+    pub const S: Status = Status::new_indef("Incineration Drive", StatusAttachMode::Character).prepare_skill(1);
+
+    pub const I: PreparedSkill = PreparedSkill::new(SkillId::IncinerationDrive);
+}
@@ -0,0 +1,73 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Dori",
+    elem: Element::Electro,
+    weapon: WeaponType::Claymore,
+    faction: Faction::Sumeru,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![
+        SkillId::MarvelousSwordDanceModified,
+        SkillId::SpiritWardingLampTroubleshooterCannon,
+        SkillId::AlcazarzaraysExactitude,
+    ],
+    passive: None,
+};
+
+pub const MARVELOUS_SWORD_DANCE_MODIFIED: Skill = skill_na(
+    "Marvelous Sword-Dance (Modified)",
+    Element::Electro,
+    2,
+    DealDMGType::Physical,
+);
+
+pub const SPIRIT_WARDING_LAMP_TROUBLESHOOTER_CANNON: Skill = Skill {
+    name: "Spirit-Warding Lamp: Troubleshooter Cannon",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Electro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Electro, 2, 0)),
+    summon: Some(SummonSpec::One(SummonId::AfterSalesServiceRounds)),
+    ..Skill::new()
+};
+
+pub const ALCAZARZARAYS_EXACTITUDE: Skill = Skill {
+    name: "Alcazarzaray's Exactitude",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Electro, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Electro, 1, 0)),
+    summon: Some(SummonSpec::One(SummonId::Jinni)),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::MarvelousSwordDanceModified, MARVELOUS_SWORD_DANCE_MODIFIED),
+    (
+        SkillId::SpiritWardingLampTroubleshooterCannon,
+        SPIRIT_WARDING_LAMP_TROUBLESHOOTER_CANNON,
+    ),
+    (SkillId::AlcazarzaraysExactitude, ALCAZARZARAYS_EXACTITUDE),
+];
+
+pub mod after_sales_service_rounds {
+    use super::*;
+
+    pub const S: Status = Status::new_summon_usages("After-Sales Service Rounds", 1);
+
+    pub const I: EndPhaseDealDMG = EndPhaseDealDMG(deal_elem_dmg(Element::Electro, 1, 0));
+}
+
+pub mod jinni {
+    use super::*;
+
+    pub const S: Status = Status::new_summon_usages("Jinni", 2).casted_by_character(CharId::Dori);
+
+    decl_summon_impl_type!(Jinni, I);
+    trigger_event_impl!(Jinni, [EndPhase], |e| {
+        e.add_cmd(Command::Heal(2, CmdCharIdx::MostInjured));
+        if e.c.has_talent_equipped() {
+            e.add_cmd(Command::AddEnergy(1, CmdCharIdx::Active));
+        }
+        Some(AppliedEffectResult::ConsumeUsage)
+    });
+}
@@ -0,0 +1,99 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Electro Hypostasis",
+    elem: Element::Electro,
+    weapon: WeaponType::Other,
+    faction: Faction::Monster,
+    max_health: 8,
+    max_energy: 2,
+    skills: list8![
+        SkillId::ElectroCrystalProjection,
+        SkillId::RockPaperScissorsCombo,
+        SkillId::LightningLockdown,
+    ],
+    passive: Some(Passive::new("Electro Crystal Core").status(StatusId::ElectroCrystalCore)),
+};
+
+pub const ELECTRO_CRYSTAL_PROJECTION: Skill =
+    skill_na("Electro Crystal Projection", Element::Electro, 1, DealDMGType::ELECTRO);
+
+pub const ROCK_PAPER_SCISSORS_COMBO: Skill = Skill {
+    name: "Rock-Paper-Scissors Combo",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Electro, 5, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Electro, 2, 0)),
+    apply: Some(StatusId::RockPaperScissorsComboScissors),
+    ..Skill::new()
+};
+
+pub const ROCK_PAPER_SCISSORS_COMBO_SCISSORS: Skill = Skill {
+    name: "Rock-Paper-Scissors Combo: Scissors",
+    skill_type: SkillType::ElementalSkill,
+    cost: Cost::ZERO,
+    deal_dmg: Some(deal_elem_dmg(Element::Electro, 2, 0)),
+    apply: Some(StatusId::RockPaperScissorsComboPaper),
+    ..Skill::new()
+};
+
+pub const ROCK_PAPER_SCISSORS_COMBO_PAPER: Skill = Skill {
+    name: "Rock-Paper-Scissors Combo: Paper",
+    skill_type: SkillType::ElementalSkill,
+    cost: Cost::ZERO,
+    deal_dmg: Some(deal_elem_dmg(Element::Electro, 3, 0)),
+    ..Skill::new()
+};
+
+pub const LIGHTNING_LOCKDOWN: Skill = Skill {
+    name: "Lightning Lockdown",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Electro, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Electro, 2, 0)),
+    summon: Some(SummonSpec::One(SummonId::ChainsOfWardingThunder)),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 5] = [
+    (SkillId::ElectroCrystalProjection, ELECTRO_CRYSTAL_PROJECTION),
+    (SkillId::RockPaperScissorsCombo, ROCK_PAPER_SCISSORS_COMBO),
+    (
+        SkillId::RockPaperScissorsComboScissors,
+        ROCK_PAPER_SCISSORS_COMBO_SCISSORS,
+    ),
+    (SkillId::RockPaperScissorsComboPaper, ROCK_PAPER_SCISSORS_COMBO_PAPER),
+    (SkillId::LightningLockdown, LIGHTNING_LOCKDOWN),
+];
+
+pub mod electro_crystal_core {
+    use super::*;
+
+    pub const S: Status = Status::new_indef("Electro Crystal Core", StatusAttachMode::Character);
+
+    pub const I: ReviveOnDefeat = ReviveOnDefeat::new(1);
+}
+
+pub mod rock_paper_scissors_combo_scissors {
+    use super::*;
+
+    pub const S: Status =
+        Status::new_indef("Rock-Paper-Scissors Combo: Scissors", StatusAttachMode::Character).prepare_skill(1);
+
+    pub const I: PreparedSkill = PreparedSkill::new(SkillId::RockPaperScissorsComboScissors);
+}
+
+pub mod rock_paper_scissors_combo_paper {
+    use super::*;
+
+    pub const S: Status =
+        Status::new_indef("Rock-Paper-Scissors Combo: Paper", StatusAttachMode::Character).prepare_skill(1);
+
+    pub const I: PreparedSkill = PreparedSkill::new(SkillId::RockPaperScissorsComboPaper);
+}
+
+pub mod chains_of_warding_thunder {
+    use super::*;
+
+    pub const S: Status = Status::new_summon_usages("Chains of Warding Thunder", 2);
+
+    pub const I: EndPhaseDealDMG = EndPhaseDealDMG(deal_elem_dmg(Element::Electro, 1, 0));
+}
@@ -0,0 +1,51 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Eremite Desert Clearwater",
+    elem: Element::Hydro,
+    weapon: WeaponType::Catalyst,
+    faction: Faction::Eremite,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![
+        SkillId::WaterRippleStrike,
+        SkillId::SpringOfRespite,
+        SkillId::TorrentialRite,
+    ],
+    passive: None,
+};
+
+pub const WATER_RIPPLE_STRIKE: Skill = skill_na("Water Ripple Strike", Element::Hydro, 1, DealDMGType::HYDRO);
+
+pub const SPRING_OF_RESPITE: Skill = Skill {
+    name: "Spring of Respite",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Hydro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Hydro, 1, 0)),
+    summon: Some(SummonSpec::One(SummonId::ClearwaterSpring)),
+    ..Skill::new()
+};
+
+pub const TORRENTIAL_RITE: Skill = Skill {
+    name: "Torrential Rite",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Hydro, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Hydro, 3, 0)),
+    commands: list8![Command::Heal(1, CmdCharIdx::All)],
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::WaterRippleStrike, WATER_RIPPLE_STRIKE),
+    (SkillId::SpringOfRespite, SPRING_OF_RESPITE),
+    (SkillId::TorrentialRite, TORRENTIAL_RITE),
+];
+
+pub mod clearwater_spring {
+    use super::*;
+
+    pub const S: Status =
+        Status::new_summon_usages("Clearwater Spring", 2).talent_usages_increase(CharId::EremiteDesertClearwater, 1);
+
+    pub const I: EndPhaseCommands = EndPhaseCommands(list8![Command::Heal(2, CmdCharIdx::MostInjured)]);
+}
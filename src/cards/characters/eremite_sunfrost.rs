@@ -0,0 +1,63 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Eremite Sunfrost",
+    elem: Element::Cryo,
+    weapon: WeaponType::Sword,
+    faction: Faction::Eremite,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![
+        SkillId::SunfrostEdge,
+        SkillId::FrostboundFeint,
+        SkillId::DesertChillstorm,
+    ],
+    passive: None,
+};
+
+pub const SUNFROST_EDGE: Skill = skill_na("Sunfrost Edge", Element::Cryo, 2, DealDMGType::Physical);
+
+pub const FROSTBOUND_FEINT: Skill = Skill {
+    name: "Frostbound Feint",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Cryo, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Cryo, 2, 0)),
+    apply: Some(StatusId::FrigidGuard),
+    ..Skill::new()
+};
+
+pub const DESERT_CHILLSTORM: Skill = Skill {
+    name: "Desert Chillstorm",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Cryo, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Cryo, 4, 0)),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::SunfrostEdge, SUNFROST_EDGE),
+    (SkillId::FrostboundFeint, FROSTBOUND_FEINT),
+    (SkillId::DesertChillstorm, DESERT_CHILLSTORM),
+];
+
+pub mod frigid_guard {
+    use super::*;
+
+    pub const S: Status = Status::new_shield_points("Frigid Guard", StatusAttachMode::Character, 1, None);
+
+    // Talent: Normal Attacks deal +1 DMG while the shield is active.
+    decl_status_impl_type!(FrigidGuard, I);
+    impl StatusImpl for FrigidGuard {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::OutgoingDMG]
+        }
+
+        fn outgoing_dmg(&self, e: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if !e.has_talent_equipped() || !e.is_normal_attack() {
+                return None;
+            }
+            dmg.dmg += 1;
+            Some(AppliedEffectResult::NoChange)
+        }
+    }
+}
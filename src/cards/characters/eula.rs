@@ -37,6 +37,7 @@ impl SkillImpl for IcetideVortex {
         &self,
         _: &PlayerState,
         status_collection: &StatusCollection,
+        _: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
@@ -27,7 +27,7 @@ pub const DANDELION_BREEZE: Skill = Skill {
     skill_type: SkillType::ElementalBurst,
     cost: cost_elem(Element::Anemo, 4, 0, 3),
     summon: Some(SummonSpec::One(SummonId::DandelionField)),
-    commands: list8![Command::HealAll(2)],
+    commands: list8![Command::Heal(2, CmdCharIdx::All)],
     ..Skill::new()
 };
 
@@ -0,0 +1,81 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Kirara",
+    elem: Element::Dendro,
+    weapon: WeaponType::Sword,
+    faction: Faction::Inazuma,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![
+        SkillId::Boxcutter,
+        SkillId::MeowteorKick,
+        SkillId::SecretArtSurpriseDispatch,
+    ],
+    passive: None,
+};
+
+pub const BOXCUTTER: Skill = skill_na("Boxcutter", Element::Dendro, 2, DealDMGType::Physical);
+
+pub const MEOWTEOR_KICK: Skill = Skill {
+    name: "Meow-teor Kick",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Dendro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Dendro, 1, 0)),
+    apply: Some(StatusId::ShieldOfSafeTransport),
+    skill_impl: Some(&MeowteorKick()),
+    ..Skill::new()
+};
+
+pub const SECRET_ART_SURPRISE_DISPATCH: Skill = Skill {
+    name: "Secret Art: Surprise Dispatch",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Dendro, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Dendro, 4, 0)),
+    apply: Some(StatusId::CatGrassCardamom),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::Boxcutter, BOXCUTTER),
+    (SkillId::MeowteorKick, MEOWTEOR_KICK),
+    (SkillId::SecretArtSurpriseDispatch, SECRET_ART_SURPRISE_DISPATCH),
+];
+
+/// Talent: draws a card.
+pub struct MeowteorKick();
+impl SkillImpl for MeowteorKick {
+    fn commands(
+        &self,
+        src_player: &PlayerState,
+        _: &StatusCollection,
+        _: &StatusCollection,
+        ctx: &CommandContext,
+        cmds: &mut CommandList<(CommandContext, Command)>,
+    ) {
+        if !src_player.active_character_has_talent_equipped() {
+            return;
+        }
+        cmds.push((*ctx, Command::DrawCards(1, None)))
+    }
+}
+
+pub mod shield_of_safe_transport {
+    use super::*;
+
+    pub const S: Status = Status::new_shield_points("Shield of Safe Transport", StatusAttachMode::Team, 2, None);
+
+    pub const I: EmptyStatusImpl = EmptyStatusImpl();
+}
+
+pub mod cat_grass_cardamom {
+    use super::*;
+
+    pub const S: Status = Status::new_usages("Cat Grass Cardamom", StatusAttachMode::Team, 2, None);
+
+    decl_status_impl_type!(CatGrassCardamom, I);
+    trigger_event_impl!(CatGrassCardamom, [Switched], |e| {
+        e.cmd_deal_dmg(DealDMGType::DENDRO, 1, 0);
+        Some(AppliedEffectResult::ConsumeUsage)
+    });
+}
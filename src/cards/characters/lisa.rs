@@ -0,0 +1,110 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Lisa",
+    elem: Element::Electro,
+    weapon: WeaponType::Catalyst,
+    faction: Faction::Mondstadt,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![SkillId::LightningTouch, SkillId::VioletArc, SkillId::LightningRose,],
+    passive: None,
+};
+
+pub const LIGHTNING_TOUCH: Skill = Skill {
+    skill_impl: Some(&LightningTouch()),
+    ..skill_na("Lightning Touch", Element::Electro, 1, DealDMGType::ELECTRO)
+};
+
+pub const VIOLET_ARC: Skill = Skill {
+    name: "Violet Arc",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Electro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Electro, 2, 0)),
+    skill_impl: Some(&VioletArc()),
+    ..Skill::new()
+};
+
+pub const LIGHTNING_ROSE: Skill = Skill {
+    name: "Lightning Rose",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Electro, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Electro, 2, 0)),
+    summon: Some(SummonSpec::One(SummonId::LightningRose)),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::LightningTouch, LIGHTNING_TOUCH),
+    (SkillId::VioletArc, VIOLET_ARC),
+    (SkillId::LightningRose, LIGHTNING_ROSE),
+];
+
+pub struct LightningTouch();
+impl SkillImpl for LightningTouch {
+    fn commands(
+        &self,
+        src_player: &PlayerState,
+        _: &StatusCollection,
+        _: &StatusCollection,
+        ctx: &CommandContext,
+        cmds: &mut CommandList<(CommandContext, Command)>,
+    ) {
+        if !src_player.flags.contains(PlayerFlag::ChargedAttack) {
+            return;
+        }
+        cmds.push((*ctx, Command::ApplyCharacterStatusToTarget(StatusId::Conductive)))
+    }
+}
+
+/// Attaches Conductive only if the target does not have it already.
+/// Otherwise, the DMG dealt consumes the existing Conductive.
+pub struct VioletArc();
+impl SkillImpl for VioletArc {
+    fn commands(
+        &self,
+        _: &PlayerState,
+        _: &StatusCollection,
+        tgt_status_collection: &StatusCollection,
+        ctx: &CommandContext,
+        cmds: &mut CommandList<(CommandContext, Command)>,
+    ) {
+        let Some(tgt_char_idx) = ctx.dmg_tgt_char_idx() else {
+            return;
+        };
+        if tgt_status_collection.has_character_status(tgt_char_idx, StatusId::Conductive) {
+            return;
+        }
+        cmds.push((*ctx, Command::ApplyCharacterStatusToTarget(StatusId::Conductive)))
+    }
+}
+
+pub mod conductive {
+    use super::*;
+
+    pub const S: Status =
+        Status::new_usages("Conductive", StatusAttachMode::Character, 2, Some(4)).applies_to_opposing();
+
+    decl_status_impl_type!(Conductive, I);
+    impl StatusImpl for Conductive {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::IncomingDMG]
+        }
+
+        fn incoming_dmg(&self, e: &StatusImplContext, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if e.skill_id() != Some(SkillId::VioletArc) {
+                return None;
+            }
+            dmg.dmg += e.eff_state.usages();
+            Some(AppliedEffectResult::DeleteSelf)
+        }
+    }
+}
+
+pub mod lightning_rose {
+    use super::*;
+
+    pub const S: Status = Status::new_summon_usages("Lightning Rose", 2);
+
+    pub const I: EndPhaseDealDMG = EndPhaseDealDMG(deal_elem_dmg(Element::Electro, 2, 0));
+}
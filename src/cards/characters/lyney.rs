@@ -0,0 +1,94 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Lyney",
+    elem: Element::Pyro,
+    weapon: WeaponType::Bow,
+    faction: Faction::Fontaine,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![
+        SkillId::CardForceTranslocation,
+        SkillId::BewilderingLights,
+        SkillId::WondrousTrickMiracleParade,
+    ],
+    passive: None,
+};
+
+pub const CARD_FORCE_TRANSLOCATION: Skill = Skill {
+    skill_impl: Some(&CardForceTranslocation()),
+    ..skill_na("Card Force Translocation", Element::Pyro, 2, DealDMGType::Physical)
+};
+
+pub const BEWILDERING_LIGHTS: Skill = Skill {
+    name: "Bewildering Lights",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Pyro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Pyro, 3, 0)),
+    ..Skill::new()
+};
+
+pub const WONDROUS_TRICK_MIRACLE_PARADE: Skill = Skill {
+    name: "Wondrous Trick: Miracle Parade",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Pyro, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Pyro, 3, 0)),
+    summon: Some(SummonSpec::One(SummonId::GrinMalkinHat)),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::CardForceTranslocation, CARD_FORCE_TRANSLOCATION),
+    (SkillId::BewilderingLights, BEWILDERING_LIGHTS),
+    (SkillId::WondrousTrickMiracleParade, WONDROUS_TRICK_MIRACLE_PARADE),
+];
+
+pub struct CardForceTranslocation();
+impl SkillImpl for CardForceTranslocation {
+    fn commands(
+        &self,
+        src_player: &PlayerState,
+        _: &StatusCollection,
+        _: &StatusCollection,
+        ctx: &CommandContext,
+        cmds: &mut CommandList<(CommandContext, Command)>,
+    ) {
+        if !src_player.flags.contains(PlayerFlag::ChargedAttack) {
+            return;
+        }
+        cmds.push((*ctx, Command::Summon(SummonId::GrinMalkinHat)));
+        cmds.push((
+            *ctx,
+            Command::ApplyCharacterStatus(StatusId::PropSurplus, CmdCharIdx::Active),
+        ));
+    }
+}
+
+pub mod prop_surplus {
+    use super::*;
+
+    pub const S: Status = Status::new_usages("Prop Surplus", StatusAttachMode::Character, 1, Some(3));
+
+    decl_status_impl_type!(PropSurplus, I);
+    impl StatusImpl for PropSurplus {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::OutgoingDMG]
+        }
+
+        fn outgoing_dmg(&self, e: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if e.skill_id() != Some(SkillId::BewilderingLights) {
+                return None;
+            }
+            dmg.dmg += e.eff_state.usages();
+            Some(AppliedEffectResult::DeleteSelf)
+        }
+    }
+}
+
+pub mod grin_malkin_hat {
+    use super::*;
+
+    pub const S: Status = Status::new_summon_usages("Grin-Malkin Hat", 1);
+
+    pub const I: EndPhaseDealDMG = EndPhaseDealDMG(deal_elem_dmg(Element::Pyro, 1, 0));
+}
@@ -0,0 +1,89 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Maguu Kenki",
+    elem: Element::Anemo,
+    weapon: WeaponType::Other,
+    faction: Faction::Monster,
+    max_health: 10,
+    max_energy: 3,
+    skills: list8![
+        SkillId::Ichimonji,
+        SkillId::BlusteringBlade,
+        SkillId::FrostyAssault,
+        SkillId::PseudoTenguSweeper,
+    ],
+    passive: None,
+};
+
+pub const ICHIMONJI: Skill = skill_na("Ichimonji", Element::Anemo, 2, DealDMGType::Physical);
+
+pub const BLUSTERING_BLADE: Skill = Skill {
+    name: "Blustering Blade",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Anemo, 3, 0, 0),
+    summon: Some(SummonSpec::One(SummonId::ShadowswordLoneGale)),
+    ..Skill::new()
+};
+
+pub const FROSTY_ASSAULT: Skill = Skill {
+    name: "Frosty Assault",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Cryo, 3, 0, 0),
+    summon: Some(SummonSpec::One(SummonId::ShadowswordGallopingFrost)),
+    ..Skill::new()
+};
+
+pub const PSEUDO_TENGU_SWEEPER: Skill = Skill {
+    name: "Pseudo Tengu Sweeper",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Anemo, 3, 0, 3),
+    deal_dmg: Some(deal_elem_dmg(Element::Anemo, 4, 0)),
+    skill_impl: Some(&PseudoTenguSweeper()),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 4] = [
+    (SkillId::Ichimonji, ICHIMONJI),
+    (SkillId::BlusteringBlade, BLUSTERING_BLADE),
+    (SkillId::FrostyAssault, FROSTY_ASSAULT),
+    (SkillId::PseudoTenguSweeper, PSEUDO_TENGU_SWEEPER),
+];
+
+/// Triggers the DMG of each Shadowsword summon without consuming usages.
+pub struct PseudoTenguSweeper();
+impl SkillImpl for PseudoTenguSweeper {
+    fn commands(
+        &self,
+        _: &PlayerState,
+        status_collection: &StatusCollection,
+        _: &StatusCollection,
+        ctx: &CommandContext,
+        cmds: &mut CommandList<(CommandContext, Command)>,
+    ) {
+        if status_collection.has_summon(SummonId::ShadowswordLoneGale) {
+            cmds.push((*ctx, Command::DealDMG(deal_elem_dmg(Element::Anemo, 1, 0))));
+        }
+        if status_collection.has_summon(SummonId::ShadowswordGallopingFrost) {
+            cmds.push((*ctx, Command::DealDMG(deal_elem_dmg(Element::Cryo, 1, 0))));
+        }
+    }
+}
+
+pub mod shadowsword_lone_gale {
+    use super::*;
+
+    pub const S: Status =
+        Status::new_summon_usages("Shadowsword: Lone Gale", 2).talent_usages_increase(CharId::MaguuKenki, 1);
+
+    pub const I: EndPhaseDealDMG = EndPhaseDealDMG(deal_elem_dmg(Element::Anemo, 1, 0));
+}
+
+pub mod shadowsword_galloping_frost {
+    use super::*;
+
+    pub const S: Status =
+        Status::new_summon_usages("Shadowsword: Galloping Frost", 2).talent_usages_increase(CharId::MaguuKenki, 1);
+
+    pub const I: EndPhaseDealDMG = EndPhaseDealDMG(deal_elem_dmg(Element::Cryo, 1, 0));
+}
@@ -0,0 +1,110 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Mirror Maiden",
+    elem: Element::Hydro,
+    weapon: WeaponType::Other,
+    faction: Faction::Fatui,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![SkillId::WaterBall, SkillId::InfluxBlast, SkillId::RippledReflection,],
+    passive: None,
+};
+
+pub const WATER_BALL: Skill = skill_na("Water Ball", Element::Hydro, 1, DealDMGType::HYDRO);
+
+pub const INFLUX_BLAST: Skill = Skill {
+    name: "Influx Blast",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Hydro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Hydro, 2, 0)),
+    skill_impl: Some(&InfluxBlast()),
+    ..Skill::new()
+};
+
+pub const RIPPLED_REFLECTION: Skill = Skill {
+    name: "Rippled Reflection",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Hydro, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Hydro, 5, 0)),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::WaterBall, WATER_BALL),
+    (SkillId::InfluxBlast, INFLUX_BLAST),
+    (SkillId::RippledReflection, RIPPLED_REFLECTION),
+];
+
+/// Attaches Refraction, or the stronger Mirror Cage variant when the Talent card is equipped.
+pub struct InfluxBlast();
+impl SkillImpl for InfluxBlast {
+    fn commands(
+        &self,
+        src_player: &PlayerState,
+        _: &StatusCollection,
+        _: &StatusCollection,
+        ctx: &CommandContext,
+        cmds: &mut CommandList<(CommandContext, Command)>,
+    ) {
+        let status_id = if src_player.active_character_has_talent_equipped() {
+            StatusId::RefractionMirrorCage
+        } else {
+            StatusId::Refraction
+        };
+        cmds.push((*ctx, Command::ApplyCharacterStatusToTarget(status_id)));
+    }
+}
+
+pub mod refraction {
+    use super::*;
+
+    pub const S: Status = Status::new_duration("Refraction", StatusAttachMode::Character, 2).applies_to_opposing();
+
+    decl_status_impl_type!(Refraction, I);
+    impl StatusImpl for Refraction {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::IncomingDMG]
+        }
+
+        fn incoming_dmg(&self, _: &StatusImplContext, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if dmg.dmg_type != DealDMGType::HYDRO {
+                return None;
+            }
+            dmg.dmg += 1;
+            Some(AppliedEffectResult::NoChange)
+        }
+    }
+}
+
+pub mod refraction_mirror_cage {
+    use super::*;
+
+    pub const S: Status =
+        Status::new_duration("Refraction (Mirror Cage)", StatusAttachMode::Character, 3).applies_to_opposing();
+
+    decl_status_impl_type!(RefractionMirrorCage, I);
+    impl StatusImpl for RefractionMirrorCage {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::IncomingDMG | RespondsTo::UpdateCost]
+        }
+
+        fn incoming_dmg(&self, e: &StatusImplContext, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            refraction::I.incoming_dmg(e, dmg)
+        }
+
+        fn update_cost(
+            &self,
+            e: &StatusImplContext,
+            cost: &mut Cost,
+            cost_type: CostType,
+        ) -> Option<AppliedEffectResult> {
+            cost_type.is_switching().then_some(())?;
+            if e.status_key.char_idx() != Some(e.src_player_state.active_char_idx) {
+                return None;
+            }
+            cost.unaligned_cost += 1;
+            Some(AppliedEffectResult::NoChange)
+        }
+    }
+}
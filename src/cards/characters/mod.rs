@@ -8,9 +8,14 @@ use crate::status_impls::primitives::all::*;
 
 use super::ids::GetCharCard;
 
+pub mod abyss_herald_wicked_torrents;
+pub mod abyss_lector_fathomless_flames;
+pub mod abyss_lector_violet_lightning;
 pub mod albedo;
+pub mod alhaitham;
 pub mod amber;
 pub mod arataki_itto;
+pub mod baizhu;
 pub mod barbara;
 pub mod beidou;
 pub mod bennett;
@@ -18,8 +23,13 @@ pub mod candace;
 pub mod chongyun;
 pub mod collei;
 pub mod cyno;
+pub mod dehya;
 pub mod diluc;
 pub mod diona;
+pub mod dori;
+pub mod electro_hypostasis;
+pub mod eremite_desert_clearwater;
+pub mod eremite_sunfrost;
 pub mod eula;
 pub mod fatui_pyro_agent;
 pub mod fischl;
@@ -31,8 +41,13 @@ pub mod kaeya;
 pub mod kamisato_ayaka;
 pub mod kamisato_ayato;
 pub mod keqing;
+pub mod kirara;
 pub mod klee;
 pub mod kujou_sara;
+pub mod lisa;
+pub mod lyney;
+pub mod maguu_kenki;
+pub mod mirror_maiden;
 pub mod mona;
 pub mod nahida;
 pub mod nilou;
@@ -43,6 +58,7 @@ pub mod raiden_shogun;
 pub mod razor;
 pub mod rhodeia_of_loch;
 pub mod sangonomiya_kokomi;
+pub mod sayu;
 pub mod shenhe;
 pub mod stonehide_lawachurl;
 pub mod sucrose;
@@ -53,6 +69,7 @@ pub mod wanderer;
 pub mod xiangling;
 pub mod xiao;
 pub mod xingqiu;
+pub mod xinyan;
 pub mod yae_miko;
 pub mod yanfei;
 pub mod yaoyao;
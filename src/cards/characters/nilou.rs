@@ -50,6 +50,7 @@ impl SkillImpl for DanceOfHaftkarsvar {
         &self,
         src_player: &PlayerState,
         _: &StatusCollection,
+        _: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
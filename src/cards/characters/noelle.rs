@@ -77,7 +77,7 @@ pub mod full_plate {
                 return None;
             }
 
-            e.add_cmd(Command::HealAll(1));
+            e.add_cmd(Command::Heal(1, CmdCharIdx::All));
             Some(AppliedEffectResult::ConsumeOncePerRound)
         }
     }
@@ -53,7 +53,7 @@ pub mod herald_of_frost {
                 return None;
             };
 
-            c.add_cmd(Command::HealTakenMostDMG(1));
+            c.add_cmd(Command::Heal(1, CmdCharIdx::MostInjured));
             Some(AppliedEffectResult::NoChange)
         }
     }
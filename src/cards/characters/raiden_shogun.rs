@@ -33,7 +33,7 @@ pub const SECRET_ART_MUSOU_SHINSETSU: Skill = Skill {
     skill_type: SkillType::ElementalBurst,
     cost: cost_elem(Element::Electro, 4, 0, 2),
     deal_dmg: Some(deal_elem_dmg(Element::Electro, 3, 1)),
-    commands: list8![Command::AddEnergyToNonActiveCharacters(2)],
+    commands: list8![Command::AddEnergy(2, CmdCharIdx::AllStandby)],
     ..Skill::new()
 };
 
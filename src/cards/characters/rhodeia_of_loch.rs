@@ -69,6 +69,7 @@ impl SkillImpl for TideAndTorrent {
         &self,
         _: &PlayerState,
         status_collection: &StatusCollection,
+        _: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
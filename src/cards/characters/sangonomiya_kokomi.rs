@@ -79,7 +79,7 @@ pub mod ceremonial_garment {
             let SkillType::NormalAttack = e.event_skill_ensuring_attached_character()?.skill_type() else {
                 return None;
             };
-            e.out_cmds.push((*e.ctx_for_dmg, Command::HealAll(1)));
+            e.out_cmds.push((*e.ctx_for_dmg, Command::Heal(1, CmdCharIdx::All)));
             Some(AppliedEffectResult::NoChange)
         }
     }
@@ -0,0 +1,81 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Sayu",
+    elem: Element::Anemo,
+    weapon: WeaponType::Claymore,
+    faction: Faction::Inazuma,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![
+        SkillId::ShuumatsubanNinjaBlade,
+        SkillId::YoohooArtFuuinDash,
+        SkillId::YoohooArtMujinaFlurry,
+    ],
+    passive: None,
+};
+
+pub const SHUUMATSUBAN_NINJA_BLADE: Skill =
+    skill_na("Shuumatsuban Ninja Blade", Element::Anemo, 2, DealDMGType::Physical);
+
+pub const YOOHOO_ART_FUUIN_DASH: Skill = Skill {
+    name: "Yoohoo Art: Fuuin Dash",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Anemo, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Anemo, 2, 0)),
+    apply: Some(StatusId::FuufuuWindwheel),
+    ..Skill::new()
+};
+
+pub const YOOHOO_ART_MUJINA_FLURRY: Skill = Skill {
+    name: "Yoohoo Art: Mujina Flurry",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Anemo, 3, 0, 2),
+    deal_dmg: Some(deal_elem_dmg(Element::Anemo, 1, 0)),
+    summon: Some(SummonSpec::One(SummonId::MujiMujiDaruma)),
+    commands: list8![Command::Heal(2, CmdCharIdx::All)],
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::ShuumatsubanNinjaBlade, SHUUMATSUBAN_NINJA_BLADE),
+    (SkillId::YoohooArtFuuinDash, YOOHOO_ART_FUUIN_DASH),
+    (SkillId::YoohooArtMujinaFlurry, YOOHOO_ART_MUJINA_FLURRY),
+];
+
+pub mod fuufuu_windwheel {
+    use super::*;
+
+    pub const S: Status = Status::new_usages("Fuufuu Windwheel", StatusAttachMode::Character, 1, None);
+
+    decl_status_impl_type!(FuufuuWindwheel, I);
+    impl StatusImpl for FuufuuWindwheel {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::SwitchIsFastAction | RespondsTo::OutgoingDMG]
+        }
+
+        fn outgoing_dmg(&self, e: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if !e.has_talent_equipped() {
+                return None;
+            }
+            dmg.dmg += 1;
+            Some(AppliedEffectResult::NoChange)
+        }
+
+        fn switch_is_fast_action(&self, _: &AppliedEffectState, res: &mut bool) -> Option<AppliedEffectResult> {
+            *res = true;
+            Some(AppliedEffectResult::ConsumeUsage)
+        }
+    }
+}
+
+pub mod muji_muji_daruma {
+    use super::*;
+
+    pub const S: Status = Status::new_summon_usages("Muji-Muji Daruma", 2);
+
+    pub const I: EndPhaseCommands = EndPhaseCommands(list8![
+        Command::DealDMG(deal_elem_dmg(Element::Anemo, 1, 0)),
+        Command::Heal(2, CmdCharIdx::MostInjured),
+    ]);
+}
@@ -49,6 +49,7 @@ impl SkillImpl for FoulLegacyRagingTide {
         &self,
         src_player: &PlayerState,
         status_collection: &StatusCollection,
+        _: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
@@ -73,6 +74,7 @@ impl SkillImpl for HavocObliteration {
         &self,
         src_player: &PlayerState,
         status_collection: &StatusCollection,
+        _: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
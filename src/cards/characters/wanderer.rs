@@ -46,6 +46,7 @@ impl SkillImpl for KyougenFiveCeremonialPlaysImpl {
         &self,
         src_player: &PlayerState,
         status_collection: &StatusCollection,
+        _: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
@@ -93,11 +94,7 @@ pub mod windfavored {
             if !e.is_normal_attack() {
                 return None;
             }
-            // TODO next character, skipping dead and is cyclic
-            let Some(char_idx1) = tgt_chars.relative_switch_char_idx(tgt_active_char_idx, RelativeCharIdx::Next) else {
-                return None;
-            };
-            *tgt_char_idx = char_idx1;
+            *tgt_char_idx = tgt_chars.select_char_idx(tgt_active_char_idx, None, CmdCharIdx::NextAlive)?;
             Some(AppliedEffectResult::NoChange)
         }
     }
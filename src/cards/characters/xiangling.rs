@@ -28,6 +28,7 @@ impl SkillImpl for GuobaAttack {
         &self,
         src_player: &PlayerState,
         _: &StatusCollection,
+        _: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut crate::data_structures::CommandList<(CommandContext, Command)>,
     ) {
@@ -0,0 +1,76 @@
+use super::*;
+
+pub const C: CharCard = CharCard {
+    name: "Xinyan",
+    elem: Element::Pyro,
+    weapon: WeaponType::Claymore,
+    faction: Faction::Liyue,
+    max_health: 10,
+    max_energy: 2,
+    skills: list8![SkillId::DanceOnFire, SkillId::SweepingFervor, SkillId::RiffRevolution,],
+    passive: None,
+};
+
+pub const DANCE_ON_FIRE: Skill = skill_na("Dance on Fire", Element::Pyro, 2, DealDMGType::Physical);
+
+pub const SWEEPING_FERVOR: Skill = Skill {
+    name: "Sweeping Fervor",
+    skill_type: SkillType::ElementalSkill,
+    cost: cost_elem(Element::Pyro, 3, 0, 0),
+    deal_dmg: Some(deal_elem_dmg(Element::Pyro, 2, 0)),
+    apply: Some(StatusId::FervorShield),
+    ..Skill::new()
+};
+
+pub const RIFF_REVOLUTION: Skill = Skill {
+    name: "Riff Revolution",
+    skill_type: SkillType::ElementalBurst,
+    cost: cost_elem(Element::Pyro, 3, 0, 2),
+    deal_dmg: Some(DealDMG::new(DealDMGType::Physical, 3, 1)),
+    apply: Some(StatusId::FestiveFires),
+    ..Skill::new()
+};
+
+pub const SKILLS: [(SkillId, Skill); 3] = [
+    (SkillId::DanceOnFire, DANCE_ON_FIRE),
+    (SkillId::SweepingFervor, SWEEPING_FERVOR),
+    (SkillId::RiffRevolution, RIFF_REVOLUTION),
+];
+
+pub mod fervor_shield {
+    use super::*;
+
+    pub const S: Status =
+        Status::new_shield_points("Fervor Shield", StatusAttachMode::Team, 2, None).casted_by_character(CharId::Xinyan);
+
+    // Talent: Normal Attacks deal +1 DMG once per round while the shield is active.
+    decl_status_impl_type!(FervorShield, I);
+    impl StatusImpl for FervorShield {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::OutgoingDMG]
+        }
+
+        fn outgoing_dmg(&self, e: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if !e.has_talent_equipped() || !e.is_normal_attack() || !e.eff_state.can_use_once_per_round() {
+                return None;
+            }
+            dmg.dmg += 1;
+            Some(AppliedEffectResult::ConsumeOncePerRound)
+        }
+    }
+}
+
+pub mod festive_fires {
+    use super::*;
+
+    pub const S: Status = Status::new_usages("Festive Fires", StatusAttachMode::Team, 2, None);
+
+    decl_event_handler_trait_impl!(OwnCharacterSkillEvent(FestiveFires), I);
+    impl OwnCharacterSkillEvent for FestiveFires {
+        const SKILL_TYPES: EnumSet<SkillType> = enum_set![SkillType::NormalAttack];
+        fn invoke(e: &mut TriggerEventContext<XEvent>, _: XEventSkill) -> Option<AppliedEffectResult> {
+            e.cmd_deal_dmg(DealDMGType::PYRO, 1, 0);
+            Some(AppliedEffectResult::ConsumeUsage)
+        }
+    }
+}
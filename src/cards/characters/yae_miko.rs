@@ -46,6 +46,7 @@ impl SkillImpl for GreatSecretArtTenkoKenshin {
         &self,
         _: &PlayerState,
         status_collection: &StatusCollection,
+        _: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
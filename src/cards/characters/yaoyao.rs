@@ -50,10 +50,10 @@ pub mod yuegui_throwing_mode {
     trigger_event_impl!(YueguiThrowingMode, [Switched], |e| {
         if e.c.has_talent_equipped() && e.c.eff_state.usages() == 1 {
             e.cmd_deal_dmg(DealDMGType::DENDRO, 2, 0);
-            e.add_cmd(Command::HealTakenMostDMG(2));
+            e.add_cmd(Command::Heal(2, CmdCharIdx::MostInjured));
         } else {
             e.cmd_deal_dmg(DealDMGType::DENDRO, 1, 0);
-            e.add_cmd(Command::HealTakenMostDMG(1));
+            e.add_cmd(Command::Heal(1, CmdCharIdx::MostInjured));
         }
         Some(AppliedEffectResult::ConsumeUsage)
     });
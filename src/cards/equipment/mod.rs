@@ -75,8 +75,9 @@ impl StatusImpl for ElementalArtifact {
             return None;
         }
 
-        // TODO also check equip talent cost
-        let CostType::Skill(_) = cost_type else { return None };
+        let (CostType::Skill(..) | CostType::Talent { .. }) = cost_type else {
+            return None;
+        };
         cost.try_reduce_elemental_cost(1, self.elem)
             .then_some(AppliedEffectResult::ConsumeOncePerRound)
     }
@@ -389,3 +389,303 @@ pub mod the_starry_skies_their_flowers_rain {
         skill => DanceOfHaftkarsvar,
     );
 }
+
+pub mod pulsating_witch {
+    talent!(
+        PulsatingWitch,
+        "Pulsating Witch",
+        Cost::elem(Element::Electro, 3),
+        Lisa,
+        skill => VioletArc,
+        status => PulsatingWitch,
+    );
+
+    impl StatusImpl for PulsatingWitch {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::TriggerEvent]
+        }
+
+        fn responds_to_triggers(&self) -> EnumSet<EventId> {
+            enum_set![EventId::Switched]
+        }
+
+        fn trigger_event(&self, e: &mut TriggerEventContext<EventId>) -> Option<AppliedEffectResult> {
+            let EventId::Switched = e.event_id else { return None };
+            let CommandSource::Switch { from_char_idx, .. } = e.c.ctx.src else {
+                return None;
+            };
+            if e.status_key.char_idx() != Some(from_char_idx) || !e.c.eff_state.can_use_once_per_round() {
+                return None;
+            }
+            e.add_cmd(Command::ApplyCharacterStatusToTarget(StatusId::Conductive));
+            Some(AppliedEffectResult::ConsumeOncePerRound)
+        }
+    }
+}
+
+pub mod rockin_in_a_flaming_world {
+    talent!(
+        RockinInAFlamingWorld,
+        "Rockin' in a Flaming World",
+        Cost::elem(Element::Pyro, 3),
+        Xinyan,
+        skill => SweepingFervor,
+    );
+}
+
+pub mod skiving_new_and_improved {
+    talent!(
+        SkivingNewAndImproved,
+        "Skiving: New and Improved",
+        Cost::elem(Element::Anemo, 3),
+        Sayu,
+        skill => YoohooArtFuuinDash,
+    );
+}
+
+pub mod stalwart_and_true {
+    talent!(
+        StalwartAndTrue,
+        "Stalwart and True",
+        Cost::elem(Element::Pyro, 3),
+        Dehya,
+        skill => MoltenInferno,
+        status => StalwartAndTrue,
+    );
+
+    impl StatusImpl for StalwartAndTrue {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::TriggerEvent]
+        }
+
+        fn responds_to_triggers(&self) -> EnumSet<EventId> {
+            enum_set![EventId::EndPhase]
+        }
+
+        fn trigger_event(&self, e: &mut TriggerEventContext<EventId>) -> Option<AppliedEffectResult> {
+            let EventId::EndPhase = e.event_id else { return None };
+            let char_idx = e.status_key.char_idx()?;
+            if e.c.character_state(char_idx)?.hp() > 6 {
+                return None;
+            }
+            e.add_cmd(Command::Heal(2, CmdCharIdx::Index(char_idx)));
+            Some(AppliedEffectResult::NoChange)
+        }
+    }
+}
+
+pub mod structuration {
+    talent!(
+        Structuration,
+        "Structuration",
+        Cost::elem(Element::Dendro, 3),
+        Alhaitham,
+        skill => UniversalityAnElaborationOnForm,
+    );
+}
+
+pub mod all_things_are_of_the_earth {
+    talent!(
+        AllThingsAreOfTheEarth,
+        "All Things Are of the Earth",
+        Cost::elem(Element::Dendro, 4).with_energy(2),
+        Baizhu,
+        skill => HolisticRevivification,
+    );
+}
+
+pub mod countless_sights_to_see {
+    talent!(
+        CountlessSightsToSee,
+        "Countless Sights to See",
+        Cost::elem(Element::Dendro, 3),
+        Kirara,
+        skill => MeowteorKick,
+    );
+}
+
+pub mod conclusive_ovation {
+    talent!(
+        ConclusiveOvation,
+        "Conclusive Ovation",
+        Cost::elem(Element::Pyro, 3),
+        Lyney,
+        skill => BewilderingLights,
+        status => ConclusiveOvation,
+    );
+
+    impl StatusImpl for ConclusiveOvation {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::OutgoingDMG]
+        }
+
+        fn outgoing_dmg(&self, e: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if e.skill_id() != Some(SkillId::BewilderingLights) {
+                return None;
+            }
+            dmg.dmg += 1;
+            Some(AppliedEffectResult::NoChange)
+        }
+    }
+}
+
+pub mod discretionary_supplement {
+    talent!(
+        DiscretionarySupplement,
+        "Discretionary Supplement",
+        Cost::elem(Element::Electro, 3).with_energy(2),
+        Dori,
+        skill => AlcazarzaraysExactitude,
+    );
+}
+
+pub mod absorbing_prism {
+    use super::*;
+
+    pub const C: Card = Card {
+        name: "Absorbing Prism",
+        cost: Cost::elem(Element::Electro, 3),
+        card_type: CardType::Talent(CharId::ElectroHypostasis),
+        effects: list8![],
+        card_impl: Some(&AbsorbingPrism()),
+    };
+
+    /// Equipped on the active Electro Hypostasis, which heals 3 HP and re-attaches Electro Crystal Core.
+    pub struct AbsorbingPrism();
+
+    impl AbsorbingPrism {
+        pub const TALENT: Talent = Talent {
+            skill_id: None,
+            status_id: None,
+        };
+    }
+
+    impl CardImpl for AbsorbingPrism {
+        fn can_be_played(&self, cic: &CardImplContext) -> CanBePlayedResult {
+            let res = Self::TALENT.can_be_played(cic);
+            if res != CanBePlayedResult::CanBePlayed {
+                return res;
+            }
+            let Some(CardSelection::OwnCharacter(char_idx)) = cic.selection else {
+                return CanBePlayedResult::InvalidSelection;
+            };
+            if char_idx != cic.players[cic.active_player_id].active_char_idx {
+                return CanBePlayedResult::CannotBePlayed;
+            }
+            CanBePlayedResult::CanBePlayed
+        }
+
+        fn selection(&self) -> Option<CardSelectionSpec> {
+            Self::TALENT.selection()
+        }
+
+        fn effects(
+            &self,
+            cic: &CardImplContext,
+            ctx: &CommandContext,
+            commands: &mut CommandList<(CommandContext, Command)>,
+        ) {
+            Self::TALENT.effects(cic, ctx, commands);
+            commands.push((*ctx, Command::Heal(3, CmdCharIdx::CardSelected)));
+            commands.push((
+                *ctx,
+                Command::ApplyCharacterStatus(StatusId::ElectroCrystalCore, CmdCharIdx::CardSelected),
+            ));
+        }
+    }
+}
+
+pub mod transcendent_automaton {
+    talent!(
+        TranscendentAutomaton,
+        "Transcendent Automaton",
+        Cost::elem(Element::Anemo, 3),
+        MaguuKenki,
+        skill => BlusteringBlade,
+    );
+}
+
+pub mod mirror_cage {
+    talent!(
+        MirrorCage,
+        "Mirror Cage",
+        Cost::elem(Element::Hydro, 3),
+        MirrorMaiden,
+        skill => InfluxBlast,
+    );
+}
+
+pub mod embers_rekindled {
+    talent!(
+        EmbersRekindled,
+        "Embers Rekindled",
+        Cost::elem(Element::Pyro, 3).with_energy(2),
+        AbyssLectorFathomlessFlames,
+        skill => OminousStar,
+    );
+}
+
+pub mod surging_undercurrent {
+    talent!(
+        SurgingUndercurrent,
+        "Surging Undercurrent",
+        Cost::elem(Element::Hydro, 3),
+        AbyssHeraldWickedTorrents,
+        skill => VortexEdict,
+        status => SurgingUndercurrent,
+    );
+
+    impl StatusImpl for SurgingUndercurrent {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::OutgoingDMG]
+        }
+
+        fn outgoing_dmg(&self, e: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if e.skill_id() != Some(SkillId::VortexEdict) {
+                return None;
+            }
+            dmg.piercing_dmg_to_standby += 1;
+            Some(AppliedEffectResult::NoChange)
+        }
+    }
+}
+
+pub mod featherfall_judgment {
+    talent!(
+        FeatherfallJudgment,
+        "Featherfall Judgment",
+        Cost::elem(Element::Electro, 3),
+        Cyno,
+        skill => SecretRiteChasmicSoulfarer,
+    );
+}
+
+pub mod lightning_veil {
+    talent!(
+        LightningVeil,
+        "Lightning Veil",
+        Cost::elem(Element::Electro, 3).with_energy(2),
+        AbyssLectorVioletLightning,
+        skill => VeilOfVioletLightning,
+    );
+}
+
+pub mod oasis_blessing {
+    talent!(
+        OasisBlessing,
+        "Oasis Blessing",
+        Cost::elem(Element::Hydro, 3),
+        EremiteDesertClearwater,
+        skill => SpringOfRespite,
+    );
+}
+
+pub mod biting_sands {
+    talent!(
+        BitingSands,
+        "Biting Sands",
+        Cost::elem(Element::Cryo, 3),
+        EremiteSunfrost,
+        skill => FrostboundFeint,
+    );
+}
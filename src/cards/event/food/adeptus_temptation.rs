@@ -5,10 +5,12 @@ const NAME: &str = "Adeptus' Temptation";
 pub const C: Card = Card {
     name: NAME,
     cost: Cost::unaligned(2),
-    // TODO doesn't apply status properly?
-    effects: list8![Command::Heal(2, CmdCharIdx::CardSelected)],
+    effects: list8![Command::ApplyCharacterStatus(
+        StatusId::AdeptusTemptation,
+        CmdCharIdx::CardSelected
+    )],
     card_type: CardType::Food,
-    card_impl: Some(&FoodCardImpl()),
+    card_impl: Some(&FoodCardImpl::SELECTED),
 };
 
 pub const S: Status = Status::new_duration(NAME, StatusAttachMode::Character, 1);
@@ -1,20 +1,70 @@
 use super::*;
 
-pub struct FoodCardImpl();
+/// Which characters a food card is eaten by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FoodTarget {
+    /// The selected character, which must not be Satiated.
+    Selected,
+    /// All alive characters that are not Satiated. No selection is needed.
+    Team,
+}
+
+/// Shared card implementation for food cards.
+///
+/// The card effects are run once for each fed character with `CmdCharIdx::CardSelected`
+/// resolving to that character, and each fed character becomes Satiated.
+pub struct FoodCardImpl(pub FoodTarget);
+
+impl FoodCardImpl {
+    pub const SELECTED: Self = Self(FoodTarget::Selected);
+    pub const TEAM: Self = Self(FoodTarget::Team);
+
+    fn is_satiated(cic: &CardImplContext, char_idx: u8) -> bool {
+        cic.status_collections[cic.active_player_id].has_character_status(char_idx, StatusId::Satiated)
+    }
+
+    fn fed_char_idxs<'a>(&self, cic: &'a CardImplContext) -> impl Iterator<Item = u8> + 'a {
+        let selected = match (self.0, cic.selection) {
+            (FoodTarget::Selected, Some(CardSelection::OwnCharacter(i))) => Some(i),
+            _ => None,
+        };
+        let team = self.0 == FoodTarget::Team;
+        cic.players[cic.active_player_id]
+            .char_states
+            .enumerate_valid()
+            .map(|(i, _)| i)
+            .filter(move |&i| team || selected == Some(i))
+            .filter(move |&i| !Self::is_satiated(cic, i))
+    }
+}
+
 impl CardImpl for FoodCardImpl {
     fn selection(&self) -> Option<CardSelectionSpec> {
-        Some(CardSelectionSpec::OwnCharacter)
+        match self.0 {
+            FoodTarget::Selected => Some(CardSelectionSpec::OwnCharacter),
+            FoodTarget::Team => None,
+        }
     }
 
     fn can_be_played(&self, cic: &CardImplContext) -> CanBePlayedResult {
-        let Some(CardSelection::OwnCharacter(char_idx)) = cic.selection else {
-            return CanBePlayedResult::InvalidSelection;
-        };
-        let status_collection = &cic.status_collections[cic.active_player_id];
-        if status_collection.has_character_status(char_idx, StatusId::Satiated) {
-            CanBePlayedResult::InvalidSelection
-        } else {
-            CanBePlayedResult::CanBePlayed
+        match self.0 {
+            FoodTarget::Selected => {
+                let Some(CardSelection::OwnCharacter(char_idx)) = cic.selection else {
+                    return CanBePlayedResult::InvalidSelection;
+                };
+                if Self::is_satiated(cic, char_idx) {
+                    CanBePlayedResult::InvalidSelection
+                } else {
+                    CanBePlayedResult::CanBePlayed
+                }
+            }
+            FoodTarget::Team => {
+                if self.fed_char_idxs(cic).next().is_some() {
+                    CanBePlayedResult::CanBePlayed
+                } else {
+                    CanBePlayedResult::CannotBePlayed
+                }
+            }
         }
     }
 
@@ -24,11 +74,18 @@ impl CardImpl for FoodCardImpl {
         ctx: &CommandContext,
         commands: &mut crate::data_structures::CommandList<(CommandContext, Command)>,
     ) {
-        for &eff in cic.card.effects.iter() {
-            commands.push((*ctx, eff))
-        }
-        if let CardSelection::OwnCharacter(i) = cic.selection.expect("FoodCardImpl: must have selection") {
-            commands.push((*ctx, Command::ApplyCharacterStatus(StatusId::Satiated, i.into())));
+        for i in self.fed_char_idxs(cic) {
+            let ctx = CommandContext {
+                src: CommandSource::Card {
+                    card_id: cic.card_id,
+                    target: Some(CardSelection::OwnCharacter(i)),
+                },
+                ..*ctx
+            };
+            for &eff in cic.card.effects.iter() {
+                commands.push((ctx, eff))
+            }
+            commands.push((ctx, Command::ApplyCharacterStatus(StatusId::Satiated, i.into())));
         }
     }
 }
@@ -41,7 +98,7 @@ pub mod sweet_madame {
         cost: Cost::ZERO,
         effects: list8![Command::Heal(1, CmdCharIdx::CardSelected)],
         card_type: CardType::Food,
-        card_impl: Some(&FoodCardImpl()),
+        card_impl: Some(&FoodCardImpl::SELECTED),
     };
 }
 
@@ -53,7 +110,7 @@ pub mod mondstadt_hash_brown {
         cost: Cost::ONE,
         effects: list8![Command::Heal(2, CmdCharIdx::CardSelected)],
         card_type: CardType::Food,
-        card_impl: Some(&FoodCardImpl()),
+        card_impl: Some(&FoodCardImpl::SELECTED),
     };
 }
 
@@ -66,3 +123,5 @@ pub mod adeptus_temptation;
 pub mod minty_meat_rolls;
 
 pub mod northern_smoked_chicken;
+
+pub mod tandoori_roast_chicken;
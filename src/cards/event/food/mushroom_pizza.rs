@@ -5,15 +5,14 @@ pub const C: Card = Card {
     cost: Cost::ONE,
     effects: list8![
         Command::Heal(1, CmdCharIdx::CardSelected),
-        Command::ApplyCharacterStatus(StatusId::MushroomPizza, CmdCharIdx::Active)
+        Command::ApplyCharacterStatus(StatusId::MushroomPizza, CmdCharIdx::CardSelected)
     ],
     card_type: CardType::Food,
-    card_impl: Some(&FoodCardImpl()),
+    card_impl: Some(&FoodCardImpl::SELECTED),
 };
 
 pub const S: Status = Status::new_usages("Mushroom Pizza", StatusAttachMode::Character, 2, None);
 
-// TODO tests
 decl_status_impl_type!(MushroomPizza, I);
 impl StatusImpl for MushroomPizza {
     fn responds_to(&self) -> EnumSet<RespondsTo> {
@@ -7,10 +7,10 @@ pub const C: Card = Card {
     cost: Cost::ZERO,
     effects: list8![Command::ApplyCharacterStatus(
         StatusId::NorthernSmokedChicken,
-        CmdCharIdx::Active
+        CmdCharIdx::CardSelected
     )],
     card_type: CardType::Food,
-    card_impl: Some(&FoodCardImpl()),
+    card_impl: Some(&FoodCardImpl::SELECTED),
 };
 
 pub const S: Status = Status::new_usages(NAME, StatusAttachMode::Character, 1, None);
@@ -0,0 +1,32 @@
+use super::*;
+
+const NAME: &str = "Tandoori Roast Chicken";
+
+pub const C: Card = Card {
+    name: NAME,
+    cost: Cost::unaligned(2),
+    effects: list8![Command::ApplyCharacterStatus(
+        StatusId::TandooriRoastChicken,
+        CmdCharIdx::CardSelected
+    )],
+    card_type: CardType::Food,
+    card_impl: Some(&FoodCardImpl::TEAM),
+};
+
+pub const S: Status = Status::new_duration(NAME, StatusAttachMode::Character, 1);
+
+decl_status_impl_type!(TandooriRoastChicken, I);
+impl StatusImpl for TandooriRoastChicken {
+    fn responds_to(&self) -> EnumSet<RespondsTo> {
+        enum_set![RespondsTo::OutgoingDMG]
+    }
+
+    fn outgoing_dmg(&self, e: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+        let Some(SkillType::ElementalSkill) = e.skill_type() else {
+            return None;
+        };
+
+        dmg.dmg += 2;
+        Some(AppliedEffectResult::DeleteSelf)
+    }
+}
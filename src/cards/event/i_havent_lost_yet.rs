@@ -8,9 +8,12 @@ pub const C: Card = Card {
     effects: list8![
         Command::AddSingleDice(Dice::Omni, 1),
         Command::AddEnergy(1, CmdCharIdx::Active),
+        Command::MarkUsedThisRound(ROUND_USAGE_KEY),
     ],
 };
 
+const ROUND_USAGE_KEY: RoundUsageKey = RoundUsageKey::Card(CardId::IHaventLostYet);
+
 pub struct IHaventLostYet();
 
 pub const I: IHaventLostYet = IHaventLostYet();
@@ -18,8 +21,7 @@ pub const I: IHaventLostYet = IHaventLostYet();
 impl CardImpl for IHaventLostYet {
     fn can_be_played(&self, cic: &CardImplContext) -> CanBePlayedResult {
         let player = &cic.players[cic.active_player_id];
-        // TODO apply a status to enforce once per turn
-        if player.flags.contains(PlayerFlag::DiedThisRound) {
+        if player.flags.contains(PlayerFlag::DiedThisRound) && !player.is_used_this_round(ROUND_USAGE_KEY) {
             CanBePlayedResult::CanBePlayed
         } else {
             CanBePlayedResult::CannotBePlayed
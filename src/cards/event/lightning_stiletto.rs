@@ -37,7 +37,13 @@ impl CardImpl for LightningStiletto {
             return;
         };
         let status_collection = cic.status_collections.get(cic.active_player_id);
+        let tgt_status_collection = cic.status_collections.get(cic.active_player_id.opposite());
         commands.push((*ctx, Command::SwitchCharacter(ci)));
-        commands.append(&mut player.cast_skill_cmds(status_collection, ctx, SkillId::StellarRestoration));
+        commands.append(&mut player.cast_skill_cmds(
+            status_collection,
+            tgt_status_collection,
+            ctx,
+            SkillId::StellarRestoration,
+        ));
     }
 }
@@ -20,13 +20,17 @@ pub mod dendro_core {
 
     pub const S: Status = Status::new_usages("Dendro Core", StatusAttachMode::Team, 1, None);
 
+    // Created by Bloom: +2 Pyro/Electro DMG dealt to the opponent's active character.
     decl_status_impl_type!(DendroCore, I);
     impl StatusImpl for DendroCore {
         fn responds_to(&self) -> EnumSet<RespondsTo> {
             enum_set![RespondsTo::OutgoingDMG]
         }
 
-        fn outgoing_dmg(&self, _: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+        fn outgoing_dmg(&self, e: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if !e.dmg.target_is_active {
+                return None;
+            }
             let DealDMGType::Elemental(Element::Pyro | Element::Electro) = dmg.dmg_type else {
                 return None;
             };
@@ -41,13 +45,18 @@ pub mod catalyzing_field {
 
     pub const S: Status = Status::new_usages("Catalyzing Field", StatusAttachMode::Team, 2, None);
 
+    // Created by Quicken: +1 Dendro/Electro DMG dealt to the opponent's active character.
+    // Consuming a usage with Electro DMG is Aggravate, and with Dendro DMG is Spread.
     decl_status_impl_type!(CatalyzingField, I);
     impl StatusImpl for CatalyzingField {
         fn responds_to(&self) -> EnumSet<RespondsTo> {
             enum_set![RespondsTo::OutgoingDMG]
         }
 
-        fn outgoing_dmg(&self, _: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+        fn outgoing_dmg(&self, e: &StatusImplContext<DMGInfo>, dmg: &mut DealDMG) -> Option<AppliedEffectResult> {
+            if !e.dmg.target_is_active {
+                return None;
+            }
             let DealDMGType::Elemental(Element::Dendro | Element::Electro) = dmg.dmg_type else {
                 return None;
             };
@@ -14,7 +14,9 @@ pub const C: Card = Card {
 
 pub const S: Status = Status::new_usages(NAME, StatusAttachMode::Support, 2, None);
 
-// TODO implement once per round per name (not per instance)
+/// Once per round per name, shared by all instances of Liu Su.
+const ROUND_USAGE_KEY: RoundUsageKey = RoundUsageKey::Support(SupportId::LiuSu);
+
 decl_support_impl_type!(LiuSu, I);
 impl StatusImpl for LiuSu {
     fn responds_to(&self) -> EnumSet<RespondsTo> {
@@ -27,12 +29,16 @@ impl StatusImpl for LiuSu {
 
     fn trigger_event(&self, e: &mut TriggerEventContext) -> Option<AppliedEffectResult> {
         let EventId::Switched = e.event_id else { return None };
+        if e.is_used_this_round(ROUND_USAGE_KEY) {
+            return None;
+        }
         let Some(dst_char_idx) = e.c.ctx.src.switch_dst_char_idx() else {
             return None;
         };
         let char_state = &e.c.src_player_state.char_states[dst_char_idx];
         if char_state.energy() < char_state.char_id.char_card().max_energy {
             e.add_cmd(Command::AddEnergy(1, CmdCharIdx::Active));
+            e.mark_used_this_round(ROUND_USAGE_KEY);
             Some(AppliedEffectResult::ConsumeUsage)
         } else {
             None
@@ -111,10 +111,7 @@ impl StatusImpl for CardCostReductionSupport {
     }
 
     fn update_cost(&self, e: &StatusImplContext, cost: &mut Cost, cost_type: CostType) -> Option<AppliedEffectResult> {
-        let CostType::Card(card_id) = cost_type else {
-            return None;
-        };
-
+        let card_id = cost_type.card_id()?;
         if !e.eff_state.can_use_once_per_round() {
             return None;
         }
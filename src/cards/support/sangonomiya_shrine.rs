@@ -12,4 +12,4 @@ pub const C: Card = Card {
 
 pub const S: Status = Status::new_usages(NAME, StatusAttachMode::Support, 2, None);
 
-pub const I: EndPhaseCommands = EndPhaseCommands(list8![Command::HealAll(1)]);
+pub const I: EndPhaseCommands = EndPhaseCommands(list8![Command::Heal(1, CmdCharIdx::All)]);
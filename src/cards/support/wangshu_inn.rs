@@ -26,17 +26,16 @@ impl StatusImpl for WangshuInn {
 
     fn trigger_event(&self, e: &mut TriggerEventContext) -> Option<AppliedEffectResult> {
         let EventId::EndPhase = e.event_id else { return None };
-        // TODO heal most injured character
-        let Some(char_idx) =
-            e.c.src_player_state
-                .char_states
-                .enumerate_valid()
-                .min_by_key(|(_, c)| c.hp())
-                .map(|(i, _)| i)
-        else {
+        let player = &e.c.src_player_state;
+        let sel = CmdCharIdx::MostInjuredStandby;
+        if player
+            .char_states
+            .select_char_idx(player.active_char_idx, None, sel)
+            .is_none()
+        {
             return Some(AppliedEffectResult::NoChange);
-        };
-        e.add_cmd(Command::Heal(2, char_idx.into()));
+        }
+        e.add_cmd(Command::Heal(2, sel));
         Some(AppliedEffectResult::ConsumeUsage)
     }
 }
@@ -0,0 +1,198 @@
+use enum_map::Enum;
+use rand::Rng;
+
+use crate::cards::ids::*;
+use crate::std_subset::Vec;
+use crate::types::card_defs::CardType;
+
+use super::{DeckVec, Decklist};
+
+pub const CHARACTER_COUNT: usize = 3;
+pub const DECK_SIZE: usize = 30;
+pub const MAX_COPIES: usize = 2;
+
+/// Cards that only exist during a game and cannot be included in a deck:
+///  - `BlankCard` stands in for hidden cards.
+///  - `LightningStiletto` is created by Keqing's Stellar Restoration.
+pub const NON_DECKBUILDABLE_CARDS: [CardId; 2] = [CardId::BlankCard, CardId::LightningStiletto];
+
+/// Reason for a decklist to be illegal for deckbuilding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(thiserror::Error))]
+pub enum DeckError {
+    #[cfg_attr(feature = "std", error("expected 3 characters, found {0}"))]
+    CharacterCount(usize),
+    #[cfg_attr(feature = "std", error("duplicate character: {0:?}"))]
+    DuplicateCharacter(CharId),
+    #[cfg_attr(feature = "std", error("expected 30 cards, found {0}"))]
+    CardCount(usize),
+    #[cfg_attr(feature = "std", error("too many copies of {0:?}"))]
+    TooManyCopies(CardId),
+    #[cfg_attr(feature = "std", error("{0:?} requirements are not met by the characters"))]
+    UnmetRequirement(CardId),
+    #[cfg_attr(feature = "std", error("{0:?} cannot be included in a deck"))]
+    NotDeckbuildable(CardId),
+}
+
+/// Whether the card can be included in a deck at all.
+#[inline]
+pub fn is_deckbuildable(card_id: CardId) -> bool {
+    !NON_DECKBUILDABLE_CARDS.contains(&card_id)
+}
+
+/// Whether the card is deckbuildable and the characters meet its deckbuilding requirements:
+///  - Talent cards require their character.
+///  - Elemental Resonance cards require 2 characters of their element.
+pub fn card_allowed_for(characters: &[CharId], card_id: CardId) -> bool {
+    if !is_deckbuildable(card_id) {
+        return false;
+    }
+    match card_id.card().card_type {
+        CardType::Talent(char_id) => characters.contains(&char_id),
+        CardType::ElementalResonance(elem) => characters.iter().filter(|c| c.char_card().elem == elem).count() >= 2,
+        _ => true,
+    }
+}
+
+/// All cards that can be included in a deck for the characters.
+pub fn allowed_cards(characters: &[CharId]) -> impl Iterator<Item = CardId> + '_ {
+    (0..CardId::LENGTH)
+        .map(CardId::from_usize)
+        .filter(move |&card_id| card_allowed_for(characters, card_id))
+}
+
+/// Fill the cards up to `DECK_SIZE` with random cards allowed for the characters,
+/// without exceeding `MAX_COPIES`.
+pub fn fill_random_cards<R: Rng>(rand: &mut R, characters: &[CharId], cards: &mut DeckVec<CardId>) {
+    let allowed: Vec<CardId> = allowed_cards(characters).collect();
+    while cards.len() < DECK_SIZE {
+        let available: Vec<CardId> = allowed
+            .iter()
+            .copied()
+            .filter(|&card_id| cards.iter().filter(|&&c| c == card_id).count() < MAX_COPIES)
+            .collect();
+        if available.is_empty() {
+            break;
+        }
+        cards.push(available[rand.gen_range(0..available.len())]);
+    }
+}
+
+impl Decklist {
+    /// Random legal decklist for the characters.
+    pub fn random_for_characters<R: Rng>(rand: &mut R, characters: &[CharId]) -> Self {
+        let mut cards = Default::default();
+        fill_random_cards(rand, characters, &mut cards);
+        Self::new(characters.into(), cards)
+    }
+
+    /// Check the deckbuilding rules: 3 distinct characters, 30 cards, at most 2 copies of each card,
+    /// and the requirements of Talent and Elemental Resonance cards.
+    pub fn validate(&self) -> Result<(), DeckError> {
+        if self.characters.len() != CHARACTER_COUNT {
+            return Err(DeckError::CharacterCount(self.characters.len()));
+        }
+        for (i, char_id) in self.characters.iter().enumerate() {
+            if self.characters[..i].contains(char_id) {
+                return Err(DeckError::DuplicateCharacter(*char_id));
+            }
+        }
+        if self.cards.len() != DECK_SIZE {
+            return Err(DeckError::CardCount(self.cards.len()));
+        }
+        for &card_id in &self.cards {
+            if self.cards.iter().filter(|&&c| c == card_id).count() > MAX_COPIES {
+                return Err(DeckError::TooManyCopies(card_id));
+            }
+            if !is_deckbuildable(card_id) {
+                return Err(DeckError::NotDeckbuildable(card_id));
+            }
+            if !card_allowed_for(&self.characters, card_id) {
+                return Err(DeckError::UnmetRequirement(card_id));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deck::sample_deck;
+    use rand::{rngs::SmallRng, SeedableRng};
+    use smallvec::smallvec;
+
+    #[test]
+    fn test_talent_requires_character() {
+        assert!(card_allowed_for(&[CharId::Ganyu], CardId::UndividedHeart));
+        assert!(!card_allowed_for(&[CharId::Xingqiu], CardId::UndividedHeart));
+    }
+
+    #[test]
+    fn test_resonance_requires_two_characters() {
+        let chars = [CharId::Ganyu, CharId::KamisatoAyaka, CharId::Xingqiu];
+        assert!(card_allowed_for(&chars, CardId::ElementalResonanceWovenIce));
+        assert!(!card_allowed_for(&chars, CardId::ElementalResonanceWovenWaters));
+    }
+
+    #[test]
+    fn test_non_deckbuildable_cards_are_not_allowed() {
+        let chars = [CharId::Keqing, CharId::Fischl, CharId::Ganyu];
+        assert!(!card_allowed_for(&chars, CardId::BlankCard));
+        assert!(!card_allowed_for(&chars, CardId::LightningStiletto));
+        assert!(allowed_cards(&chars).all(is_deckbuildable));
+        assert!(allowed_cards(&chars).any(|c| c == CardId::Paimon));
+    }
+
+    #[test]
+    fn test_validate() {
+        let chars = smallvec![CharId::Ganyu, CharId::KamisatoAyaka, CharId::Xingqiu];
+        let mut cards = sample_deck();
+        cards.retain(|c| card_allowed_for(&chars, *c));
+        let mut rand = SmallRng::seed_from_u64(1);
+        fill_random_cards(&mut rand, &chars, &mut cards);
+        let decklist = Decklist::new(chars.clone(), cards);
+        assert_eq!(Ok(()), decklist.validate());
+
+        let mut d = decklist.clone();
+        d.cards.pop();
+        assert_eq!(Err(DeckError::CardCount(29)), d.validate());
+
+        let mut d = decklist.clone();
+        d.characters[1] = CharId::Ganyu;
+        assert_eq!(Err(DeckError::DuplicateCharacter(CharId::Ganyu)), d.validate());
+
+        let mut d = decklist.clone();
+        d.cards[0] = CardId::Paimon;
+        d.cards[1] = CardId::Paimon;
+        d.cards[2] = CardId::Paimon;
+        assert_eq!(Err(DeckError::TooManyCopies(CardId::Paimon)), d.validate());
+
+        let mut d = decklist.clone();
+        d.cards[0] = CardId::BlankCard;
+        assert_eq!(Err(DeckError::NotDeckbuildable(CardId::BlankCard)), d.validate());
+
+        let mut d = decklist;
+        d.cards[0] = CardId::ElementalResonanceWovenFlames;
+        assert_eq!(
+            Err(DeckError::UnmetRequirement(CardId::ElementalResonanceWovenFlames)),
+            d.validate()
+        );
+    }
+
+    #[test]
+    fn test_random_for_characters_is_legal() {
+        let mut rand = SmallRng::seed_from_u64(100);
+        for _ in 0..50 {
+            let mut chars: smallvec::SmallVec<[CharId; 4]> = smallvec![];
+            while chars.len() < CHARACTER_COUNT {
+                let char_id = CharId::from_usize(rand.gen_range(0..CharId::LENGTH));
+                if !chars.contains(&char_id) {
+                    chars.push(char_id);
+                }
+            }
+            let decklist = Decklist::random_for_characters(&mut rand, &chars);
+            assert_eq!(Ok(()), decklist.validate());
+        }
+    }
+}
@@ -9,6 +9,9 @@ use crate::cards::ids::*;
 
 type DeckVec<T> = SmallVec<[T; 32]>;
 
+mod legality;
+pub use legality::*;
+
 mod parser;
 
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
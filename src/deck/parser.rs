@@ -47,6 +47,13 @@ impl Decklist {
 
         Decklist { characters, cards }
     }
+
+    /// Inverse of `from_lines`: character names, a blank line, then card names.
+    pub fn to_lines(&self) -> Vec<String> {
+        let characters = self.characters.iter().map(|c| c.char_card().name.into());
+        let cards = self.cards.iter().map(|c| c.card().name.into());
+        characters.chain([String::new()]).chain(cards).collect()
+    }
 }
 
 #[cfg(test)]
@@ -86,4 +93,13 @@ mod tests {
             Decklist::from_lines(lines)
         )
     }
+
+    #[test]
+    fn test_to_lines_round_trip() {
+        let decklist = Decklist::new(
+            smallvec![CharId::Yoimiya, CharId::KamisatoAyaka, CharId::Diona],
+            sample_deck(),
+        );
+        assert_eq!(decklist, Decklist::from_lines(decklist.to_lines()));
+    }
 }
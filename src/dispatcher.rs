@@ -229,7 +229,7 @@ impl<P: GameStateParams> GameState<P> {
             .expect("can_play_card: must have active player");
         if !self.ignore_costs {
             let status_collection = self.status_collections.get(active_player_id);
-            if !player.can_pay_dice_cost(status_collection, &card.cost, CostType::Card(card_id)) {
+            if !player.can_pay_dice_cost(status_collection, &card.cost, CostType::for_card(card_id, selection)) {
                 return false;
             }
 
@@ -286,7 +286,7 @@ impl<P: GameStateParams> GameState<P> {
             },
             self.cmd_tgt(active_player_id),
         );
-        self.pay_cost(&card.cost, CostType::Card(card_id))?;
+        self.pay_cost(&card.cost, CostType::for_card(card_id, selection))?;
         let cic = CardImplContext {
             players: &self.players,
             status_collections: &self.status_collections,
@@ -397,7 +397,9 @@ impl<P: GameStateParams> GameState<P> {
                 let player = self.player(player_id);
                 let mut is_fast_action = true;
                 let Some((mut cost, cost_type)) = (match action {
-                    PlayerAction::PlayCard(card_id, _) => Some((card_id.card().cost, CostType::Card(card_id))),
+                    PlayerAction::PlayCard(card_id, selection) => {
+                        Some((card_id.card().cost, CostType::for_card(card_id, selection)))
+                    }
                     PlayerAction::CastSkill(skill_id) => {
                         is_fast_action = false;
                         Some((skill_id.skill().cost, CostType::Skill(skill_id)))
@@ -666,7 +668,11 @@ impl<P: GameStateParams> GameState<P> {
         first_active_player: PlayerId,
     ) -> Result<DispatchResult, DispatchError> {
         match input {
-            Input::NondetResult(NondetResult::ProvideCards(ByPlayer(cards1, cards2))) => {
+            Input::NondetResult(NondetResult::ProvideCards(cards)) => {
+                if let Some(req) = self.nondet_request() {
+                    self.log_deck_empty(req, &cards);
+                }
+                let ByPlayer(cards1, cards2) = cards;
                 self.add_cards_to_hand(PlayerId::PlayerFirst, &cards1);
                 self.add_cards_to_hand(PlayerId::PlayerSecond, &cards2);
                 if self.round_number == 1 {
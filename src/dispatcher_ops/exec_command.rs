@@ -7,7 +7,13 @@ use crate::{
 
 use smallvec::{smallvec, SmallVec};
 
-use crate::{chc, cmd_list, dispatcher::cmd_trigger_event, phc, reaction::find_reaction, view};
+use crate::{
+    chc, cmd_list,
+    dispatcher::{cmd_trigger_event, cmd_trigger_event_src},
+    phc,
+    reaction::find_reaction,
+    view,
+};
 
 impl<P: GameStateParams> GameState<P> {
     /// Attempt to pay the cost. Succeeds without cost payment if `ignore_costs` is true.
@@ -89,6 +95,34 @@ impl<P: GameStateParams> GameState<P> {
         res
     }
 
+    /// Consumes the first status that prevents the character from being defeated.
+    /// Returns the HP the character is revived with.
+    fn try_prevent_defeat(&mut self, player_id: PlayerId, char_idx: u8) -> Option<u8> {
+        if !self
+            .status_collections
+            .get(player_id)
+            .responds_to(RespondsTo::PreventDefeat)
+        {
+            return None;
+        }
+
+        let mut revive_hp = 0;
+        self.status_collections.mutate_hashed(phc!(self, player_id), |sc| {
+            sc.consume_statuses_first(
+                CharIdxSelector::One(char_idx),
+                |si| si.responds_to().contains(RespondsTo::PreventDefeat),
+                |es, _sk, si| si.prevent_defeat(es, &mut revive_hp),
+            )
+        });
+        if revive_hp == 0 {
+            return None;
+        }
+
+        let char_state = &mut self.players.get_mut(player_id).char_states[char_idx];
+        char_state.set_hp_hashed(chc!(self, player_id, char_idx), revive_hp);
+        Some(revive_hp)
+    }
+
     fn end_of_turn(&mut self) -> ExecResult {
         if let Phase::EndPhase {
             next_first_active_player: first_active_player,
@@ -113,6 +147,7 @@ impl<P: GameStateParams> GameState<P> {
                 });
                 let player = self.players.get_mut(player_id);
                 player.clear_flags_for_end_of_turn(phc!(self, player_id));
+                player.clear_used_this_round(phc!(self, player_id));
                 for (char_idx, char_state) in player.char_states.iter_all_mut().enumerate() {
                     let next_flags = char_state.flags & CharFlag::RETAIN;
                     char_state.set_flags_hashed(chc!(self, player_id, char_idx as u8), next_flags);
@@ -317,6 +352,7 @@ impl<P: GameStateParams> GameState<P> {
                     target_hp: tgt_char.hp(),
                     target_affected_by_riptide: tgt_status_collection
                         .has_character_status(tgt_char_idx, StatusId::Riptide),
+                    target_is_active: tgt_char_idx == tgt_player.active_char_idx,
                 };
                 (tgt_applied, log_tgt, dmg_info)
             };
@@ -491,8 +527,8 @@ impl<P: GameStateParams> GameState<P> {
                             *ctx,
                             Command::TriggerXEvent(XEvent::DMG(XEventDMG {
                                 src_player_id,
-                                tgt_char_idx,
-                                dmg_value: dmg.dmg,
+                                tgt_char_idx: j,
+                                dmg_value: pdmg.dmg,
                                 dmg_type: DealDMGType::Piercing,
                                 dmg_info,
                                 reaction: None,
@@ -550,13 +586,32 @@ impl<P: GameStateParams> GameState<P> {
         &mut self,
         player_id: PlayerId,
         ctx: &CommandContext,
-        defeated: CharIdxSet,
+        mut defeated: CharIdxSet,
         addl_cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
         if defeated.is_empty() {
             return;
         }
 
+        for char_idx in defeated {
+            let Some(revive_hp) = self.try_prevent_defeat(player_id, char_idx.into()) else {
+                continue;
+            };
+            defeated.remove(char_idx);
+            let char_idx: u8 = char_idx.into();
+            for (_, cmd) in addl_cmds.iter_mut() {
+                if let Command::TriggerXEvent(XEvent::DMG(dmg)) = cmd {
+                    if dmg.tgt_char_idx == char_idx {
+                        dmg.defeated = false;
+                    }
+                }
+            }
+
+            let char_id = self.players.get(player_id).char_states[char_idx].char_id;
+            self.log
+                .log(Event::CharacterRevived(player_id, (char_idx, char_id), revive_hp));
+        }
+
         let player = self.players.get_mut(player_id);
         let mut shifts_to_next_active: SmallVec<[(StatusId, AppliedEffectState); 2]> = Default::default();
 
@@ -590,12 +645,15 @@ impl<P: GameStateParams> GameState<P> {
     }
 
     fn take_dmg(&mut self, ctx: &CommandContext, dmg: DealDMG) -> ExecResult {
-        // TODO refactor TakeDMG targeting
         let take_dmg_player_id = ctx.src_player_id;
-        let char_idx = ctx.src.char_idx().unwrap_or_else(|| {
-            let player = self.player(take_dmg_player_id);
-            player.active_char_idx
-        });
+        // The source character if alive, otherwise the active character
+        let sel = ctx.src.char_idx().map_or(CmdCharIdx::Active, CmdCharIdx::Index);
+        let Some(char_idx) = self
+            .resolve_cmd_char_idx(ctx, sel)
+            .or_else(|| self.resolve_cmd_char_idx(ctx, CmdCharIdx::Active))
+        else {
+            return ExecResult::Success;
+        };
         let ctx = CommandContext::new(
             take_dmg_player_id.opposite(),
             ctx.src,
@@ -640,29 +698,21 @@ impl<P: GameStateParams> GameState<P> {
     }
 
     fn add_energy(&mut self, ctx: &CommandContext, energy: u8, char_idx: CmdCharIdx) -> ExecResult {
-        let char_idx = self.resolve_cmd_char_idx(ctx, char_idx);
+        let char_idxs = self.resolve_cmd_char_idxs(ctx, char_idx);
         let p = self.players.get_mut(ctx.src_player_id);
-        if let Some(active_char) = p.try_get_character_mut(char_idx) {
-            active_char.add_energy_hashed(chc!(self, ctx.src_player_id, char_idx), energy);
-        }
-        ExecResult::Success
-    }
-
-    fn add_energy_to_non_active_characters(&mut self, ctx: &CommandContext, energy: u8) -> ExecResult {
-        let player = self.players.get_mut(ctx.src_player_id);
-        let active_char_idx = player.active_char_idx;
-        for (i, char_state) in player.char_states.enumerate_valid_mut() {
-            let char_idx = i;
-            if char_idx == active_char_idx {
-                continue;
+        for char_idx in char_idxs {
+            let char_idx: u8 = char_idx.into();
+            if let Some(char_state) = p.try_get_character_mut(char_idx) {
+                char_state.add_energy_hashed(chc!(self, ctx.src_player_id, char_idx), energy);
             }
-            char_state.add_energy_hashed(chc!(self, ctx.src_player_id, char_idx), energy);
         }
         ExecResult::Success
     }
 
     fn set_energy_for_active_character(&mut self, ctx: &CommandContext, energy: u8) -> ExecResult {
-        let char_idx = self.resolve_cmd_char_idx(ctx, CmdCharIdx::Active);
+        let Some(char_idx) = self.resolve_cmd_char_idx(ctx, CmdCharIdx::Active) else {
+            return ExecResult::Success;
+        };
         let p = self.players.get_mut(ctx.src_player_id);
         if let Some(active_char) = p.try_get_character_mut(char_idx) {
             active_char.set_energy_hashed(chc!(self, ctx.src_player_id, char_idx), energy);
@@ -671,7 +721,9 @@ impl<P: GameStateParams> GameState<P> {
     }
 
     fn shift_energy_to_active_character(&mut self, ctx: &CommandContext) -> ExecResult {
-        let char_idx = self.resolve_cmd_char_idx(ctx, CmdCharIdx::Active);
+        let Some(char_idx) = self.resolve_cmd_char_idx(ctx, CmdCharIdx::Active) else {
+            return ExecResult::Success;
+        };
         let player = self.players.get_mut(ctx.src_player_id);
         let mut total = 0;
         for (i, char_state) in player.char_states.enumerate_valid_mut() {
@@ -731,34 +783,16 @@ impl<P: GameStateParams> GameState<P> {
     }
 
     fn heal(&mut self, ctx: &CommandContext, hp: u8, char_idx: CmdCharIdx) -> ExecResult {
-        let char_idx = self.resolve_cmd_char_idx(ctx, char_idx);
-        let Some(active_char) = self.players.get_mut(ctx.src_player_id).try_get_character_mut(char_idx) else {
-            return ExecResult::Success;
-        };
-        active_char.heal_hashed(chc!(self, ctx.src_player_id, char_idx), hp);
-        self.log
-            .log(Event::Heal(ctx.src_player_id, (char_idx, active_char.char_id), hp));
-        ExecResult::Success
-    }
-
-    fn heal_taken_most_dmg(&mut self, ctx: &CommandContext, hp: u8) -> ExecResult {
-        let char_states = &self.players.get(ctx.src_player_id).char_states;
-        let Some((char_idx, _)) = char_states.taken_most_dmg() else {
-            return ExecResult::Success;
-        };
-        self.heal(
-            &ctx.with_src(CommandSource::Character { char_idx }),
-            hp,
-            char_idx.into(),
-        )
-    }
-
-    fn heal_all(&mut self, ctx: &CommandContext, hp: u8) -> ExecResult {
+        let char_idxs = self.resolve_cmd_char_idxs(ctx, char_idx);
         let p = self.players.get_mut(ctx.src_player_id);
-        for (char_idx, character) in p.char_states.enumerate_valid_mut() {
-            character.heal_hashed(chc!(self, ctx.src_player_id, char_idx), hp);
+        for char_idx in char_idxs {
+            let char_idx: u8 = char_idx.into();
+            let Some(char_state) = p.try_get_character_mut(char_idx) else {
+                continue;
+            };
+            char_state.heal_hashed(chc!(self, ctx.src_player_id, char_idx), hp);
             self.log
-                .log(Event::Heal(ctx.src_player_id, (char_idx, character.char_id), hp));
+                .log(Event::Heal(ctx.src_player_id, (char_idx, char_state.char_id), hp));
         }
         ExecResult::Success
     }
@@ -771,6 +805,14 @@ impl<P: GameStateParams> GameState<P> {
         ExecResult::Success
     }
 
+    fn mark_used_this_round(&mut self, ctx: &CommandContext, key: RoundUsageKey) -> ExecResult {
+        let player_id = ctx.src_player_id;
+        self.players
+            .get_mut(player_id)
+            .mark_used_this_round(phc!(self, player_id), key);
+        ExecResult::Success
+    }
+
     fn add_dice(&mut self, ctx: &CommandContext, dice: &DiceCounter) -> ExecResult {
         let player_id = ctx.src_player_id;
         self.players.get_mut(player_id).add_dice(phc!(self, player_id), dice);
@@ -788,16 +830,24 @@ impl<P: GameStateParams> GameState<P> {
     pub(crate) fn add_cards_to_hand(&mut self, player_id: PlayerId, cards: &List8<CardId>) -> ExecResult {
         let player = self.players.get_mut(player_id);
         for &card_id in cards.iter() {
-            player.add_card_to_hand(phc!(self, player_id), card_id);
+            // Cards exceeding the hand size limit are burned
+            if !player.add_card_to_hand(phc!(self, player_id), card_id) {
+                self.log.log(Event::BurnCard(player_id, card_id));
+            }
         }
 
         ExecResult::Success
     }
 
+    /// Draws in requests of at most `NondetRequest::MAX_DRAW_COUNT` cards.
+    /// The remaining cards are drawn by a follow-up `DrawCards` command.
     fn draw_cards(&mut self, ctx: &CommandContext, count: u8, card_type: Option<CardType>) -> ExecResult {
+        let req_count = min(count, NondetRequest::MAX_DRAW_COUNT);
+        let remaining =
+            (count > req_count).then(|| cmd_list![(*ctx, Command::DrawCards(count - req_count, card_type))]);
         ExecResult::Suspend(
-            SuspendedState::NondetRequest(NondetRequest::DrawCardsOfType(ctx.src_player_id, count, card_type)),
-            None,
+            SuspendedState::NondetRequest(NondetRequest::DrawCardsOfType(ctx.src_player_id, req_count, card_type)),
+            remaining,
         )
     }
 
@@ -933,19 +983,15 @@ impl<P: GameStateParams> GameState<P> {
         status_id: StatusId,
         char_idx: CmdCharIdx,
     ) -> ExecResult {
-        let char_idx = self.resolve_cmd_char_idx(ctx, char_idx);
-        let player = self.players.get_mut(ctx.src_player_id);
-        if !player.is_valid_char_idx(char_idx) {
-            return ExecResult::Success;
-        }
-
         let status = status_id.status();
         if status.attach_mode != StatusAttachMode::Character {
             panic!("apply_character_status: wrong StatusAttachMode");
         }
-        self.apply_or_refresh_status(ctx.src_player_id, StatusKey::Character(char_idx, status_id), status);
 
-        {
+        for char_idx in self.resolve_cmd_char_idxs(ctx, char_idx) {
+            let char_idx: u8 = char_idx.into();
+            self.apply_or_refresh_status(ctx.src_player_id, StatusKey::Character(char_idx, status_id), status);
+
             let player = self.players.get(ctx.src_player_id);
             self.log.log(Event::ApplyCharStatus(
                 ctx.src_player_id,
@@ -966,11 +1012,10 @@ impl<P: GameStateParams> GameState<P> {
         status_id: StatusId,
         char_idx: CmdCharIdx,
     ) -> ExecResult {
-        let char_idx = self.resolve_cmd_char_idx(ctx, char_idx);
-        let player = self.players.get_mut(ctx.src_player_id);
-        if !player.is_valid_char_idx(char_idx) {
+        let Some(char_idx) = self.resolve_cmd_char_idx(ctx, char_idx) else {
             return ExecResult::Success;
-        }
+        };
+        let player = self.players.get_mut(ctx.src_player_id);
 
         let status = status_id.status();
         self.status_collections
@@ -997,11 +1042,10 @@ impl<P: GameStateParams> GameState<P> {
         status_id: Option<StatusId>,
         char_idx: CmdCharIdx,
     ) -> ExecResult {
-        let char_idx = self.resolve_cmd_char_idx(ctx, char_idx);
-        let player = self.players.get_mut(ctx.src_player_id);
-        if !player.is_valid_char_idx(char_idx) {
+        let Some(char_idx) = self.resolve_cmd_char_idx(ctx, char_idx) else {
             return ExecResult::Success;
-        }
+        };
+        let player = self.players.get_mut(ctx.src_player_id);
         let char_state = &mut player.char_states[char_idx];
         let flags = char_state.flags | CharFlag::TalentEquipped;
         if let Some(status_id) = status_id {
@@ -1024,7 +1068,18 @@ impl<P: GameStateParams> GameState<P> {
             EquipSlot::Talent,
             status_id,
         ));
-        ExecResult::Success
+        let src = match ctx.src {
+            CommandSource::Card { card_id, .. } => CommandSource::Card {
+                card_id,
+                target: Some(CardSelection::OwnCharacter(char_idx)),
+            },
+            src => src,
+        };
+        ExecResult::AdditionalCmds(cmd_list![cmd_trigger_event_src(
+            ctx.src_player_id,
+            EventId::TalentEquipped,
+            src
+        )])
     }
 
     fn apply_character_status_with_state_to_active(
@@ -1068,6 +1123,13 @@ impl<P: GameStateParams> GameState<P> {
     }
 
     fn summon(&mut self, ctx: &CommandContext, summon_id: SummonId) -> ExecResult {
+        if !self
+            .status_collections
+            .get(ctx.src_player_id)
+            .can_place_summon(summon_id)
+        {
+            return ExecResult::Success;
+        }
         self.log.log(Event::Summon(ctx.src_player_id, summon_id));
         let status = summon_id.status();
         self.apply_or_refresh_status(ctx.src_player_id, StatusKey::Summon(summon_id), status);
@@ -1115,7 +1177,9 @@ impl<P: GameStateParams> GameState<P> {
             )])
         } else {
             if !matches!(ctx.src, CommandSource::Card { .. }) {
-                active_player.add_card_to_hand((&mut h, player_id), CardId::LightningStiletto);
+                if !active_player.add_card_to_hand((&mut h, player_id), CardId::LightningStiletto) {
+                    self.log.log(Event::BurnCard(player_id, CardId::LightningStiletto));
+                }
                 if active_player.is_tactical() {
                     active_player.pseudo_elemental_tuning((&mut h, player_id));
                 }
@@ -1136,20 +1200,29 @@ impl<P: GameStateParams> GameState<P> {
             let flags = char.flags | char.skill_flags(skill_id);
             char.set_flags_hashed(chc, flags);
         }
-        let cmds = player.cast_skill_cmds(self.status_collections.get(player_id), ctx, skill_id);
+        let cmds = player.cast_skill_cmds(
+            self.status_collections.get(player_id),
+            self.status_collections.get(player_id.opposite()),
+            ctx,
+            skill_id,
+        );
         ExecResult::AdditionalCmds(cmds)
     }
 
     #[inline]
-    fn resolve_cmd_char_idx(&self, ctx: &CommandContext, char_idx: CmdCharIdx) -> u8 {
-        match char_idx {
-            CmdCharIdx::Active => self.players.get(ctx.src_player_id).active_char_idx,
-            CmdCharIdx::CardSelected => match ctx.src {
-                CommandSource::Card { target: Some(CardSelection::OwnCharacter(char_idx)), .. } => char_idx,
-                _ => panic!("resolve_cmd_char_idx: CmdCharIdx::CardSelected must be used on card effects with own character selections. ctx.src={:?}", ctx.src),
-            }
-            CmdCharIdx::Index(char_idx) => char_idx,
-        }
+    fn resolve_cmd_char_idxs(&self, ctx: &CommandContext, char_idx: CmdCharIdx) -> CharIdxSet {
+        let player = self.players.get(ctx.src_player_id);
+        player
+            .char_states
+            .select_char_idxs(player.active_char_idx, ctx.src.card_selected_char_idx(), char_idx)
+    }
+
+    #[inline]
+    fn resolve_cmd_char_idx(&self, ctx: &CommandContext, char_idx: CmdCharIdx) -> Option<u8> {
+        let player = self.players.get(ctx.src_player_id);
+        player
+            .char_states
+            .select_char_idx(player.active_char_idx, ctx.src.card_selected_char_idx(), char_idx)
     }
 
     fn exec(&mut self, ctx: &CommandContext, cmd: Command) -> ExecResult {
@@ -1166,11 +1239,8 @@ impl<P: GameStateParams> GameState<P> {
             Command::TakeDMGForAffectedBy(status_id, dmg) => self.take_dmg_for_affected_by(ctx, status_id, dmg),
             Command::InternalDealSwirlDMG(..) => panic!("Cannot execute InternalDealSwirlDMG command."),
             Command::Heal(hp, char_idx) => self.heal(ctx, hp, char_idx),
-            Command::HealTakenMostDMG(hp) => self.heal_taken_most_dmg(ctx, hp),
-            Command::HealAll(hp) => self.heal_all(ctx, hp),
             Command::AddEnergy(energy, char_idx) => self.add_energy(ctx, energy, char_idx),
             Command::AddEnergyWithoutMaximum(energy) => self.add_energy_without_maximum(ctx, energy),
-            Command::AddEnergyToNonActiveCharacters(energy) => self.add_energy_to_non_active_characters(ctx, energy),
             Command::SetEnergyForActiveCharacter(energy) => self.set_energy_for_active_character(ctx, energy),
             Command::ShiftEnergyToActiveCharacter => self.shift_energy_to_active_character(ctx),
             Command::IncreaseStatusUsages(key, usages) => self.increase_status_usages(ctx, key, usages),
@@ -1205,6 +1275,7 @@ impl<P: GameStateParams> GameState<P> {
             Command::HandOverPlayer => self.hand_over_player(),
             Command::EndOfTurn => self.end_of_turn(),
             Command::InternalStellarRestorationFromSkill => self.stellar_restoration_from_skill(ctx),
+            Command::MarkUsedThisRound(key) => self.mark_used_this_round(ctx, key),
         };
         self.post_death_check(res)
     }
@@ -16,6 +16,7 @@ macro_rules! view {
             // TODO will eventually be needed?
             // affected_by: $p.status_collection.affected_by_keys(),
             affected_by: Default::default(),
+            used_this_round: $p.used_this_round,
         }
     };
 }
@@ -154,7 +155,16 @@ impl PlayerState {
             .relative_switch_char_idx(self.active_char_idx, switch_type)
     }
 
-    // TODO can reduce cost for character talent cards
+    /// The character whose statuses can update the cost: the character equipping the Talent card
+    /// for Talent cards, and the active character otherwise.
+    #[inline]
+    fn cost_char_idx(&self, cost_type: CostType) -> u8 {
+        match cost_type {
+            CostType::Talent { char_idx, .. } => char_idx,
+            _ => self.active_char_idx,
+        }
+    }
+
     pub fn augment_cost(
         &mut self,
         status_collections: &mut ByPlayer<StatusCollection>,
@@ -167,7 +177,7 @@ impl PlayerState {
         }
 
         let view = &view!(self);
-        let char_idx = self.active_char_idx;
+        let char_idx = self.cost_char_idx(cost_type);
         status_collections.mutate_hashed(c, |sc| {
             let ctx = &CommandContext::EMPTY.with_src(cost_type.into_cmd_src(self.active_char_idx));
             let sicb = StatusImplContextBuilder::new(view, ctx, ());
@@ -184,7 +194,7 @@ impl PlayerState {
             return;
         }
 
-        let char_idx = self.active_char_idx;
+        let char_idx = self.cost_char_idx(cost_type);
         let view = &view!(self);
         let ctx = &CommandContext::EMPTY.with_src(cost_type.into_cmd_src(self.active_char_idx));
         let sicb = StatusImplContextBuilder::new(view, ctx, ());
@@ -266,6 +276,7 @@ impl PlayerState {
     pub fn cast_skill_cmds(
         &self,
         status_collection: &StatusCollection,
+        tgt_status_collection: &StatusCollection,
         ctx: &CommandContext,
         skill_id: SkillId,
     ) -> CommandList<(CommandContext, Command)> {
@@ -312,10 +323,12 @@ impl PlayerState {
                     } else {
                         Default::default()
                     };
-                    cmds.push((
-                        *ctx,
-                        Command::SummonRandom(SummonRandomSpec::new(summon_ids, existing_summon_ids, count)),
-                    ));
+                    let mut spec = SummonRandomSpec::new(summon_ids, existing_summon_ids, count);
+                    if prioritize_new {
+                        let free_slots = StatusCollection::MAX_SUMMONS.saturating_sub(status_collection.summon_count());
+                        spec = spec.with_free_slots(free_slots as u8);
+                    }
+                    cmds.push((*ctx, Command::SummonRandom(spec)));
                 }
             }
         }
@@ -327,7 +340,7 @@ impl PlayerState {
         let mut gains_energy = !skill.no_energy;
         src_player.update_gains_energy(status_collection, ctx, &mut gains_energy);
         if let Some(si) = skill.skill_impl {
-            si.commands(src_player, status_collection, ctx, &mut cmds);
+            si.commands(src_player, status_collection, tgt_status_collection, ctx, &mut cmds);
         }
 
         if gains_energy && skill.skill_type != SkillType::ElementalBurst {
@@ -355,8 +368,58 @@ impl CharStates {
             .find(|&j| self.is_valid_char_idx(j))
     }
 
-    pub fn taken_most_dmg(&self) -> Option<(u8, &CharState)> {
-        self.enumerate_valid().max_by_key(|(_, c)| c.total_dmg_taken())
+    /// Resolve a character selector to the set of alive characters it refers to.
+    /// `card_selected` is the own character chosen by the card being played, if any.
+    pub fn select_char_idxs(&self, active_char_idx: u8, card_selected: Option<u8>, sel: CmdCharIdx) -> CharIdxSet {
+        let single = |char_idx: Option<u8>| {
+            char_idx
+                .filter(|&i| self.is_valid_char_idx(i))
+                .and_then(|i| CharIdx::try_from(i).ok())
+                .map(CharIdxSet::only)
+                .unwrap_or_default()
+        };
+        let all = |include_active: bool| {
+            self.enumerate_valid()
+                .filter(|&(i, _)| include_active || i != active_char_idx)
+                .filter_map(|(i, _)| CharIdx::try_from(i).ok())
+                .collect::<CharIdxSet>()
+        };
+        let most_injured = |include_active: bool| {
+            self.enumerate_valid()
+                .filter(|&(i, c)| (include_active || i != active_char_idx) && !c.is_max_hp())
+                .min_by_key(|&(i, c)| (c.hp() as i16 - c.char_id.char_card().max_health as i16, i))
+                .map(|(i, _)| i)
+        };
+        let relative = |switch_type: RelativeCharIdx| {
+            self.relative_switch_char_idx(active_char_idx, switch_type)
+                .filter(|&i| i != active_char_idx)
+        };
+        match sel {
+            CmdCharIdx::Active => single(Some(active_char_idx)),
+            CmdCharIdx::CardSelected => {
+                let Some(char_idx) = card_selected else {
+                    panic!("select_char_idxs: CmdCharIdx::CardSelected must be used on card effects with own character selections.");
+                };
+                single(Some(char_idx))
+            }
+            CmdCharIdx::Index(char_idx) => single(Some(char_idx)),
+            CmdCharIdx::MostInjured => single(most_injured(true)),
+            CmdCharIdx::MostInjuredStandby => single(most_injured(false)),
+            CmdCharIdx::LowestHP => single(self.enumerate_valid().min_by_key(|&(i, c)| (c.hp(), i)).map(|(i, _)| i)),
+            CmdCharIdx::NextAlive => single(relative(RelativeCharIdx::Next)),
+            CmdCharIdx::PrevAlive => single(relative(RelativeCharIdx::Previous)),
+            CmdCharIdx::AllStandby => all(false),
+            CmdCharIdx::All => all(true),
+        }
+    }
+
+    /// Resolve a character selector that refers to at most one character.
+    #[inline]
+    pub fn select_char_idx(&self, active_char_idx: u8, card_selected: Option<u8>, sel: CmdCharIdx) -> Option<u8> {
+        self.select_char_idxs(active_char_idx, card_selected, sel)
+            .iter()
+            .next()
+            .map(u8::from)
     }
 }
 
@@ -371,6 +434,10 @@ impl CostType {
                 dst_char_idx: tgt_char_idx,
             },
             CostType::Card(card_id) => CommandSource::Card { card_id, target: None },
+            CostType::Talent { card_id, char_idx } => CommandSource::Card {
+                card_id,
+                target: Some(CardSelection::OwnCharacter(char_idx)),
+            },
             CostType::Skill(skill_id) => CommandSource::Skill {
                 char_idx: active_char_idx,
                 skill_id,
@@ -378,3 +445,62 @@ impl CostType {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn char_states() -> CharStates {
+        let mut char_states = CharStates::from_ids([CharId::Kaeya, CharId::Fischl, CharId::Yoimiya, CharId::Ganyu]);
+        char_states[0].set_hp(4);
+        char_states[1].set_hp(0);
+        char_states[2].set_hp(5);
+        char_states[3].set_hp(7);
+        char_states
+    }
+
+    fn select(char_states: &CharStates, active_char_idx: u8, sel: CmdCharIdx) -> Vec<u8> {
+        char_states
+            .select_char_idxs(active_char_idx, Some(1), sel)
+            .iter()
+            .map(u8::from)
+            .collect()
+    }
+
+    #[test]
+    fn selectors_skip_defeated_characters() {
+        let char_states = char_states();
+        assert_eq!(Vec::<u8>::new(), select(&char_states, 0, CmdCharIdx::Index(1)));
+        assert_eq!(Vec::<u8>::new(), select(&char_states, 0, CmdCharIdx::CardSelected));
+        assert_eq!(vec![2], select(&char_states, 0, CmdCharIdx::NextAlive));
+        assert_eq!(vec![0], select(&char_states, 2, CmdCharIdx::PrevAlive));
+        assert_eq!(vec![3], select(&char_states, 0, CmdCharIdx::PrevAlive));
+        assert_eq!(vec![0], select(&char_states, 3, CmdCharIdx::NextAlive));
+        assert_eq!(vec![2, 3], select(&char_states, 0, CmdCharIdx::AllStandby));
+        assert_eq!(vec![0, 2, 3], select(&char_states, 0, CmdCharIdx::All));
+    }
+
+    #[test]
+    fn selectors_by_hp() {
+        let mut char_states = char_states();
+        assert_eq!(vec![0], select(&char_states, 0, CmdCharIdx::LowestHP));
+        assert_eq!(vec![0], select(&char_states, 0, CmdCharIdx::MostInjured));
+        assert_eq!(vec![2], select(&char_states, 0, CmdCharIdx::MostInjuredStandby));
+        char_states[0].set_hp(10);
+        char_states[2].set_hp(10);
+        assert_eq!(vec![3], select(&char_states, 0, CmdCharIdx::MostInjured));
+        char_states[3].set_hp(10);
+        assert_eq!(Vec::<u8>::new(), select(&char_states, 0, CmdCharIdx::MostInjured));
+        assert_eq!(vec![0], select(&char_states, 0, CmdCharIdx::LowestHP));
+    }
+
+    #[test]
+    fn next_and_prev_alive_exclude_the_only_alive_active_character() {
+        let mut char_states = char_states();
+        char_states[2].set_hp(0);
+        char_states[3].set_hp(0);
+        assert_eq!(Vec::<u8>::new(), select(&char_states, 0, CmdCharIdx::NextAlive));
+        assert_eq!(Vec::<u8>::new(), select(&char_states, 0, CmdCharIdx::PrevAlive));
+        assert_eq!(vec![0], select(&char_states, 0, CmdCharIdx::Active));
+    }
+}
@@ -30,7 +30,7 @@ mod nondet;
 mod suspension;
 
 mod relative_char_idx;
-pub(crate) use relative_char_idx::{CharIdxSet, RelativeCharIdx};
+pub(crate) use relative_char_idx::{CharIdx, CharIdxSet, RelativeCharIdx};
 
 mod exec_command;
 
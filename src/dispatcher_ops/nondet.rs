@@ -1,8 +1,25 @@
+use crate::data_structures::List8;
 use crate::types::by_player::ByPlayer;
 
 use super::*;
 
 impl<P: GameStateParams> GameState<P> {
+    /// Logs `Event::DeckEmpty` for players provided fewer cards than requested by `req`,
+    /// which happens when the deck runs out of cards.
+    /// Draws of a specific card type are skipped, since the deck may still have cards of other types.
+    pub(crate) fn log_deck_empty(&mut self, req: NondetRequest, cards: &ByPlayer<List8<CardId>>) {
+        if let NondetRequest::DrawCardsOfType(_, _, Some(..)) = req {
+            return;
+        }
+        let Some(counts) = req.draw_counts() else { return };
+        for player_id in PlayerId::VALUES {
+            let missing = counts[player_id].saturating_sub(cards[player_id].len());
+            if missing > 0 {
+                self.log.log(Event::DeckEmpty(player_id, missing));
+            }
+        }
+    }
+
     pub(crate) fn nondet_result_to_commands(
         &self,
         res: NondetResult,
@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 use enumset::{EnumSet, EnumSetType};
 
-// TODO remove
+/// Character index, used as the element of `CharIdxSet` for characters resolved from `CmdCharIdx` selectors.
 #[derive(Debug, PartialOrd, Ord, EnumSetType)]
 #[enumset(repr = "u8")]
 pub enum CharIdx {
@@ -100,7 +100,7 @@ impl PlayerState {
     pub fn element_priority_for_cost_type(&self, cost_type: CostType) -> ElementPriority {
         match cost_type {
             CostType::Switching { dst_char_idx } => self.element_priority_switch(dst_char_idx),
-            CostType::Card(..) | CostType::Skill(..) => self.element_priority(),
+            CostType::Card(..) | CostType::Talent { .. } | CostType::Skill(..) => self.element_priority(),
         }
     }
 
@@ -135,6 +135,9 @@ impl AppliedEffectState {
 }
 
 impl StatusCollection {
+    /// Maximum number of summons a player can have at the same time.
+    pub const MAX_SUMMONS: usize = 4;
+
     #[inline]
     pub fn iter_entries(&self) -> crate::std_subset::slice::Iter<StatusEntry> {
         self.status_entries.iter()
@@ -254,6 +257,13 @@ impl StatusCollection {
         })
     }
 
+    /// Whether the summon can be placed: either it already exists and will be refreshed,
+    /// or there is a free slot in the summon zone.
+    #[inline]
+    pub fn can_place_summon(&self, summon_id: SummonId) -> bool {
+        self.has_summon(summon_id) || self.summon_count() < Self::MAX_SUMMONS
+    }
+
     pub fn find_equipment(&self, char_idx: u8, slot: EquipSlot) -> Option<&StatusEntry> {
         let f = StatusKeyFilter::Equipment(char_idx, slot);
         self.status_entries.iter().find(|e| f.matches(e.key))
@@ -334,6 +344,8 @@ impl StatusCollection {
         self.responds_to_events = e1;
     }
 
+    /// Refreshes the status if it already exists, otherwise adds it.
+    /// New summons are not added if the summon zone is full (see `MAX_SUMMONS`).
     pub(crate) fn apply_or_refresh_status(
         &mut self,
         path: StatusKey,
@@ -346,6 +358,11 @@ impl StatusCollection {
                 modifiers.modify(path, eff_state);
             }
         } else {
+            if let StatusKey::Summon(summon_id) = path {
+                if !self.can_place_summon(summon_id) {
+                    return;
+                }
+            }
             let mut eff_state = AppliedEffectState::new(status_spec);
             if let Some(modifiers) = modifiers {
                 modifiers.modify(path, &mut eff_state);
@@ -108,6 +108,9 @@ impl<P: GameStateParams> GameState<P> {
                 if !correct {
                     return Err(DispatchError::NondetResultInvalid);
                 }
+                if let NondetResult::ProvideCards(cards) = &res {
+                    self.log_deck_empty(req, cards);
+                }
                 self.nondet_result_to_commands(res, cmds);
                 Ok(())
             }
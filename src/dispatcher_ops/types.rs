@@ -9,6 +9,23 @@ pub enum NondetRequest {
     SummonRandom(SummonRandomSpec),
 }
 
+impl NondetRequest {
+    /// Maximum number of cards drawn per player by a single request.
+    pub const MAX_DRAW_COUNT: u8 = 8;
+
+    /// Number of cards requested to be drawn for each player.
+    pub fn draw_counts(&self) -> Option<ByPlayer<u8>> {
+        match *self {
+            NondetRequest::DrawCards(counts) => Some(counts),
+            NondetRequest::DrawCardsOfType(player_id, count, _) => Some(match player_id {
+                PlayerId::PlayerFirst => (count, 0).into(),
+                PlayerId::PlayerSecond => (0, count).into(),
+            }),
+            _ => None,
+        }
+    }
+}
+
 /// Indicates game state advancement succeeds.
 #[derive(Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -86,6 +86,24 @@ pub fn new_standard_game(
     GameStateWrapper::new(game_state, NondetProvider::new(state))
 }
 
+/// Same as `new_standard_game`, but with the given RNG streams for the card draws and dice rolls of each player.
+/// Swapping both the decklists and the player RNGs yields the same game with the sides swapped.
+pub fn new_standard_game_with_player_rngs(
+    decklists: ByPlayer<&Decklist>,
+    rng: SmallRng,
+    player_rngs: ByPlayer<SmallRng>,
+) -> GameStateWrapper<StandardNondetHandlerState> {
+    let ByPlayer(decklist1, decklist2) = decklists;
+    let game_state = {
+        GameStateInitializer::new(decklist1.characters.clone(), decklist2.characters.clone())
+            .start_at_beginning()
+            .build()
+    };
+    let state =
+        StandardNondetHandlerState::new_with_player_rngs(decklist1, decklist2, rng.into(), player_rngs.map(Into::into));
+    GameStateWrapper::new(game_state, NondetProvider::new(state))
+}
+
 impl<S: NondetState> ZobristHashable for GameStateWrapper<S> {
     #[inline]
     fn zobrist_hash(&self) -> HashValue {
@@ -58,6 +58,23 @@ pub enum CharId {
     Yaoyao,
     Nilou,
     Zhongli,
+    Lisa,
+    Xinyan,
+    Sayu,
+    Dehya,
+    Alhaitham,
+    Baizhu,
+    Kirara,
+    Lyney,
+    Dori,
+    ElectroHypostasis,
+    MaguuKenki,
+    MirrorMaiden,
+    AbyssLectorFathomlessFlames,
+    AbyssHeraldWickedTorrents,
+    AbyssLectorVioletLightning,
+    EremiteDesertClearwater,
+    EremiteSunfrost,
 }
 const_default_enum_impl!(CharId, Yoimiya);
 
@@ -220,11 +237,66 @@ pub enum SkillId {
     DominusLapidis,
     DominusLapidisStrikingStone,
     PlanetBefall,
+    LightningTouch,
+    VioletArc,
+    LightningRose,
+    DanceOnFire,
+    SweepingFervor,
+    RiffRevolution,
+    ShuumatsubanNinjaBlade,
+    YoohooArtFuuinDash,
+    YoohooArtMujinaFlurry,
+    SandstormAssault,
+    MoltenInferno,
+    LeonineBite,
+    IncinerationDrive,
+    ForeignRockblade,
+    UniversalityAnElaborationOnForm,
+    ParticularFieldFettersOfPhenomena,
+    TheClassicsOfAcupuncture,
+    UniversalDiagnosis,
+    HolisticRevivification,
+    Boxcutter,
+    MeowteorKick,
+    SecretArtSurpriseDispatch,
+    CardForceTranslocation,
+    BewilderingLights,
+    WondrousTrickMiracleParade,
+    MarvelousSwordDanceModified,
+    SpiritWardingLampTroubleshooterCannon,
+    AlcazarzaraysExactitude,
+    ElectroCrystalProjection,
+    RockPaperScissorsCombo,
+    RockPaperScissorsComboPaper,
+    LightningLockdown,
+    Ichimonji,
+    BlusteringBlade,
+    FrostyAssault,
+    PseudoTenguSweeper,
+    WaterBall,
+    InfluxBlast,
+    RippledReflection,
+    FlameOfSalvation,
+    SearingPrecept,
+    OminousStar,
+    RipplingBlades,
+    VortexEdict,
+    CurseOfTheUndercurrent,
+    RockPaperScissorsComboScissors,
+    VoidThunderblade,
+    ThunderingShackles,
+    VeilOfVioletLightning,
+    WaterRippleStrike,
+    SpringOfRespite,
+    TorrentialRite,
+    SunfrostEdge,
+    FrostboundFeint,
+    DesertChillstorm,
 }
 const_default_enum_impl!(SkillId, FireworkFlareUp);
 
 #[derive(
-    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Enum, StatusIdDerives, GetStatus, GeneratedEnumCasesMacro,
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Enum, StatusIdDerives, GetStatus, GeneratedEnumCasesMacro,
 )]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum StatusId {
@@ -359,13 +431,40 @@ pub enum StatusId {
     SeedOfSkandha,
     ShrineOfMaya,
     RightOfFinalInterpretation,
+
+    // Appended in the order they were added to keep existing IDs stable
+    TandooriRoastChicken,
+    Conductive,
+    FervorShield,
+    FestiveFires,
+    FuufuuWindwheel,
+    IncinerationDrive,
+    ChiselLightMirror,
+    PulsingClarity,
+    ShieldOfSafeTransport,
+    CatGrassCardamom,
+    PropSurplus,
+    PulsatingWitch,
+    ConclusiveOvation,
+    ElectroCrystalCore,
+    RockPaperScissorsComboPaper,
+    Refraction,
+    FieryRebirth,
+    WateryRebirth,
+    SurgingUndercurrent,
+    RefractionMirrorCage,
+    RockPaperScissorsComboScissors,
+    StalwartAndTrue,
+    ElectricRebirth,
+    VioletVeil,
+    FrigidGuard,
 }
 const_default_enum_impl!(StatusId, CrystallizeShield);
 
 #[allow(clippy::derived_hash_with_manual_eq)]
 #[derive(Debug, PartialOrd, Ord, Hash, Enum, EnumSetType, StatusIdDerives, GetStatus, GeneratedEnumCasesMacro)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[enumset(repr = "u32")]
+#[enumset(repr = "u64")]
 pub enum SummonId {
     BurningFlame,
     Oz,
@@ -398,6 +497,18 @@ pub enum SummonId {
     YueguiThrowingMode,
     BountifulCore,
     StoneStele,
+    LightningRose,
+    MujiMujiDaruma,
+    FierySanctumField,
+    GossamerSprite,
+    GrinMalkinHat,
+    AfterSalesServiceRounds,
+    Jinni,
+    ChainsOfWardingThunder,
+    ShadowswordLoneGale,
+    ShadowswordGallopingFrost,
+    DarkfireFurnace,
+    ClearwaterSpring,
 }
 const_default_enum_impl!(SummonId, BurningFlame);
 
@@ -546,5 +657,26 @@ pub enum CardId {
     DescentOfDivinity,
     Beneficent,
     TheStarrySkiesTheirFlowersRain,
+
+    // Appended in the order they were added to keep existing IDs stable
+    TandooriRoastChicken,
+    PulsatingWitch,
+    ConclusiveOvation,
+    AbsorbingPrism,
+    TranscendentAutomaton,
+    MirrorCage,
+    EmbersRekindled,
+    SurgingUndercurrent,
+    FeatherfallJudgment,
+    RockinInAFlamingWorld,
+    SkivingNewAndImproved,
+    StalwartAndTrue,
+    Structuration,
+    AllThingsAreOfTheEarth,
+    CountlessSightsToSee,
+    DiscretionarySupplement,
+    LightningVeil,
+    OasisBlessing,
+    BitingSands,
 }
 const_default_enum_impl!(CardId, BlankCard);
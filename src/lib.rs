@@ -98,7 +98,7 @@ pub mod prelude {
     pub use crate::builder::*;
     pub use crate::deck::Decklist;
     pub use crate::dispatcher_ops::{DispatchError, DispatchResult, NondetRequest};
-    pub use crate::game_state_wrapper::{new_standard_game, GameStateWrapper};
+    pub use crate::game_state_wrapper::{new_standard_game, new_standard_game_with_player_rngs, GameStateWrapper};
     pub use crate::ids::*;
     pub use crate::types::by_player::ByPlayer;
     pub use crate::types::dice_counter::{DiceCounter, DiceDeterminization, DiceDistribution, ElementPriority};
@@ -120,5 +120,12 @@ pub mod prelude {
     pub use crate::types::tcg_model;
 }
 
+/// Proptest strategies for random decks and reachable game states, shared with the tests of dependent crates.
+/// Test-only and not part of the stable API: it is hidden from the docs and changes along with the tests.
+#[cfg(any(test, feature = "proptest"))]
+#[doc(hidden)]
+#[path = "tests/prop_tests/generator.rs"]
+pub mod generator;
+
 #[cfg(test)]
 mod tests;
@@ -71,8 +71,12 @@ impl Element {
 impl Reaction {
     /// Given an optional target element (for swirl),
     /// return the DMG increase, piercing DMG, and an additional command.
+    ///
+    /// The DMG increase is added before any DMG multipliers are applied.
+    /// Piercing DMG is dealt to all characters of the target player other than the DMG target.
+    /// Aggravate and Spread are not separate reactions: they are the consumption of the
+    /// Catalyzing Field created by Quicken.
     pub fn reaction_effects(&self, target: Option<Element>) -> (u8, u8, Option<Command>) {
-        // TODO no proc effects for most reactions
         match self {
             Reaction::Bloom => (1, 0, Some(Command::ApplyStatusToTeam(StatusId::DendroCore))),
             Reaction::Burning => (1, 0, Some(Command::Summon(SummonId::BurningFlame))),
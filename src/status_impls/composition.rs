@@ -142,6 +142,11 @@ macro_rules! compose_status_impls {
                     res: &mut bool,
                 ) -> Option<AppliedEffectResult>;
                 fn preparing_skill(&self, eff_state: &AppliedEffectState) -> Option<SkillId>;
+                fn prevent_defeat(
+                    &self,
+                    eff_state: &AppliedEffectState,
+                    revive_hp: &mut u8,
+                ) -> Option<AppliedEffectResult>;
                 fn trigger_event(&self, e: &mut TriggerEventContext<EventId>) -> Option<AppliedEffectResult>;
                 fn trigger_xevent(&self, e: &mut TriggerEventContext<XEvent>) -> Option<AppliedEffectResult>;
             });
@@ -196,7 +201,7 @@ mod tests {
             if !e.c.eff_state.can_use_once_per_round() {
                 return None;
             }
-            e.add_cmd(Command::HealAll(1));
+            e.add_cmd(Command::Heal(1, CmdCharIdx::All));
             Some(AppliedEffectResult::ConsumeOncePerRound)
         }
     }
@@ -268,6 +273,7 @@ mod tests {
                     flags: enum_set![],
                     dice: DiceCounter::omni(0),
                     affected_by: smallvec![],
+                    used_this_round: Default::default(),
                 },
                 status_key: StatusKey::Character(0, StatusId::NiwabiEnshou),
                 eff_state: &AppliedEffectState::from(128),
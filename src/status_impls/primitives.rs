@@ -371,12 +371,40 @@ pub mod prepared_skill {
     }
 }
 
+pub mod revive {
+    use super::*;
+
+    /// When the attached character would be defeated, revives the character
+    /// with `revive_hp` HP and deletes this status.
+    pub struct ReviveOnDefeat {
+        pub revive_hp: u8,
+    }
+
+    impl ReviveOnDefeat {
+        pub const fn new(revive_hp: u8) -> Self {
+            Self { revive_hp }
+        }
+    }
+
+    impl StatusImpl for ReviveOnDefeat {
+        fn responds_to(&self) -> EnumSet<RespondsTo> {
+            enum_set![RespondsTo::PreventDefeat]
+        }
+
+        fn prevent_defeat(&self, _: &AppliedEffectState, revive_hp: &mut u8) -> Option<AppliedEffectResult> {
+            *revive_hp = self.revive_hp;
+            Some(AppliedEffectResult::DeleteSelf)
+        }
+    }
+}
+
 #[allow(unused_imports)]
 pub mod all {
     pub use super::dmg::*;
     pub use super::end_phase::*;
     pub use super::incoming_dmg::*;
     pub use super::prepared_skill::*;
+    pub use super::revive::*;
     pub use super::xevent::*;
     pub use crate::types::status_impl::EmptyStatusImpl;
     pub use crate::{compose_status_impls, decl_event_handler_trait_impl};
@@ -123,12 +123,13 @@ impl SkillImpl for SkillId {
         &self,
         src_player: &PlayerState,
         status_collection: &StatusCollection,
+        tgt_status_collection: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
         match self.skill().skill_impl {
             None => {}
-            Some(si) => si.commands(src_player, status_collection, ctx, cmds),
+            Some(si) => si.commands(src_player, status_collection, tgt_status_collection, ctx, cmds),
         }
     }
 }
@@ -254,6 +255,7 @@ status_impl_macros!(
         fn dice_distribution(&self, e: &StatusImplContext, dist: &mut DiceDistribution) -> bool;
         fn switch_is_fast_action(&self, eff_state: &AppliedEffectState, res: &mut bool) -> Option<AppliedEffectResult>;
         fn preparing_skill(&self, eff_state: &AppliedEffectState) -> Option<SkillId>;
+        fn prevent_defeat(&self, eff_state: &AppliedEffectState, revive_hp: &mut u8) -> Option<AppliedEffectResult>;
         fn trigger_event(&self, e: &mut TriggerEventContext<EventId>) -> Option<AppliedEffectResult>;
         fn trigger_xevent(&self, e: &mut TriggerEventContext<XEvent>) -> Option<AppliedEffectResult>;
     }
@@ -127,6 +127,8 @@ pub enum Faction {
     Fatui,
     Hilichurl,
     Kairagi,
+    Fontaine,
+    Eremite,
 }
 
 #[allow(clippy::derived_hash_with_manual_eq)]
@@ -75,6 +75,51 @@ fn artifact_2_does_not_reduce_non_matching_element_cost() {
     assert_eq!(4, gs.players.0.dice.total());
 }
 
+#[test]
+fn artifact_2_reduces_talent_card_cost_once_per_round() {
+    let mut gs = game_state_for_artifacts(CardId::BrokenRimesEcho);
+    gs.players.0.add_to_hand_ignore(CardId::KantenSenmyouBlessing);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::BrokenRimesEcho, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(6, gs.players.0.dice.total());
+    let play_talent = Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::KantenSenmyouBlessing, Some(CardSelection::OwnCharacter(0))),
+    );
+    assert_eq!(1, gs.action_info(play_talent).0.total_dice());
+    gs.advance_multiple([play_talent]);
+    assert_eq!(5, gs.players.0.dice.total());
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::KamisatoArtHyouka),
+    )]);
+    // No longer cost reduced
+    assert_eq!(2, gs.players.0.dice.total());
+}
+
+#[test]
+fn artifact_2_reduces_talent_card_cost_for_equipped_non_active_character() {
+    let mut gs = game_state_for_artifacts(CardId::BrokenRimesEcho);
+    gs.players.0.add_to_hand_ignore(CardId::KantenSenmyouBlessing);
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::PlayCard(CardId::BrokenRimesEcho, Some(CardSelection::OwnCharacter(0))),
+        ),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(1)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(5, gs.players.0.dice.total());
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::KantenSenmyouBlessing, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(4, gs.players.0.dice.total());
+    assert!(gs.players.0.char_states[0].has_talent_equipped());
+}
+
 #[test]
 fn artifact_3_dice_guarantee() {
     let mut gs = game_state_for_artifacts(CardId::BlizzardStrayer);
@@ -0,0 +1,240 @@
+use super::*;
+
+fn game_state_for_food(card_id: CardId) -> GameState {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::Kaeya, CharId::Fischl, CharId::Yoimiya],
+        vector![CharId::Ganyu],
+    )
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.players.0.dice.add_single(Dice::Omni, 8);
+    gs.players.1.dice.add_single(Dice::Omni, 8);
+    gs.players.0.add_to_hand_ignore(card_id);
+    gs.players.0.add_to_hand_ignore(card_id);
+    gs
+}
+
+fn play_food(card_id: CardId, char_idx: u8) -> Input {
+    Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(card_id, Some(CardSelection::OwnCharacter(char_idx))),
+    )
+}
+
+#[test]
+fn sweet_madame_targets_selected_character_once_per_round() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::Kaeya, CharId::Fischl, CharId::Yoimiya],
+        vector![CharId::KamisatoAyaka],
+    )
+    .build();
+
+    gs.advance_roll_phase_no_dice();
+    gs.players.0.char_states[0].reduce_hp(5);
+    gs.players.0.char_states[1].reduce_hp(5);
+    gs.players.0.char_states[2].reduce_hp(5);
+    gs.players.0.add_to_hand_ignore(CardId::SweetMadame);
+    gs.players.0.add_to_hand_ignore(CardId::SweetMadame);
+    gs.players.0.add_to_hand_ignore(CardId::SweetMadame);
+    {
+        let mut gs = gs.clone();
+        assert_eq!(
+            Err(DispatchError::InvalidSelection),
+            gs.advance(Input::FromPlayer(
+                PlayerId::PlayerFirst,
+                PlayerAction::PlayCard(CardId::SweetMadame, None)
+            ))
+        )
+    }
+    gs.advance(Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::SweetMadame, Some(CardSelection::OwnCharacter(2))),
+    ))
+    .unwrap();
+    assert_eq!(6, gs.players.0.char_states[2].hp());
+    {
+        let mut gs = gs.clone();
+        assert_eq!(
+            Err(DispatchError::InvalidSelection),
+            gs.advance(Input::FromPlayer(
+                PlayerId::PlayerFirst,
+                PlayerAction::PlayCard(CardId::SweetMadame, Some(CardSelection::OwnCharacter(2)))
+            ))
+        )
+    }
+    gs.advance(Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::SweetMadame, Some(CardSelection::OwnCharacter(0))),
+    ))
+    .unwrap();
+    assert_eq!(6, gs.players.0.char_states[0].hp());
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    gs.advance_roll_phase_no_dice();
+    gs.advance(Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::SweetMadame, Some(CardSelection::OwnCharacter(2))),
+    ))
+    .unwrap();
+    assert_eq!(7, gs.players.0.char_states[2].hp());
+}
+
+#[test]
+fn mondstadt_hash_brown_heals_selected_character() {
+    let mut gs = game_state_for_food(CardId::MondstadtHashBrown);
+    gs.players.0.char_states[0].reduce_hp(5);
+    gs.players.0.char_states[1].reduce_hp(5);
+    gs.advance_multiple([play_food(CardId::MondstadtHashBrown, 1)]);
+    assert_eq!(7, gs.players.0.dice.total());
+    assert_eq!(5, gs.players.0.char_states[0].hp());
+    assert_eq!(7, gs.players.0.char_states[1].hp());
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 1, StatusId::Satiated));
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::Satiated));
+}
+
+#[test]
+fn mushroom_pizza_heals_selected_character_at_end_phase() {
+    let mut gs = game_state_for_food(CardId::MushroomPizza);
+    gs.players.0.char_states[1].reduce_hp(5);
+    gs.advance_multiple([play_food(CardId::MushroomPizza, 1)]);
+    assert_eq!(6, gs.players.0.char_states[1].hp());
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 1, StatusId::MushroomPizza));
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::MushroomPizza));
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    gs.advance_roll_phase_no_dice();
+    assert_eq!(7, gs.players.0.char_states[1].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 1, StatusId::Satiated));
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    gs.advance_roll_phase_no_dice();
+    assert_eq!(8, gs.players.0.char_states[1].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 1, StatusId::MushroomPizza));
+}
+
+#[test]
+fn lotus_flower_crisp_applies_to_selected_character() {
+    let mut gs = game_state_for_food(CardId::LotusFlowerCrisp);
+    gs.advance_multiple([play_food(CardId::LotusFlowerCrisp, 1)]);
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 1, StatusId::LotusFlowerCrisp));
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::LotusFlowerCrisp));
+    gs.advance_multiple([
+        play_food(CardId::LotusFlowerCrisp, 0),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::CastSkill(SkillId::LiutianArchery)),
+    ]);
+    assert_eq!(10, gs.players.0.char_states[0].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::LotusFlowerCrisp));
+}
+
+#[test]
+fn adeptus_temptation_increases_burst_dmg() {
+    let mut gs = game_state_for_food(CardId::AdeptusTemptation);
+    gs.players.0.char_states[0].set_energy(2);
+    gs.advance_multiple([play_food(CardId::AdeptusTemptation, 0)]);
+    assert_eq!(10, gs.players.0.char_states[0].hp());
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::AdeptusTemptation));
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::Satiated));
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::GlacialWaltz),
+    )]);
+    assert_eq!(6, gs.players.1.char_states[0].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::AdeptusTemptation));
+}
+
+#[test]
+fn minty_meat_rolls_reduces_normal_attack_cost_for_selected_character() {
+    let mut gs = game_state_for_food(CardId::MintyMeatRolls);
+    gs.advance_multiple([play_food(CardId::MintyMeatRolls, 0)]);
+    assert_eq!(7, gs.players.0.dice.total());
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::MintyMeatRolls));
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::CeremonialBladework),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(5, gs.players.0.dice.total());
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::CeremonialBladework),
+    )]);
+    assert_eq!(3, gs.players.0.dice.total());
+}
+
+#[test]
+fn northern_smoked_chicken_reduces_normal_attack_cost_once() {
+    let mut gs = game_state_for_food(CardId::NorthernSmokedChicken);
+    gs.advance_multiple([play_food(CardId::NorthernSmokedChicken, 0)]);
+    assert_eq!(8, gs.players.0.dice.total());
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::NorthernSmokedChicken));
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::CeremonialBladework),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(6, gs.players.0.dice.total());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::NorthernSmokedChicken));
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::CeremonialBladework),
+    )]);
+    assert_eq!(3, gs.players.0.dice.total());
+}
+
+#[test]
+fn northern_smoked_chicken_does_not_apply_to_active_character_when_another_is_selected() {
+    let mut gs = game_state_for_food(CardId::NorthernSmokedChicken);
+    gs.advance_multiple([play_food(CardId::NorthernSmokedChicken, 2)]);
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 2, StatusId::NorthernSmokedChicken));
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::NorthernSmokedChicken));
+}
+
+#[test]
+fn tandoori_roast_chicken_feeds_all_non_satiated_characters() {
+    let mut gs = game_state_for_food(CardId::TandooriRoastChicken);
+    gs.players.0.add_to_hand_ignore(CardId::SweetMadame);
+    gs.advance_multiple([
+        play_food(CardId::SweetMadame, 1),
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::PlayCard(CardId::TandooriRoastChicken, None),
+        ),
+    ]);
+    assert_eq!(6, gs.players.0.dice.total());
+    for i in 0..3 {
+        assert!(gs.has_character_status(PlayerId::PlayerFirst, i, StatusId::Satiated));
+    }
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::TandooriRoastChicken));
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 1, StatusId::TandooriRoastChicken));
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 2, StatusId::TandooriRoastChicken));
+    {
+        let mut gs = gs.clone();
+        assert_eq!(
+            Err(DispatchError::UnableToPlayCard),
+            gs.advance(Input::FromPlayer(
+                PlayerId::PlayerFirst,
+                PlayerAction::PlayCard(CardId::TandooriRoastChicken, None)
+            ))
+        );
+    }
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::Frostgnaw),
+    )]);
+    assert_eq!(5, gs.players.1.char_states[0].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::TandooriRoastChicken));
+}
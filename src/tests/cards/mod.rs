@@ -6,6 +6,8 @@ pub mod support;
 
 pub mod elemental_resonance;
 
+pub mod food;
+
 #[test]
 fn changing_shifts() {
     let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
@@ -76,68 +78,6 @@ fn leave_it_to_me() {
     assert_eq!(Some(PlayerId::PlayerSecond), gs.to_move_player());
 }
 
-#[test]
-fn food() {
-    // TODO food implementation: target character and once per turn check
-    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
-        vector![CharId::Kaeya, CharId::Fischl, CharId::Yoimiya],
-        vector![CharId::KamisatoAyaka],
-    )
-    .build();
-
-    gs.advance_roll_phase_no_dice();
-    gs.players.0.char_states[0].reduce_hp(5);
-    gs.players.0.char_states[1].reduce_hp(5);
-    gs.players.0.char_states[2].reduce_hp(5);
-    gs.players.0.add_to_hand_ignore(CardId::SweetMadame);
-    gs.players.0.add_to_hand_ignore(CardId::SweetMadame);
-    gs.players.0.add_to_hand_ignore(CardId::SweetMadame);
-    {
-        let mut gs = gs.clone();
-        assert_eq!(
-            Err(DispatchError::InvalidSelection),
-            gs.advance(Input::FromPlayer(
-                PlayerId::PlayerFirst,
-                PlayerAction::PlayCard(CardId::SweetMadame, None)
-            ))
-        )
-    }
-    gs.advance(Input::FromPlayer(
-        PlayerId::PlayerFirst,
-        PlayerAction::PlayCard(CardId::SweetMadame, Some(CardSelection::OwnCharacter(2))),
-    ))
-    .unwrap();
-    assert_eq!(6, gs.players.0.char_states[2].hp());
-    {
-        let mut gs = gs.clone();
-        assert_eq!(
-            Err(DispatchError::InvalidSelection),
-            gs.advance(Input::FromPlayer(
-                PlayerId::PlayerFirst,
-                PlayerAction::PlayCard(CardId::SweetMadame, Some(CardSelection::OwnCharacter(2)))
-            ))
-        )
-    }
-    gs.advance(Input::FromPlayer(
-        PlayerId::PlayerFirst,
-        PlayerAction::PlayCard(CardId::SweetMadame, Some(CardSelection::OwnCharacter(0))),
-    ))
-    .unwrap();
-    assert_eq!(6, gs.players.0.char_states[0].hp());
-    gs.advance_multiple([
-        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
-        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
-        Input::NoAction,
-    ]);
-    gs.advance_roll_phase_no_dice();
-    gs.advance(Input::FromPlayer(
-        PlayerId::PlayerFirst,
-        PlayerAction::PlayCard(CardId::SweetMadame, Some(CardSelection::OwnCharacter(2))),
-    ))
-    .unwrap();
-    assert_eq!(7, gs.players.0.char_states[2].hp());
-}
-
 #[test]
 fn i_havent_lost_yet_activation_condition() {
     let mut gs: GameState<()> =
@@ -182,6 +122,38 @@ fn i_havent_lost_yet_activation_condition() {
     )));
 }
 
+#[test]
+fn i_havent_lost_yet_once_per_round_across_copies() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Kaeya, CharId::Fischl], vector![CharId::Yoimiya])
+            .build();
+
+    gs.players.0.dice.add_single(Dice::Omni, 8);
+    gs.players.1.dice.add_single(Dice::Omni, 8);
+    gs.players.0.add_to_hand_ignore(CardId::IHaventLostYet);
+    gs.players.0.add_to_hand_ignore(CardId::IHaventLostYet);
+    gs.players.0.try_get_character_mut(1).unwrap().set_hp(1);
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(1)),
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::FireworkFlareUp),
+        ),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::PostDeathSwitch(0)),
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::PlayCard(CardId::IHaventLostYet, None),
+        ),
+    ]);
+
+    assert_eq!([CardId::IHaventLostYet], gs.players.0.hand.slice());
+    assert!(!gs.available_actions().contains(&Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::IHaventLostYet, None)
+    )));
+}
+
 #[test]
 fn strategize() {
     let mut gs: GameState<()> =
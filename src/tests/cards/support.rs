@@ -196,3 +196,109 @@ fn liben() {
     assert_eq!(2, gs.players.0.dice[Dice::Omni]);
     assert_eq!(3, gs.round_number);
 }
+
+#[test]
+fn liu_su_once_per_round_shared_across_instances() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::Fischl, CharId::Ganyu, CharId::Keqing],
+        vector![CharId::Yoimiya],
+    )
+    .ignore_costs(true)
+    .build();
+
+    gs.advance_roll_phase_no_dice();
+    gs.players.0.add_to_hand_ignore(CardId::LiuSu);
+    gs.players.0.add_to_hand_ignore(CardId::LiuSu);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::PlayCard(CardId::LiuSu, None)),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::PlayCard(CardId::LiuSu, None)),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(1)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(2)),
+    ]);
+    let total_usages = |gs: &GameState<()>| -> u8 {
+        [SupportSlot::Slot0, SupportSlot::Slot1]
+            .iter()
+            .filter_map(|&slot| gs.status_collection(PlayerId::PlayerFirst).find_support(slot))
+            .map(|s| s.state.usages())
+            .sum()
+    };
+    assert_eq!(1, gs.players.0.char_states[1].energy());
+    assert_eq!(0, gs.players.0.char_states[2].energy());
+    assert_eq!(3, total_usages(&gs));
+
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    gs.advance_roll_phase_no_dice();
+    assert_eq!(2, gs.round_number);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(0)),
+    ]);
+    assert_eq!(1, gs.players.0.char_states[0].energy());
+    assert_eq!(2, total_usages(&gs));
+}
+
+#[test]
+fn wangshu_inn_heals_most_injured_standby_character() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::Kaeya, CharId::Fischl, CharId::Yoimiya, CharId::Ganyu],
+        vector![CharId::Noelle],
+    )
+    .ignore_costs(true)
+    .build();
+
+    gs.advance_roll_phase_no_dice();
+    gs.players.0.char_states[0].set_hp(2);
+    gs.players.0.char_states[1].set_hp(0);
+    gs.players.0.char_states[2].set_hp(7);
+    gs.players.0.char_states[3].set_hp(5);
+    gs.players.0.add_to_hand_ignore(CardId::WangshuInn);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::PlayCard(CardId::WangshuInn, None)),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(2, gs.players.0.char_states[0].hp());
+    assert_eq!(0, gs.players.0.char_states[1].hp());
+    assert_eq!(7, gs.players.0.char_states[2].hp());
+    assert_eq!(7, gs.players.0.char_states[3].hp());
+    assert_eq!(
+        1,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .find_support(SupportSlot::Slot0)
+            .unwrap()
+            .state
+            .usages()
+    );
+}
+
+#[test]
+fn wangshu_inn_does_not_consume_usage_without_injured_standby_character() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Kaeya, CharId::Fischl], vector![CharId::Noelle])
+            .ignore_costs(true)
+            .build();
+
+    gs.advance_roll_phase_no_dice();
+    gs.players.0.char_states[0].set_hp(2);
+    gs.players.0.add_to_hand_ignore(CardId::WangshuInn);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::PlayCard(CardId::WangshuInn, None)),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(2, gs.players.0.char_states[0].hp());
+    assert_eq!(
+        2,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .find_support(SupportSlot::Slot0)
+            .unwrap()
+            .state
+            .usages()
+    );
+}
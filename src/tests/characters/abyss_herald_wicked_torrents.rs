@@ -0,0 +1,56 @@
+use super::*;
+
+#[test]
+fn watery_rebirth_revives_with_4_hp() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::Fischl],
+        vector![CharId::AbyssHeraldWickedTorrents, CharId::Kaeya],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerSecond).char_states[0].set_hp(2);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+    )]);
+    assert_eq!(4, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerSecond, 0, StatusId::WateryRebirth));
+    assert_eq!(0, gs.player(PlayerId::PlayerSecond).active_char_idx);
+}
+
+#[test]
+fn vortex_edict_deals_piercing_dmg() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::AbyssHeraldWickedTorrents],
+        vector![CharId::Fischl, CharId::Kaeya],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::VortexEdict),
+    )]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(9, gs.player(PlayerId::PlayerSecond).char_states[1].hp());
+}
+
+#[test]
+fn talent_card_increases_piercing_dmg() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::AbyssHeraldWickedTorrents],
+        vector![CharId::Fischl, CharId::Kaeya],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::SurgingUndercurrent);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::SurgingUndercurrent, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[1].hp());
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+#[test]
+fn fiery_rebirth_revives_without_triggering_defeat_effects() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::Fischl],
+        vector![CharId::AbyssLectorFathomlessFlames, CharId::Kaeya],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerSecond).char_states[0].set_hp(1);
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::GamblersEarrings);
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::PlayCard(CardId::GamblersEarrings, Some(CardSelection::OwnCharacter(0))),
+        ),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::BoltsOfDownfall)),
+    ]);
+    assert_eq!(3, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerSecond, 0, StatusId::FieryRebirth));
+    assert_eq!(0, gs.player(PlayerId::PlayerFirst).dice[Dice::Omni]);
+    assert_eq!(Some(PlayerId::PlayerSecond), gs.to_move_player());
+}
+
+#[test]
+fn darkfire_furnace_deals_piercing_dmg_at_end_phase() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::AbyssLectorFathomlessFlames],
+        vector![CharId::Fischl, CharId::Kaeya],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::OminousStar)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(7, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::DarkfireFurnace));
+    gs.player_mut(PlayerId::PlayerSecond).char_states[0].applied.clear();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(6, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(9, gs.player(PlayerId::PlayerSecond).char_states[1].hp());
+}
+
+#[test]
+fn talent_card_increases_darkfire_furnace_usages() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::AbyssLectorFathomlessFlames],
+        vector![CharId::Fischl],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::EmbersRekindled);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::EmbersRekindled, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(
+        3,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::DarkfireFurnace))
+            .unwrap()
+            .usages()
+    );
+}
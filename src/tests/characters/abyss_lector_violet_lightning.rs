@@ -0,0 +1,75 @@
+use super::*;
+
+#[test]
+fn electric_rebirth_revives_without_triggering_defeat_effects() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::Fischl],
+        vector![CharId::AbyssLectorVioletLightning, CharId::Kaeya],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerSecond).char_states[0].set_hp(1);
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::GamblersEarrings);
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::PlayCard(CardId::GamblersEarrings, Some(CardSelection::OwnCharacter(0))),
+        ),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::BoltsOfDownfall)),
+    ]);
+    assert_eq!(3, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerSecond, 0, StatusId::ElectricRebirth));
+    assert_eq!(0, gs.player(PlayerId::PlayerFirst).dice[Dice::Omni]);
+    assert_eq!(Some(PlayerId::PlayerSecond), gs.to_move_player());
+}
+
+#[test]
+fn veil_of_violet_lightning_creates_shield() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::AbyssLectorVioletLightning],
+        vector![CharId::Fischl],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::VeilOfVioletLightning),
+        ),
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+        ),
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert!(!gs.has_team_status(PlayerId::PlayerFirst, StatusId::VioletVeil));
+}
+
+#[test]
+fn talent_card_increases_violet_veil_shield_points() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::AbyssLectorVioletLightning],
+        vector![CharId::Fischl],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::LightningVeil);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::LightningVeil, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(
+        3,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Team(StatusId::VioletVeil))
+            .unwrap()
+            .usages()
+    );
+}
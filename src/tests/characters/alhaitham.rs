@@ -0,0 +1,85 @@
+use super::*;
+
+fn chisel_light_mirror_usages(gs: &GameState<()>) -> Option<u8> {
+    gs.status_collection(PlayerId::PlayerFirst)
+        .get(StatusKey::Character(0, StatusId::ChiselLightMirror))
+        .map(|s| s.usages())
+}
+
+#[test]
+fn chisel_light_mirror_infuses_normal_attack() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Alhaitham], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::UniversalityAnElaborationOnForm),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(Some(2), chisel_light_mirror_usages(&gs));
+    gs.player_mut(PlayerId::PlayerSecond).char_states[0].applied.clear();
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::ForeignRockblade),
+    )]);
+    assert_eq!(6, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(
+        elem_set![Element::Dendro],
+        gs.player(PlayerId::PlayerSecond).char_states[0].applied
+    );
+
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(Some(1), chisel_light_mirror_usages(&gs));
+}
+
+#[test]
+fn burst_consumes_chisel_light_mirror_for_extra_dmg() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Alhaitham], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::UniversalityAnElaborationOnForm),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    gs.player_mut(PlayerId::PlayerSecond).char_states[0].applied.clear();
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::ParticularFieldFettersOfPhenomena),
+    )]);
+    assert_eq!(2, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(None, chisel_light_mirror_usages(&gs));
+}
+
+#[test]
+fn talent_card_keeps_chisel_light_mirror_usages_at_end_phase() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Alhaitham], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::Structuration);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::Structuration, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(Some(2), chisel_light_mirror_usages(&gs));
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(Some(2), chisel_light_mirror_usages(&gs));
+}
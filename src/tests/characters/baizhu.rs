@@ -0,0 +1,86 @@
+use super::*;
+
+#[test]
+fn gossamer_sprite_deals_dmg_and_heals_at_end_phase() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Baizhu], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).char_states[0].set_hp(5);
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::UniversalDiagnosis),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::GossamerSprite));
+    assert_eq!(9, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    gs.player_mut(PlayerId::PlayerSecond).char_states[0].applied.clear();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert!(!gs.has_summon(PlayerId::PlayerFirst, SummonId::GossamerSprite));
+}
+
+#[test]
+fn pulsing_clarity_heals_all_at_end_phase() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Baizhu, CharId::Fischl], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).char_states[0].set_hp(5);
+    gs.player_mut(PlayerId::PlayerFirst).char_states[1].set_hp(5);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::HolisticRevivification),
+    )]);
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+    assert!(gs.has_team_status(PlayerId::PlayerFirst, StatusId::PulsingClarity));
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(7, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert_eq!(7, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+    assert_eq!(
+        1,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Team(StatusId::PulsingClarity))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn talent_card_increases_pulsing_clarity_healing() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Baizhu, CharId::Fischl], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).char_states[0].set_hp(5);
+    gs.player_mut(PlayerId::PlayerFirst).char_states[1].set_hp(5);
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::AllThingsAreOfTheEarth);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::AllThingsAreOfTheEarth, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert_eq!(8, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+}
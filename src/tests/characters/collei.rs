@@ -73,3 +73,43 @@ fn talent_card_does_not_trigger_on_incoming_dendro_reaction() {
     assert!(gs.has_team_status(PlayerId::PlayerFirst, StatusId::Sprout));
     assert!(gs.has_team_status(PlayerId::PlayerSecond, StatusId::CatalyzingField));
 }
+
+#[test]
+fn talent_card_applies_sprout_once_per_round() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Collei, CharId::Fischl], vector![CharId::Ganyu])
+            .ignore_costs(true)
+            .build();
+
+    gs.players.0.add_to_hand_ignore(CardId::FloralSidewinder);
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::PlayCard(CardId::FloralSidewinder, Some(CardSelection::OwnCharacter(0))),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(1)),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::Nightrider)),
+    ]);
+    assert!(!gs.has_team_status(PlayerId::PlayerFirst, StatusId::Sprout));
+    gs.players.1.char_states[0].set_hp(10);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(0)),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FloralBrush)),
+    ]);
+    assert!(!gs.has_team_status(PlayerId::PlayerFirst, StatusId::Sprout));
+
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    gs.advance_roll_phase_no_dice();
+    assert_eq!(2, gs.round_number);
+    gs.players.1.char_states[0].set_hp(10);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FloralBrush)),
+    ]);
+    assert!(gs.has_team_status(PlayerId::PlayerFirst, StatusId::Sprout));
+}
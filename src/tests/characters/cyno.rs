@@ -143,3 +143,31 @@ fn sacred_rite_wolfs_swiftness_uses_indwelling_level_pre_increase_and_increases_
         );
     }
 }
+
+#[test]
+fn talent_equipped_increases_indwelling_level_before_secret_rite() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Cyno], vector![CharId::Yoimiya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.status_collection_mut(PlayerId::PlayerFirst)
+        .get_mut(StatusKey::Character(0, StatusId::PactswornPathclearer))
+        .unwrap()
+        .set_counter(1);
+    gs.players.0.add_to_hand_ignore(CardId::FeatherfallJudgment);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::FeatherfallJudgment, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    // TalentEquipped is triggered before the Talent card casts Secret Rite: Chasmic Soulfarer
+    assert_eq!(
+        2,
+        gs.status_collection_mut(PlayerId::PlayerFirst)
+            .get(StatusKey::Character(0, StatusId::PactswornPathclearer))
+            .unwrap()
+            .counter()
+    );
+    // Level 2: Electro infusion and +1 DMG from the Talent card
+    assert_eq!(6, gs.player(PlayerId::PlayerSecond).active_character().hp());
+}
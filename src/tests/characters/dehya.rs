@@ -0,0 +1,126 @@
+use super::*;
+
+#[test]
+fn fiery_sanctum_field_reduces_dmg_once_per_round() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Dehya], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::MoltenInferno)),
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+        ),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+        ),
+    ]);
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::FierySanctumField));
+    assert_eq!(9, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(7, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(
+        2,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::FierySanctumField))
+            .unwrap()
+            .usages()
+    );
+}
+
+fn game_state_with_fiery_sanctum_field(dehya_hp: u8) -> GameState<()> {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Kaeya, CharId::Dehya], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).char_states[1].set_hp(dehya_hp);
+    gs.status_collection_mut(PlayerId::PlayerFirst).apply_or_refresh_status(
+        StatusKey::Summon(SummonId::FierySanctumField),
+        SummonId::FierySanctumField.status(),
+        &None,
+    );
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+        ),
+    ]);
+    gs
+}
+
+#[test]
+fn fiery_sanctum_field_standby_dehya_takes_piercing_dmg_at_7_hp() {
+    let mut gs = game_state_with_fiery_sanctum_field(7);
+    assert_eq!(9, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+
+    // Once per round
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerSecond,
+        PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+    )]);
+    assert_eq!(7, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+}
+
+#[test]
+fn fiery_sanctum_field_standby_dehya_below_7_hp_takes_no_dmg() {
+    let gs = game_state_with_fiery_sanctum_field(6);
+    assert_eq!(9, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+}
+
+#[test]
+fn leonine_bite_prepares_incineration_drive() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Dehya], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::LeonineBite),
+    )]);
+    assert_eq!(7, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::IncinerationDrive));
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(4, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::IncinerationDrive));
+}
+
+#[test]
+fn talent_card_heals_dehya_at_end_phase_at_6_hp_or_less() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Dehya], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::StalwartAndTrue);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::StalwartAndTrue, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::FierySanctumField));
+    gs.player_mut(PlayerId::PlayerFirst).char_states[0].set_hp(5);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(7, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+#[test]
+fn after_sales_service_rounds_deals_dmg_at_end_phase() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Dori], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::SpiritWardingLampTroubleshooterCannon),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(7, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(!gs.has_summon(PlayerId::PlayerFirst, SummonId::AfterSalesServiceRounds));
+}
+
+#[test]
+fn jinni_heals_most_injured_at_end_phase() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Dori, CharId::Fischl], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).char_states[1].set_hp(5);
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::AlcazarzaraysExactitude),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(9, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(7, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+    assert_eq!(
+        1,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::Jinni))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn talent_card_makes_jinni_add_energy_at_end_phase() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Dori, CharId::Fischl], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).char_states[1].set_hp(5);
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::DiscretionarySupplement);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::DiscretionarySupplement, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::Jinni));
+    gs.player_mut(PlayerId::PlayerFirst).char_states[0].set_energy(0);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(7, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+    assert_eq!(1, gs.player(PlayerId::PlayerFirst).char_states[0].energy());
+}
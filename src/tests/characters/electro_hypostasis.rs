@@ -0,0 +1,88 @@
+use super::*;
+
+#[test]
+fn electro_crystal_core_revives_character_once() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::ElectroHypostasis, CharId::Kaeya],
+        vector![CharId::Fischl],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).char_states[0].set_hp(1);
+    gs.player_mut(PlayerId::PlayerFirst).char_states[0]
+        .flags
+        .insert(CharFlag::TalentEquipped);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+        ),
+    ]);
+    {
+        let char_state = &gs.player(PlayerId::PlayerFirst).char_states[0];
+        assert_eq!(1, char_state.hp());
+        assert!(!char_state.is_invalid());
+        assert!(char_state.has_talent_equipped());
+    }
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::ElectroCrystalCore));
+    assert_eq!(0, gs.player(PlayerId::PlayerFirst).active_char_idx);
+
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+        ),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::PostDeathSwitch(1)),
+    ]);
+    assert!(gs.player(PlayerId::PlayerFirst).char_states[0].is_invalid());
+    assert_eq!(1, gs.player(PlayerId::PlayerFirst).active_char_idx);
+}
+
+#[test]
+fn rock_paper_scissors_combo_prepares_scissors_then_paper() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::ElectroHypostasis], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::RockPaperScissorsCombo),
+    )]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::RockPaperScissorsComboScissors));
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(6, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::RockPaperScissorsComboScissors));
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::RockPaperScissorsComboPaper));
+    gs.advance_multiple([Input::NoAction]);
+    assert_eq!(3, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::RockPaperScissorsComboPaper));
+}
+
+#[test]
+fn talent_card_heals_and_reattaches_electro_crystal_core() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::ElectroHypostasis], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).char_states[0].set_hp(3);
+    gs.status_collection_mut(PlayerId::PlayerFirst)
+        .delete(StatusKey::Character(0, StatusId::ElectroCrystalCore));
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::AbsorbingPrism);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::AbsorbingPrism, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    let char_state = &gs.player(PlayerId::PlayerFirst).char_states[0];
+    assert_eq!(6, char_state.hp());
+    assert!(char_state.has_talent_equipped());
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::ElectroCrystalCore));
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+#[test]
+fn clearwater_spring_heals_most_injured_at_end_phase() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::EremiteDesertClearwater, CharId::Fischl],
+        vector![CharId::Kaeya],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).char_states[1].set_hp(5);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::SpringOfRespite)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(9, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::ClearwaterSpring));
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(10, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert_eq!(7, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+    assert_eq!(
+        1,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::ClearwaterSpring))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn torrential_rite_heals_all_characters() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::EremiteDesertClearwater, CharId::Fischl],
+        vector![CharId::Kaeya],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).char_states[0].set_hp(5);
+    gs.player_mut(PlayerId::PlayerFirst).char_states[1].set_hp(5);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::TorrentialRite),
+    )]);
+    assert_eq!(7, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+}
+
+#[test]
+fn talent_card_increases_clearwater_spring_usages() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::EremiteDesertClearwater], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::OasisBlessing);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::OasisBlessing, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(
+        3,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::ClearwaterSpring))
+            .unwrap()
+            .usages()
+    );
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn frostbound_feint_applies_frigid_guard() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::EremiteSunfrost], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FrostboundFeint)),
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+        ),
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(9, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::FrigidGuard));
+}
+
+#[test]
+fn talent_card_increases_normal_attack_dmg_with_frigid_guard() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::EremiteSunfrost], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::BitingSands);
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::PlayCard(CardId::BitingSands, Some(CardSelection::OwnCharacter(0))),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::FrigidGuard));
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::SunfrostEdge),
+    )]);
+    assert_eq!(5, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+}
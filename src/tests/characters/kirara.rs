@@ -0,0 +1,75 @@
+use super::*;
+
+#[test]
+fn meowteor_kick_creates_shield() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Kirara], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::MeowteorKick)),
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+        ),
+    ]);
+    assert_eq!(9, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(10, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert!(!gs.has_team_status(PlayerId::PlayerFirst, StatusId::ShieldOfSafeTransport));
+}
+
+#[test]
+fn cat_grass_cardamom_deals_dmg_on_switch() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Kirara, CharId::Fischl], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::SecretArtSurpriseDispatch),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(6, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    gs.player_mut(PlayerId::PlayerSecond).char_states[0].applied.clear();
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::SwitchCharacter(1),
+    )]);
+    assert_eq!(5, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(
+        1,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Team(StatusId::CatGrassCardamom))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn talent_card_draws_a_card() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Kirara], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::CountlessSightsToSee);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::CountlessSightsToSee, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(
+        Some(NondetRequest::DrawCardsOfType(PlayerId::PlayerFirst, 1, None)),
+        gs.nondet_request()
+    );
+    gs.advance(Input::NondetResult(NondetResult::ProvideCards(
+        ([CardId::Paimon].into(), Default::default()).into(),
+    )))
+    .unwrap();
+    assert_eq!(9, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.player(PlayerId::PlayerFirst).hand.contains(&CardId::Paimon));
+}
@@ -0,0 +1,91 @@
+use super::*;
+
+fn conductive_usages(gs: &GameState<()>) -> Option<u8> {
+    gs.status_collection(PlayerId::PlayerSecond)
+        .get(StatusKey::Character(0, StatusId::Conductive))
+        .map(|s| s.usages())
+}
+
+#[test]
+fn violet_arc_consumes_conductive_for_extra_dmg() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Lisa], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::VioletArc)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(Some(2), conductive_usages(&gs));
+
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::VioletArc),
+    )]);
+    assert_eq!(4, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(None, conductive_usages(&gs));
+}
+
+#[test]
+fn charged_attack_applies_conductive() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Lisa], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::LightningTouch),
+    )]);
+    assert_eq!(9, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(Some(2), conductive_usages(&gs));
+
+    gs.player_mut(PlayerId::PlayerFirst).dice.add_single(Dice::Omni, 1);
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+        ),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::LightningTouch)),
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(Some(2), conductive_usages(&gs));
+}
+
+#[test]
+fn talent_card_applies_conductive_on_switch_out_once_per_round() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Lisa, CharId::Fischl], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::PulsatingWitch);
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::PlayCard(CardId::PulsatingWitch, Some(CardSelection::OwnCharacter(0))),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(Some(2), conductive_usages(&gs));
+
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::SwitchCharacter(1),
+    )]);
+    assert_eq!(Some(4), conductive_usages(&gs));
+
+    gs.status_collection_mut(PlayerId::PlayerSecond)
+        .get_mut(StatusKey::Character(0, StatusId::Conductive))
+        .unwrap()
+        .set_usages(1);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(0)),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(1)),
+    ]);
+    assert_eq!(Some(1), conductive_usages(&gs));
+}
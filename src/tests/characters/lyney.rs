@@ -0,0 +1,67 @@
+use super::*;
+
+#[test]
+fn charged_attack_summons_grin_malkin_hat_and_adds_prop_surplus() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Lyney], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::CardForceTranslocation),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::GrinMalkinHat));
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::PropSurplus));
+
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::BewilderingLights),
+    )]);
+    assert_eq!(4, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::PropSurplus));
+}
+
+#[test]
+fn non_charged_attack_does_not_summon() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Lyney], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).dice.add_single(Dice::Omni, 1);
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::BewilderingLights),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::CardForceTranslocation),
+        ),
+    ]);
+    assert_eq!(5, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(!gs.has_summon(PlayerId::PlayerFirst, SummonId::GrinMalkinHat));
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::PropSurplus));
+}
+
+#[test]
+fn talent_card_increases_bewildering_lights_dmg() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Lyney], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::ConclusiveOvation);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::ConclusiveOvation, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(6, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+#[test]
+fn shadowsword_summons_deal_dmg_at_end_phase() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::MaguuKenki], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::BlusteringBlade)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FrostyAssault)),
+    ]);
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::ShadowswordLoneGale));
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::ShadowswordGallopingFrost));
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(
+        elem_set![Element::Cryo],
+        gs.player(PlayerId::PlayerSecond).char_states[0].applied
+    );
+    assert_eq!(
+        1,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::ShadowswordLoneGale))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn pseudo_tengu_sweeper_triggers_shadowswords() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::MaguuKenki], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::BlusteringBlade)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FrostyAssault)),
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::PseudoTenguSweeper),
+        ),
+    ]);
+    assert_eq!(4, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(
+        2,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::ShadowswordGallopingFrost))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn talent_card_increases_shadowsword_usages() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::MaguuKenki], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::TranscendentAutomaton);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::TranscendentAutomaton, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(
+        3,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::ShadowswordLoneGale))
+            .unwrap()
+            .usages()
+    );
+}
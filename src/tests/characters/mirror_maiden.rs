@@ -0,0 +1,64 @@
+use super::*;
+
+#[test]
+fn influx_blast_applies_refraction() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::MirrorMaiden], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::InfluxBlast),
+    )]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.has_character_status(PlayerId::PlayerSecond, 0, StatusId::Refraction));
+}
+
+#[test]
+fn refraction_increases_hydro_dmg_taken() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::MirrorMaiden], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::InfluxBlast)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::WaterBall)),
+    ]);
+    assert_eq!(6, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.has_character_status(PlayerId::PlayerSecond, 0, StatusId::Refraction));
+}
+
+#[test]
+fn mirror_cage_extends_refraction_and_increases_switching_cost() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::MirrorMaiden],
+        vector![CharId::Fischl, CharId::Kaeya],
+    )
+    .build();
+    gs.advance_roll_phase_no_dice();
+    gs.players.0.dice = DiceCounter::new(&vec![(Dice::Omni, 8)]);
+    gs.players.1.dice = DiceCounter::new(&vec![(Dice::Omni, 8)]);
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::MirrorCage);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::MirrorCage, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(10, gs.player(PlayerId::PlayerSecond).char_states[1].hp());
+    assert!(!gs.has_character_status(PlayerId::PlayerSecond, 0, StatusId::Refraction));
+    let refraction = gs
+        .status_collection_mut(PlayerId::PlayerSecond)
+        .get(StatusKey::Character(0, StatusId::RefractionMirrorCage))
+        .unwrap();
+    assert_eq!(3, refraction.duration());
+
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerSecond,
+        PlayerAction::SwitchCharacter(1),
+    )]);
+    assert_eq!(6, gs.player(PlayerId::PlayerSecond).dice.total());
+}
@@ -55,3 +55,37 @@ mod bennett;
 mod beidou;
 
 mod nilou;
+
+mod lisa;
+
+mod xinyan;
+
+mod sayu;
+
+mod dehya;
+
+mod alhaitham;
+
+mod baizhu;
+
+mod kirara;
+
+mod lyney;
+
+mod dori;
+
+mod electro_hypostasis;
+
+mod maguu_kenki;
+
+mod mirror_maiden;
+
+mod abyss_lector_fathomless_flames;
+
+mod abyss_herald_wicked_torrents;
+
+mod abyss_lector_violet_lightning;
+
+mod eremite_desert_clearwater;
+
+mod eremite_sunfrost;
@@ -108,7 +108,7 @@ fn reflection_reduces_dmg_and_remains_until_end_phase() {
     assert_eq!(8, fischl.hp());
 }
 
-// TODO do reaction DMG bonuses apply before or after doubling? (after currently)
+// Reaction DMG bonuses are additive and apply before doubling.
 #[test]
 fn stellaris_phantasm_doubles_dmg() {
     let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
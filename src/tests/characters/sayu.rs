@@ -0,0 +1,89 @@
+use super::*;
+
+#[test]
+fn fuufuu_windwheel_makes_switch_a_fast_action() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Sayu, CharId::Fischl], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::YoohooArtFuuinDash),
+        ),
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::CeremonialBladework),
+        ),
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::FuufuuWindwheel));
+
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::SwitchCharacter(1),
+    )]);
+    assert_eq!(Some(PlayerId::PlayerFirst), gs.to_move_player());
+    assert!(!gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::FuufuuWindwheel));
+
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::SwitchCharacter(0),
+    )]);
+    assert_eq!(Some(PlayerId::PlayerSecond), gs.to_move_player());
+}
+
+#[test]
+fn muji_muji_daruma_heals_at_end_phase() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Sayu, CharId::Fischl], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst).char_states[0].set_hp(5);
+    gs.player_mut(PlayerId::PlayerFirst).char_states[1].set_hp(5);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::YoohooArtMujinaFlurry),
+    )]);
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::MujiMujiDaruma));
+    assert_eq!(9, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(7, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert_eq!(7, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+
+    gs.player_mut(PlayerId::PlayerFirst).char_states[1].set_hp(4);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(7, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert_eq!(6, gs.player(PlayerId::PlayerFirst).char_states[1].hp());
+}
+
+#[test]
+fn talent_card_increases_dmg_with_fuufuu_windwheel() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Sayu, CharId::Fischl], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::SkivingNewAndImproved);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::SkivingNewAndImproved, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.has_character_status(PlayerId::PlayerFirst, 0, StatusId::FuufuuWindwheel));
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::ShuumatsubanNinjaBlade),
+        ),
+    ]);
+    assert_eq!(5, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+}
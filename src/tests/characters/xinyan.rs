@@ -0,0 +1,81 @@
+use super::*;
+
+#[test]
+fn sweeping_fervor_creates_shield() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Xinyan], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::SweepingFervor)),
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::BoltsOfDownfall),
+        ),
+    ]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(10, gs.player(PlayerId::PlayerFirst).char_states[0].hp());
+    assert!(!gs.has_team_status(PlayerId::PlayerFirst, StatusId::FervorShield));
+}
+
+#[test]
+fn festive_fires_deals_pyro_dmg_after_normal_attack() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Xinyan], vector![CharId::Fischl, CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::RiffRevolution)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(7, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(9, gs.player(PlayerId::PlayerSecond).char_states[1].hp());
+    assert!(gs.has_team_status(PlayerId::PlayerFirst, StatusId::FestiveFires));
+
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::DanceOnFire),
+    )]);
+    assert_eq!(4, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert_eq!(
+        elem_set![Element::Pyro],
+        gs.player(PlayerId::PlayerSecond).char_states[0].applied
+    );
+    assert_eq!(
+        1,
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Team(StatusId::FestiveFires))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn talent_card_increases_normal_attack_dmg_with_fervor_shield() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Xinyan], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::RockinInAFlamingWorld);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::RockinInAFlamingWorld, Some(CardSelection::OwnCharacter(0))),
+    )]);
+    assert_eq!(8, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    assert!(gs.has_team_status(PlayerId::PlayerFirst, StatusId::FervorShield));
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::DanceOnFire)),
+    ]);
+    assert_eq!(5, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+    // Once per round
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::DanceOnFire),
+    )]);
+    assert_eq!(3, gs.player(PlayerId::PlayerSecond).char_states[0].hp());
+}
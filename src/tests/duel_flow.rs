@@ -1,4 +1,8 @@
+use crate::cmd_list;
 use crate::prelude::ByPlayer;
+use crate::types::card_defs::CardType;
+use crate::types::command::{Command, CommandContext};
+use crate::types::logging::{Event, VecEventLog};
 
 use super::*;
 
@@ -659,6 +663,122 @@ fn hand_size_limit() {
     assert_eq!(PlayerState::HAND_SIZE_LIMIT, gs.players.0.hand_len() as usize);
 }
 
+#[derive(Debug, Copy, Clone, Default)]
+struct WithVecEventLog;
+
+impl GameStateParams for WithVecEventLog {
+    type EventLog = VecEventLog;
+}
+
+#[test]
+fn hand_overflow_burns_cards() {
+    let gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Yoimiya], vector![CharId::Fischl]).build();
+    let mut gs = gs.with_log::<_, WithVecEventLog>(VecEventLog::new());
+    gs.advance_roll_phase_no_dice();
+    let mut hand = [CardId::BlankCard; PlayerState::HAND_SIZE_LIMIT];
+    hand[0] = CardId::Strategize;
+    gs.players.0.hand = hand.into();
+    gs.players.0.dice.set_single(Dice::Omni, 1);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::PlayCard(CardId::Strategize, None)),
+        Input::NondetResult(NondetResult::ProvideCards(
+            (list8![CardId::Paimon, CardId::SweetMadame], Default::default()).into(),
+        )),
+    ]);
+    assert_eq!(PlayerState::HAND_SIZE_LIMIT, gs.players.0.hand_len() as usize);
+    assert!(gs.players.0.hand.contains(&CardId::Paimon));
+    assert!(!gs.players.0.hand.contains(&CardId::SweetMadame));
+    let burned: Vec<_> = gs
+        .log
+        .events
+        .iter()
+        .filter_map(|e| match e {
+            Event::BurnCard(p, c) => Some((*p, *c)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(vec![(PlayerId::PlayerFirst, CardId::SweetMadame)], burned);
+}
+
+#[test]
+fn drawing_from_empty_deck_does_nothing() {
+    let gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Yoimiya], vector![CharId::Fischl]).build();
+    let mut gs = gs.with_log::<_, WithVecEventLog>(VecEventLog::new());
+    gs.advance_roll_phase_no_dice();
+    gs.players.0.hand = [CardId::Strategize].into();
+    gs.players.0.dice.set_single(Dice::Omni, 1);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::PlayCard(CardId::Strategize, None)),
+        Input::NondetResult(NondetResult::ProvideCards(
+            (list8![CardId::Paimon], Default::default()).into(),
+        )),
+    ]);
+    assert_eq!(1, gs.players.0.hand_len());
+    assert!(gs
+        .log
+        .events
+        .iter()
+        .any(|e| matches!(e, Event::DeckEmpty(PlayerId::PlayerFirst, 1))));
+    assert!(!gs
+        .log
+        .events
+        .iter()
+        .any(|e| matches!(e, Event::DeckEmpty(PlayerId::PlayerSecond, ..))));
+}
+
+#[test]
+fn large_draws_are_split_into_multiple_requests() {
+    let gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Yoimiya], vector![CharId::Fischl]).build();
+    let mut gs = gs.with_log::<_, WithVecEventLog>(VecEventLog::new());
+    gs.advance_roll_phase_no_dice();
+    gs.players.0.hand = Default::default();
+    gs.exec_commands(&cmd_list![(
+        CommandContext::new_event(PlayerId::PlayerFirst),
+        Command::DrawCards(12, None)
+    )])
+    .unwrap();
+    assert_eq!(
+        Some(NondetRequest::DrawCardsOfType(PlayerId::PlayerFirst, 8, None)),
+        gs.nondet_request()
+    );
+    gs.advance(Input::NondetResult(NondetResult::ProvideCards(
+        ([CardId::Paimon; 8].into(), Default::default()).into(),
+    )))
+    .unwrap();
+    assert_eq!(
+        Some(NondetRequest::DrawCardsOfType(PlayerId::PlayerFirst, 4, None)),
+        gs.nondet_request()
+    );
+    gs.advance(Input::NondetResult(NondetResult::ProvideCards(
+        ([CardId::SweetMadame; 4].into(), Default::default()).into(),
+    )))
+    .unwrap();
+    assert_eq!(None, gs.nondet_request());
+    assert_eq!(PlayerState::HAND_SIZE_LIMIT, gs.players.0.hand_len() as usize);
+    let burned = gs
+        .log
+        .events
+        .iter()
+        .filter(|e| matches!(e, Event::BurnCard(PlayerId::PlayerFirst, CardId::SweetMadame)))
+        .count();
+    assert_eq!(2, burned);
+}
+
+#[test]
+fn typed_draw_shortage_does_not_log_deck_empty() {
+    let gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Yoimiya], vector![CharId::Fischl]).build();
+    let mut gs = gs.with_log::<_, WithVecEventLog>(VecEventLog::new());
+    gs.log_deck_empty(
+        NondetRequest::DrawCardsOfType(PlayerId::PlayerFirst, 2, Some(CardType::Food)),
+        &(list8![CardId::SweetMadame], Default::default()).into(),
+    );
+    assert!(!gs.log.events.iter().any(|e| matches!(e, Event::DeckEmpty(..))));
+}
+
 #[test]
 fn auto_cost_payment_for_switching_based_on_switch_target() {
     let mut gs: GameState<()> =
@@ -0,0 +1,67 @@
+use super::*;
+
+#[test]
+fn bloom_dendro_core_increases_summon_dmg() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::Fischl, CharId::Collei],
+        vector![CharId::Yoimiya, CharId::Xingqiu],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::Nightrider)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::SwitchCharacter(1)),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(1)),
+        Input::FromPlayer(
+            PlayerId::PlayerSecond,
+            PlayerAction::CastSkill(SkillId::FatalRainscreen),
+        ),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FloralBrush)),
+    ]);
+
+    assert_eq!(
+        1,
+        gs.status_collection_mut(PlayerId::PlayerFirst)
+            .get(StatusKey::Team(StatusId::DendroCore))
+            .unwrap()
+            .usages()
+    );
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    assert!(gs
+        .status_collection_mut(PlayerId::PlayerFirst)
+        .get(StatusKey::Team(StatusId::DendroCore))
+        .is_none());
+}
+
+#[test]
+fn bloom_increases_dmg_and_creates_dendro_core() {
+    let mut gs = reaction_game_state(vector![CharId::Collei], Element::Hydro);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::FloralBrush),
+    )]);
+    // 10 - (3 + 1) = 6
+    assert_eq!(6, gs.players.1.char_states[0].hp());
+    assert!(gs.players.1.char_states[0].applied.is_empty());
+    assert!(gs.has_team_status(PlayerId::PlayerFirst, StatusId::DendroCore));
+}
+
+#[test]
+fn dendro_core_increases_pyro_dmg_to_active_character() {
+    let mut gs = reaction_game_state(vector![CharId::Collei, CharId::Bennett], Element::Hydro);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FloralBrush)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(1)),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::PassionOverload)),
+    ]);
+    // 6 - (3 + 2) = 1
+    assert_eq!(1, gs.players.1.char_states[0].hp());
+    assert!(!gs.has_team_status(PlayerId::PlayerFirst, StatusId::DendroCore));
+}
@@ -0,0 +1,74 @@
+use super::*;
+
+#[test]
+fn burning_max_2_stacks() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Collei], vector![CharId::Fischl])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerSecond).char_states[0]
+        .applied
+        .insert(Element::Pyro);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FloralBrush)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    assert_eq!(
+        1,
+        gs.status_collection_mut(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::BurningFlame))
+            .unwrap()
+            .usages()
+    );
+    gs.player_mut(PlayerId::PlayerSecond).char_states[0]
+        .applied
+        .insert(Element::Pyro);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::FloralBrush),
+    )]);
+    assert_eq!(
+        2,
+        gs.status_collection_mut(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::BurningFlame))
+            .unwrap()
+            .usages()
+    );
+    gs.player_mut(PlayerId::PlayerSecond).char_states[0]
+        .applied
+        .insert(Element::Pyro);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::FloralBrush),
+    )]);
+    assert_eq!(
+        2,
+        gs.status_collection_mut(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::BurningFlame))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn burning_increases_dmg_and_summons_burning_flame() {
+    let mut gs = reaction_game_state(vector![CharId::Collei], Element::Pyro);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::FloralBrush),
+    )]);
+    // 10 - (3 + 1) = 6
+    assert_eq!(6, gs.players.1.char_states[0].hp());
+    assert!(gs.players.1.char_states[0].applied.is_empty());
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::BurningFlame));
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::EndRound),
+        Input::NoAction,
+    ]);
+    // Burning Flame deals 1 Pyro DMG at End Phase
+    assert_eq!(5, gs.players.1.char_states[0].hp());
+    assert_eq!(elem_set![Element::Pyro], gs.players.1.char_states[0].applied);
+    assert!(!gs.has_summon(PlayerId::PlayerFirst, SummonId::BurningFlame));
+}
@@ -0,0 +1,46 @@
+use super::*;
+
+#[test]
+fn crystallize_increases_dmg_and_creates_shield() {
+    let mut gs = reaction_game_state(vector![CharId::Ningguang], Element::Pyro);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::SparklingScatter),
+    )]);
+    // 10 - (1 + 1) = 8
+    assert_eq!(8, gs.players.1.char_states[0].hp());
+    assert!(gs.players.1.char_states[0].applied.is_empty());
+    assert_eq!(
+        1,
+        gs.status_collection_mut(PlayerId::PlayerFirst)
+            .get(StatusKey::Team(StatusId::CrystallizeShield))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn crystallize_shield_max_2_stacks() {
+    let mut gs = reaction_game_state(vector![CharId::Ningguang], Element::Cryo);
+    gs.advance_multiple([
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::SparklingScatter),
+        ),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    for _ in 0..2 {
+        gs.players.1.char_states[0].applied.insert(Element::Hydro);
+        gs.advance_multiple([Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::SparklingScatter),
+        )]);
+    }
+    assert_eq!(
+        2,
+        gs.status_collection_mut(PlayerId::PlayerFirst)
+            .get(StatusKey::Team(StatusId::CrystallizeShield))
+            .unwrap()
+            .usages()
+    );
+}
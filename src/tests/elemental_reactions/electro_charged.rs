@@ -0,0 +1,28 @@
+use super::*;
+
+#[test]
+fn electro_charged_increases_dmg_and_deals_piercing_dmg() {
+    let mut gs = reaction_game_state(vector![CharId::Keqing], Element::Hydro);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::StellarRestoration),
+    )]);
+    // 10 - (3 + 1) = 6
+    assert_eq!(6, gs.players.1.char_states[0].hp());
+    assert!(gs.players.1.char_states[0].applied.is_empty());
+    assert_eq!(9, gs.players.1.char_states[1].hp());
+    assert_eq!(9, gs.players.1.char_states[2].hp());
+}
+
+#[test]
+fn electro_charged_piercing_dmg_skips_defeated_characters() {
+    let mut gs = reaction_game_state(vector![CharId::Keqing], Element::Hydro);
+    gs.players.1.char_states[1].set_hp(0);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::StellarRestoration),
+    )]);
+    assert_eq!(6, gs.players.1.char_states[0].hp());
+    assert_eq!(0, gs.players.1.char_states[1].hp());
+    assert_eq!(9, gs.players.1.char_states[2].hp());
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn melt_increases_pyro_dmg() {
+    let mut gs = reaction_game_state(vector![CharId::Bennett], Element::Cryo);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::PassionOverload),
+    )]);
+    // 10 - (3 + 2) = 5
+    assert_eq!(5, gs.players.1.char_states[0].hp());
+    assert!(gs.players.1.char_states[0].applied.is_empty());
+    assert_eq!(10, gs.players.1.char_states[1].hp());
+}
+
+#[test]
+fn melt_increases_cryo_dmg() {
+    let mut gs = reaction_game_state(vector![CharId::Kaeya], Element::Pyro);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::Frostgnaw),
+    )]);
+    assert_eq!(5, gs.players.1.char_states[0].hp());
+    assert!(gs.players.1.char_states[0].applied.is_empty());
+}
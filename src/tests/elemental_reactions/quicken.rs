@@ -0,0 +1,127 @@
+use super::*;
+
+#[test]
+fn quicken() {
+    let mut gs: GameState<()> = GameStateInitializer::new_skip_to_roll_phase(
+        vector![CharId::Collei, CharId::Fischl],
+        vector![CharId::Yoimiya, CharId::Fischl],
+    )
+    .ignore_costs(true)
+    .build();
+    gs.advance_roll_phase_no_dice();
+    {
+        let yoimiya = &mut gs.players.1.char_states[0];
+        yoimiya.applied |= Element::Electro;
+    }
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::FloralBrush),
+    )]);
+    assert!(gs.has_team_status(PlayerId::PlayerFirst, StatusId::CatalyzingField));
+    assert_eq!(
+        2,
+        gs.status_collection_mut(PlayerId::PlayerFirst).team_statuses_vec()[0]
+            .state
+            .usages()
+    );
+    assert_eq!(6, gs.player(PlayerId::PlayerSecond).active_character().hp());
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::SwitchCharacter(1)),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FloralBrush)),
+    ]);
+    assert_eq!(
+        1,
+        gs.status_collection_mut(PlayerId::PlayerFirst).team_statuses_vec()[0]
+            .state
+            .usages()
+    );
+    assert_eq!(6, gs.player(PlayerId::PlayerSecond).active_character().hp());
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(1)),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::BoltsOfDownfall)),
+    ]);
+    assert_eq!(
+        1,
+        gs.status_collection_mut(PlayerId::PlayerFirst).team_statuses_vec()[0]
+            .state
+            .usages()
+    );
+    assert_eq!(4, gs.player(PlayerId::PlayerSecond).active_character().hp());
+}
+
+#[test]
+fn aggravate_consumes_catalyzing_field_for_electro_dmg() {
+    let mut gs = reaction_game_state(vector![CharId::Collei, CharId::Keqing], Element::Electro);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FloralBrush)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    // Quicken: 10 - (3 + 1) = 6
+    assert_eq!(6, gs.players.1.char_states[0].hp());
+    assert!(gs.players.1.char_states[0].applied.is_empty());
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(1)),
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::StellarRestoration),
+        ),
+    ]);
+    // Aggravate: 6 - (3 + 1) = 2
+    assert_eq!(2, gs.players.1.char_states[0].hp());
+    assert_eq!(
+        1,
+        gs.status_collection_mut(PlayerId::PlayerFirst)
+            .get(StatusKey::Team(StatusId::CatalyzingField))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn spread_consumes_catalyzing_field_for_dendro_dmg() {
+    let mut gs = reaction_game_state(vector![CharId::Collei], Element::Electro);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FloralBrush)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FloralBrush)),
+    ]);
+    // Quicken, then Spread: 10 - (3 + 1) - (3 + 1) = 2
+    assert_eq!(2, gs.players.1.char_states[0].hp());
+    assert_eq!(elem_set![Element::Dendro], gs.players.1.char_states[0].applied);
+    assert_eq!(
+        1,
+        gs.status_collection_mut(PlayerId::PlayerFirst)
+            .get(StatusKey::Team(StatusId::CatalyzingField))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn catalyzing_field_does_not_apply_to_standby_characters() {
+    let mut gs = reaction_game_state(vector![CharId::Collei, CharId::Sucrose], Element::Electro);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::FloralBrush)),
+        Input::FromPlayer(PlayerId::PlayerSecond, PlayerAction::EndRound),
+    ]);
+    gs.players.1.char_states[0].applied.insert(Element::Electro);
+    gs.advance_multiple([
+        Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::SwitchCharacter(1)),
+        Input::FromPlayer(
+            PlayerId::PlayerFirst,
+            PlayerAction::CastSkill(SkillId::WindSpiritCreation),
+        ),
+    ]);
+    // Swirl: 6 - 1 = 5, 1 Electro DMG to each standby character
+    assert_eq!(5, gs.players.1.char_states[0].hp());
+    assert_eq!(9, gs.players.1.char_states[1].hp());
+    assert_eq!(9, gs.players.1.char_states[2].hp());
+    assert_eq!(
+        2,
+        gs.status_collection_mut(PlayerId::PlayerFirst)
+            .get(StatusKey::Team(StatusId::CatalyzingField))
+            .unwrap()
+            .usages()
+    );
+}
@@ -0,0 +1,15 @@
+use super::*;
+
+#[test]
+fn superconduct_increases_dmg_and_deals_piercing_dmg() {
+    let mut gs = reaction_game_state(vector![CharId::Kaeya], Element::Electro);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::Frostgnaw),
+    )]);
+    // 10 - (3 + 1) = 6
+    assert_eq!(6, gs.players.1.char_states[0].hp());
+    assert!(gs.players.1.char_states[0].applied.is_empty());
+    assert_eq!(9, gs.players.1.char_states[1].hp());
+    assert_eq!(9, gs.players.1.char_states[2].hp());
+}
@@ -0,0 +1,25 @@
+use super::*;
+
+#[test]
+fn vaporize_increases_pyro_dmg() {
+    let mut gs = reaction_game_state(vector![CharId::Bennett], Element::Hydro);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::PassionOverload),
+    )]);
+    // 10 - (3 + 2) = 5
+    assert_eq!(5, gs.players.1.char_states[0].hp());
+    assert!(gs.players.1.char_states[0].applied.is_empty());
+}
+
+#[test]
+fn vaporize_increases_hydro_dmg() {
+    let mut gs = reaction_game_state(vector![CharId::Xingqiu], Element::Pyro);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::FatalRainscreen),
+    )]);
+    // 10 - (2 + 2) = 6
+    assert_eq!(6, gs.players.1.char_states[0].hp());
+    assert!(gs.players.1.char_states[0].applied.is_empty());
+}
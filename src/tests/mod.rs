@@ -16,6 +16,8 @@ pub mod elemental_reactions;
 
 pub mod cards;
 
+pub mod summon_zone;
+
 pub mod zobrist_hash;
 
 pub mod perf;
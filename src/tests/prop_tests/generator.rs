@@ -215,7 +215,8 @@ prop_compose! {
         for _ in 0..steps {
             let Some(..) = gs.to_move() else { return gs; };
             let acts = gs.actions();
-            gs.advance(acts[rng.gen_range(0..acts.len())]).unwrap();
+            gs.advance(acts[rng.gen_range(0..acts.len())])
+                .expect("arb_reachable_game_state_winner: failed to advance");
         }
         panic!("arb_reachable_game_state_winner: unable to reach a winner state.")
     }
@@ -1,4 +1,4 @@
-pub(crate) mod generator;
+pub(crate) use crate::generator;
 use generator::*;
 
 use proptest::prelude::*;
@@ -0,0 +1,167 @@
+use super::*;
+
+/// Fill the first player's summon zone with the given summons.
+fn place_summons(gs: &mut GameState<()>, summon_ids: &[SummonId]) {
+    let sc = gs.status_collection_mut(PlayerId::PlayerFirst);
+    for &summon_id in summon_ids {
+        sc.apply_or_refresh_status(StatusKey::Summon(summon_id), summon_id.status(), &None);
+    }
+}
+
+fn summon_count(gs: &GameState<()>) -> usize {
+    gs.status_collection(PlayerId::PlayerFirst).summon_count()
+}
+
+#[test]
+fn summon_zone_does_not_exceed_max_summons() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Fischl], vector![CharId::Kaeya]).build();
+    place_summons(
+        &mut gs,
+        &[
+            SummonId::Guoba,
+            SummonId::BaronBunny,
+            SummonId::Ushi,
+            SummonId::DrunkenMist,
+            SummonId::Oz,
+        ],
+    );
+    assert_eq!(StatusCollection::MAX_SUMMONS, summon_count(&gs));
+    assert!(!gs.has_summon(PlayerId::PlayerFirst, SummonId::Oz));
+}
+
+#[test]
+fn full_summon_zone_discards_summon_from_skill() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Fischl], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    place_summons(
+        &mut gs,
+        &[
+            SummonId::Guoba,
+            SummonId::BaronBunny,
+            SummonId::Ushi,
+            SummonId::DrunkenMist,
+        ],
+    );
+    let cast_nightrider = Input::FromPlayer(PlayerId::PlayerFirst, PlayerAction::CastSkill(SkillId::Nightrider));
+    assert!(gs.available_actions().contains(&cast_nightrider));
+    gs.advance_multiple([cast_nightrider]);
+    // The skill still deals its DMG
+    assert_eq!(9, gs.players.1.char_states[0].hp());
+    assert!(!gs.has_summon(PlayerId::PlayerFirst, SummonId::Oz));
+    assert_eq!(StatusCollection::MAX_SUMMONS, summon_count(&gs));
+}
+
+#[test]
+fn full_summon_zone_refreshes_existing_summon() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Fischl], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    place_summons(
+        &mut gs,
+        &[SummonId::Guoba, SummonId::BaronBunny, SummonId::Ushi, SummonId::Oz],
+    );
+    gs.status_collection_mut(PlayerId::PlayerFirst)
+        .get_mut(StatusKey::Summon(SummonId::Oz))
+        .unwrap()
+        .set_usages(1);
+    assert!(gs.available_actions().contains(&Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::Nightrider)
+    )));
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::Nightrider),
+    )]);
+    assert_eq!(StatusCollection::MAX_SUMMONS, summon_count(&gs));
+    assert_eq!(
+        SummonId::Oz.status().usages.unwrap(),
+        gs.status_collection(PlayerId::PlayerFirst)
+            .get(StatusKey::Summon(SummonId::Oz))
+            .unwrap()
+            .usages()
+    );
+}
+
+#[test]
+fn full_summon_zone_allows_talent_card_with_summoning_skill() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Xiangling], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    gs.player_mut(PlayerId::PlayerFirst)
+        .add_to_hand_ignore(CardId::Crossfire);
+    place_summons(
+        &mut gs,
+        &[
+            SummonId::Oz,
+            SummonId::BaronBunny,
+            SummonId::Ushi,
+            SummonId::DrunkenMist,
+        ],
+    );
+    let play_crossfire = Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::PlayCard(CardId::Crossfire, Some(CardSelection::OwnCharacter(0))),
+    );
+    assert!(gs.available_actions().contains(&play_crossfire));
+    gs.advance_multiple([play_crossfire]);
+    assert!(gs.players.0.char_states[0].has_talent_equipped());
+    assert!(!gs.has_summon(PlayerId::PlayerFirst, SummonId::Guoba));
+    assert_eq!(StatusCollection::MAX_SUMMONS, summon_count(&gs));
+}
+
+#[test]
+fn full_summon_zone_discards_burning_flame() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::Collei], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    place_summons(
+        &mut gs,
+        &[SummonId::Oz, SummonId::Guoba, SummonId::Ushi, SummonId::DrunkenMist],
+    );
+    gs.players.1.char_states[0].applied.insert(Element::Pyro);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::FloralBrush),
+    )]);
+    // The reaction still happens: 10 - (3 + 1) = 6
+    assert_eq!(6, gs.players.1.char_states[0].hp());
+    assert!(!gs.has_summon(PlayerId::PlayerFirst, SummonId::BurningFlame));
+    assert_eq!(StatusCollection::MAX_SUMMONS, summon_count(&gs));
+}
+
+#[test]
+fn random_summons_limited_by_free_slots() {
+    let mut gs: GameState<()> =
+        GameStateInitializer::new_skip_to_roll_phase(vector![CharId::RhodeiaOfLoch], vector![CharId::Kaeya])
+            .ignore_costs(true)
+            .build();
+    gs.advance_roll_phase_no_dice();
+    place_summons(&mut gs, &[SummonId::Oz, SummonId::Guoba, SummonId::Ushi]);
+    gs.advance_multiple([Input::FromPlayer(
+        PlayerId::PlayerFirst,
+        PlayerAction::CastSkill(SkillId::TheMyriadWilds),
+    )]);
+    let Some(NondetRequest::SummonRandom(spec)) = gs.nondet_request() else {
+        panic!("expected SummonRandom request");
+    };
+    assert_eq!(2, spec.count);
+    assert_eq!(1, spec.free_slots);
+    // Summons beyond the zone limit are discarded even if provided
+    gs.advance_multiple([Input::NondetResult(NondetResult::ProvideSummonIds(list8![
+        SummonId::OceanidMimicSquirrel,
+        SummonId::OceanidMimicRaptor
+    ]))]);
+    assert_eq!(StatusCollection::MAX_SUMMONS, summon_count(&gs));
+    assert!(gs.has_summon(PlayerId::PlayerFirst, SummonId::OceanidMimicSquirrel));
+    assert!(!gs.has_summon(PlayerId::PlayerFirst, SummonId::OceanidMimicRaptor));
+}
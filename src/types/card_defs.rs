@@ -221,11 +221,13 @@ impl Cost {
 }
 
 pub trait SkillImpl {
+    /// `tgt_status_collection` belongs to the opponent of the player casting the skill.
     #[allow(unused_variables)]
     fn commands(
         &self,
         src_player: &PlayerState,
         status_collection: &StatusCollection,
+        tgt_status_collection: &StatusCollection,
         ctx: &CommandContext,
         cmds: &mut CommandList<(CommandContext, Command)>,
     ) {
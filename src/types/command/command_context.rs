@@ -5,6 +5,8 @@ use super::*;
 pub struct DMGInfo {
     pub target_hp: u8,
     pub target_affected_by_riptide: bool,
+    /// The DMG target is the target player's active character.
+    pub target_is_active: bool,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
@@ -50,6 +52,20 @@ impl CommandSource {
         }
     }
 
+    /// Matches `Card` with an own character selected
+    #[inline]
+    pub fn card_selected_char_idx(&self) -> Option<u8> {
+        if let CommandSource::Card {
+            target: Some(CardSelection::OwnCharacter(char_idx)),
+            ..
+        } = self
+        {
+            Some(*char_idx)
+        } else {
+            None
+        }
+    }
+
     /// Matches `Skill`
     #[inline]
     pub fn char_idx(&self) -> Option<u8> {
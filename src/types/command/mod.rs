@@ -215,6 +215,9 @@ pub enum EventId {
     BeforeAction,
     /// "When you declare the end of your Round:"
     DeclareEndOfRound,
+    /// "When a Talent card is equipped:"
+    /// The equipped character is the selected character of the card command source.
+    TalentEquipped,
 }
 
 #[derive(Debug)]
@@ -288,15 +291,28 @@ impl<'a, 'c, 'v, D: Copy> StatusImplContextBuilder<'a, 'c, 'v, D> {
     }
 }
 
+/// `Talent` is for playing a Talent card to be equipped on the character at `char_idx`.
+/// Its card cost already includes the cost of the skill casted by the Talent card, if any.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CostType {
     Switching { dst_char_idx: u8 },
     Card(CardId),
+    Talent { card_id: CardId, char_idx: u8 },
     Skill(SkillId),
 }
 
 impl CostType {
+    /// Cost type for playing a card with the given selection.
+    /// Talent cards targeting an own character are separated from the other cards.
+    #[inline]
+    pub fn for_card(card_id: CardId, selection: Option<CardSelection>) -> Self {
+        match (card_id.card().card_type, selection) {
+            (CardType::Talent(..), Some(CardSelection::OwnCharacter(char_idx))) => Self::Talent { card_id, char_idx },
+            _ => Self::Card(card_id),
+        }
+    }
+
     #[inline]
     pub fn skill(&self) -> Option<&'static Skill> {
         match self {
@@ -306,13 +322,23 @@ impl CostType {
     }
 
     #[inline]
-    pub fn card(&self) -> Option<&'static Card> {
-        match self {
-            Self::Card(card_id) => Some(card_id.card()),
+    pub fn card_id(&self) -> Option<CardId> {
+        match *self {
+            Self::Card(card_id) | Self::Talent { card_id, .. } => Some(card_id),
             _ => None,
         }
     }
 
+    #[inline]
+    pub fn card(&self) -> Option<&'static Card> {
+        self.card_id().map(CardId::card)
+    }
+
+    #[inline]
+    pub fn is_talent(&self) -> bool {
+        matches!(self, Self::Talent { .. })
+    }
+
     #[inline]
     pub fn is_switching(&self) -> bool {
         matches!(self, Self::Switching { .. })
@@ -325,6 +351,8 @@ pub struct SummonRandomSpec {
     pub summon_ids: List8<SummonId>,
     pub existing_summon_ids: EnumSet<SummonId>,
     pub count: u8,
+    /// Maximum number of new (not in `existing_summon_ids`) summons that can be placed.
+    pub free_slots: u8,
 }
 
 impl SummonRandomSpec {
@@ -333,12 +361,18 @@ impl SummonRandomSpec {
             summon_ids,
             existing_summon_ids,
             count,
+            free_slots: StatusCollection::MAX_SUMMONS as u8,
         }
     }
+
+    pub fn with_free_slots(self, free_slots: u8) -> Self {
+        Self { free_slots, ..self }
+    }
 }
 
-/// Target character index (`char_idx`) for `Command`s.
-#[derive(Debug, Clone, Copy, Default)]
+/// Target character selector for `Command`s, resolved against the command source player's characters.
+/// Selectors never resolve to defeated characters, and may resolve to zero or multiple characters.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CmdCharIdx {
     /// Actice character.
@@ -348,6 +382,20 @@ pub enum CmdCharIdx {
     CardSelected,
     /// Specific character index.
     Index(u8),
+    /// The injured character with the most missing HP. Ties are broken by the lowest character index.
+    MostInjured,
+    /// Same as `MostInjured`, but excluding the active character.
+    MostInjuredStandby,
+    /// The character with the lowest HP. Ties are broken by the lowest character index.
+    LowestHP,
+    /// The next character after the active character, cyclic.
+    NextAlive,
+    /// The previous character before the active character, cyclic.
+    PrevAlive,
+    /// All non-active characters.
+    AllStandby,
+    /// All characters.
+    All,
 }
 
 impl From<u8> for CmdCharIdx {
@@ -390,17 +438,12 @@ pub enum Command {
     TakeDMG(DealDMG),
     TakeDMGForAffectedBy(StatusId, DealDMG),
     InternalDealSwirlDMG(Element, u8),
-    /// Heal the active (or selected) character.
+    /// Heal the selected characters.
     Heal(u8, CmdCharIdx),
-    /// Heall all of the player's characters.
-    HealAll(u8),
-    /// Heal the character that has taken the most DMG.
-    HealTakenMostDMG(u8),
-    /// Add energy the selected character.
+    /// Add energy the selected characters.
     AddEnergy(u8, CmdCharIdx),
     /// Add energy to one character without maximum energy (active prioritized.)
     AddEnergyWithoutMaximum(u8),
-    AddEnergyToNonActiveCharacters(u8),
     /// Add energy to selected character of the player.
     SetEnergyForActiveCharacter(u8),
     /// For "Calx's Arts".
@@ -421,7 +464,7 @@ pub enum Command {
     SubtractDice(DiceCounter),
     AddCardsToHand(List8<CardId>),
     DrawCards(u8, Option<CardType>),
-    /// Apply a status to the player's character by index.
+    /// Apply a status to the player's selected characters.
     ApplyCharacterStatus(StatusId, CmdCharIdx),
     ApplyEquipment(EquipSlot, StatusId, CmdCharIdx),
     ApplyTalent(Option<StatusId>, CmdCharIdx),
@@ -452,6 +495,8 @@ pub enum Command {
     ///  - "creates 1 Lightning Stiletto"
     ///  - "When Keqing uses Stellar Restoration with this card (Lightning Stiletto) in Hand: ..."
     InternalStellarRestorationFromSkill,
+    /// Record the use of a "once per round" limit shared across instances of a card or status.
+    MarkUsedThisRound(RoundUsageKey),
 }
 
 impl ConstDefault for Command {
@@ -270,6 +270,20 @@ impl PlayerFlag {
     pub const END_OF_TURN_CLEAR: EnumSet<Self> = enum_set![Self::DiedThisRound];
 }
 
+/// Identifies a "once per round" limit that is shared by all instances of the same card or status
+/// of a player, as opposed to the once per round flag of `AppliedEffectState`, which is per instance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundUsageKey {
+    Card(CardId),
+    Status(StatusId),
+    Support(SupportId),
+}
+
+impl crate::data_structures::ConstDefault for RoundUsageKey {
+    const DEFAULT: Self = Self::Card(CardId::BlankCard);
+}
+
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlayerState {
@@ -279,10 +293,13 @@ pub struct PlayerState {
     pub(crate) char_states: CharStates,
     // TODO use wrapper type for hand
     pub(crate) hand: CapList<CardId, { PlayerState::HAND_SIZE_LIMIT }>,
+    /// "Once per round" limits used this round. Cleared in the End Phase.
+    pub(crate) used_this_round: CapList<RoundUsageKey, { PlayerState::ROUND_USAGES_LIMIT }>,
 }
 
 impl PlayerState {
     pub const HAND_SIZE_LIMIT: usize = 10;
+    pub const ROUND_USAGES_LIMIT: usize = 8;
 
     pub fn new<T: IntoIterator<Item = CharId>>(char_ids: T) -> Self {
         Self {
@@ -291,6 +308,7 @@ impl PlayerState {
             active_char_idx: 0,
             hand: Default::default(),
             flags: enum_set![],
+            used_this_round: Default::default(),
         }
     }
 
@@ -333,6 +351,11 @@ impl PlayerState {
     pub fn flags(&self) -> EnumSet<PlayerFlag> {
         self.flags
     }
+
+    #[inline]
+    pub fn is_used_this_round(&self, key: RoundUsageKey) -> bool {
+        self.used_this_round.contains(&key)
+    }
 }
 
 /// This type exists because when statuses are being modified, the entire
@@ -345,6 +368,7 @@ pub struct PlayerStateView<'a> {
     pub flags: EnumSet<PlayerFlag>,
     pub dice: DiceCounter,
     pub affected_by: SmallVec<[StatusKey; 4]>,
+    pub used_this_round: CapList<RoundUsageKey, { PlayerState::ROUND_USAGES_LIMIT }>,
 }
 
 impl<'a> PlayerStateView<'a> {
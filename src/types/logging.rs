@@ -30,6 +30,12 @@ pub enum Event {
     ElemApplied(PlayerId, (u8, CharId), Element),
     Reaction(PlayerId, (u8, CharId), Reaction),
     CharacterDied(PlayerId, (u8, CharId)),
+    /// Character revived with the given HP instead of being defeated.
+    CharacterRevived(PlayerId, (u8, CharId), u8),
+    /// Card discarded because the hand is full.
+    BurnCard(PlayerId, CardId),
+    /// Number of cards that could not be drawn because the deck is empty.
+    DeckEmpty(PlayerId, u8),
     // TODO excute command
 }
 
@@ -50,6 +56,9 @@ impl Event {
             Event::ElemApplied(..) => 3,
             Event::Reaction(..) => 3,
             Event::CharacterDied(..) => 2,
+            Event::CharacterRevived(..) => 2,
+            Event::BurnCard(..) => 2,
+            Event::DeckEmpty(..) => 2,
         }
     }
 }
@@ -101,6 +110,11 @@ impl Display for Event {
             Event::Reaction(_, _, r) => f.write_fmt(format_args!("Reaction triggered: {r:?}")),
             Event::TriggerEvent(_, e) => f.write_fmt(format_args!("Event triggered: {e:?}")),
             Event::Summon(p, s) => f.write_fmt(format_args!("{p} summoned: {}", s.status().name)),
+            Event::CharacterRevived(p, (_, c), v) => {
+                f.write_fmt(format_args!("{p} {}: Revived with {v} HP", c.char_card().name))
+            }
+            Event::BurnCard(p, c) => f.write_fmt(format_args!("{p} burned a Card (hand full): {}", c.card().name)),
+            Event::DeckEmpty(p, n) => f.write_fmt(format_args!("{p} could not draw {n} Card(s): deck is empty")),
             _ => Ok(()),
         }
     }
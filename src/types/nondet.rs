@@ -9,7 +9,7 @@ use crate::{
 // use rand::prelude::*;
 
 use enumset::{enum_set, EnumSet, EnumSetType};
-use rand::{Rng, RngCore};
+use rand::{rngs::SmallRng, Rng, RngCore, SeedableRng};
 use rustc_hash::FxHasher;
 
 use smallvec::SmallVec;
@@ -81,17 +81,28 @@ pub enum StandardNondetHandlerFlags {
 pub struct StandardNondetHandlerState {
     pub dice_determinization: DiceDeterminization,
     pub decks: (DeckState, DeckState),
+    /// RNG for random summons and for determinizing hidden dice.
     pub rng: RngState,
+    /// Separate RNG streams for the card draws and dice rolls of each player, so that the luck of
+    /// one player does not depend on the actions of the other player.
+    pub player_rngs: ByPlayer<RngState>,
     pub flags: EnumSet<StandardNondetHandlerFlags>,
 }
 
 impl StandardNondetHandlerState {
-    pub fn new(s1: &Decklist, s2: &Decklist, rng: RngState) -> Self {
+    /// The per-player RNG streams are seeded from `rng`.
+    pub fn new(s1: &Decklist, s2: &Decklist, mut rng: RngState) -> Self {
+        let player_rngs = ByPlayer::generate(|_| RngState(SmallRng::seed_from_u64(rng.next_u64())));
+        Self::new_with_player_rngs(s1, s2, rng, player_rngs)
+    }
+
+    pub fn new_with_player_rngs(s1: &Decklist, s2: &Decklist, rng: RngState, player_rngs: ByPlayer<RngState>) -> Self {
         Self {
             // TODO allow external customization
             dice_determinization: DiceDeterminization::Simplified { extra_omnis: 2 },
             decks: (DeckState::new(s1), DeckState::new(s2)),
             rng,
+            player_rngs,
             flags: Default::default(),
         }
     }
@@ -116,20 +127,19 @@ impl StandardNondetHandlerState {
         if self.should_hide_player_dice(player_id) {
             self.dice_determinization.determinize(&mut self.rng, dist)
         } else {
-            DiceCounter::rand_with_reroll(&mut self.rng, dist)
+            DiceCounter::rand_with_reroll(self.player_rngs.get_mut(player_id), dist)
         }
     }
 
     fn draw_cards(&mut self, player_id: PlayerId, count: u8) -> List8<CardId> {
-        if count >= 8 {
-            unimplemented!();
-        }
+        // The dispatcher splits larger draws into multiple requests, so this only guards the result size
+        let count = min(count, NondetRequest::MAX_DRAW_COUNT);
         let hide = self.should_hide_player_cards(player_id);
         let d = player_id.select_mut(&mut self.decks);
-        let mut v = SmallVec::<[CardId; 8]>::with_capacity(min(8, count as usize));
-        let range = 0..min(8, count);
-        for _ in range {
-            if let Some(c) = d.draw(&mut self.rng) {
+        let rng = self.player_rngs.get_mut(player_id);
+        let mut v = SmallVec::<[CardId; 8]>::with_capacity(count as usize);
+        for _ in 0..count {
+            if let Some(c) = d.draw(rng) {
                 v.push(if hide { CardId::BlankCard } else { c })
             } else {
                 break;
@@ -145,15 +155,20 @@ impl ZobristHashable for StandardNondetHandlerState {
         self.decks.0.mask.hash(&mut h);
         self.decks.1.mask.hash(&mut h);
         let mut rng = self.rng.clone();
+        let mut player_rngs = self.player_rngs.clone();
         #[cfg(feature = "hash128")]
         {
             let mut bytes = [0u8; 16];
-            rng.fill_bytes(&mut bytes);
-            (h.finish() as u128) ^ u128::from_be_bytes(bytes)
+            let mut hash = h.finish() as u128;
+            for rng in [&mut rng, &mut player_rngs.0, &mut player_rngs.1] {
+                rng.fill_bytes(&mut bytes);
+                hash ^= u128::from_be_bytes(bytes);
+            }
+            hash
         }
         #[cfg(not(feature = "hash128"))]
         {
-            h.finish() ^ rng.next_u64()
+            h.finish() ^ rng.next_u64() ^ player_rngs.0.next_u64() ^ player_rngs.1.next_u64()
         }
     }
 }
@@ -265,9 +280,21 @@ impl SummonRandomSpec {
             summon_ids,
             existing_summon_ids,
             count,
+            free_slots,
         } = *self;
         let count = count as usize;
         let mut to_summon: EnumSet<SummonId> = summon_ids.to_enum_set();
+        // New summons that do not fit in the summon zone are discarded
+        for _ in 0..summon_ids.len() {
+            let new_summons = to_summon - existing_summon_ids;
+            if new_summons.len() <= free_slots as usize {
+                break;
+            }
+            let Some(randomly_selected) = new_summons.iter().nth(r.gen_range(0..new_summons.len())) else {
+                break;
+            };
+            to_summon.remove(randomly_selected);
+        }
         for _ in 0..summon_ids.len() {
             if to_summon.len() <= count {
                 break;
@@ -416,4 +443,95 @@ mod summon_random_sample_test {
             );
         }
     }
+
+    #[test]
+    fn test_free_slots() {
+        let mut r = SmallRng::seed_from_u64(0);
+        for _ in 0..10 {
+            let spec = SummonRandomSpec::new(SUMMON_IDS, enum_set![], 2).with_free_slots(1);
+            assert_eq!(1, spec.sample(&mut r).len());
+
+            let spec = SummonRandomSpec::new(SUMMON_IDS, enum_set![], 2).with_free_slots(0);
+            assert_eq!(enum_set![], spec.sample(&mut r).to_enum_set());
+
+            // Existing summons are refreshed regardless of free slots
+            let spec = SummonRandomSpec::new(SUMMON_IDS, enum_set![SummonId::OceanidMimicFrog], 1).with_free_slots(0);
+            assert_eq!(enum_set![SummonId::OceanidMimicFrog], spec.sample(&mut r).to_enum_set());
+
+            let spec = SummonRandomSpec::new(SUMMON_IDS, enum_set![SummonId::OceanidMimicFrog], 2).with_free_slots(0);
+            assert_eq!(enum_set![SummonId::OceanidMimicFrog], spec.sample(&mut r).to_enum_set());
+        }
+    }
+}
+
+#[cfg(test)]
+mod player_rngs_test {
+    use super::*;
+    use crate::deck::sample_deck;
+    use smallvec::smallvec;
+
+    fn decklists() -> (Decklist, Decklist) {
+        let chars = smallvec![CharId::Ganyu, CharId::Xingqiu, CharId::Keqing];
+        let mut reversed = sample_deck();
+        reversed.reverse();
+        (
+            Decklist::new(chars.clone(), sample_deck()),
+            Decklist::new(chars, reversed),
+        )
+    }
+
+    fn dice() -> DiceDistribution {
+        DiceDistribution::new(8, 1, Default::default(), Default::default())
+    }
+
+    fn rng(seed: u64) -> RngState {
+        RngState(SmallRng::seed_from_u64(seed))
+    }
+
+    #[test]
+    fn test_draws_do_not_depend_on_opponent() {
+        let (d1, d2) = decklists();
+        let mut a = StandardNondetHandlerState::new(&d1, &d2, rng(1));
+        let mut b = a.clone();
+        b.draw_cards(PlayerId::PlayerSecond, 5);
+        b.roll_dice(PlayerId::PlayerSecond, dice());
+        assert_eq!(
+            a.draw_cards(PlayerId::PlayerFirst, 5),
+            b.draw_cards(PlayerId::PlayerFirst, 5)
+        );
+        assert_eq!(
+            a.roll_dice(PlayerId::PlayerFirst, dice()),
+            b.roll_dice(PlayerId::PlayerFirst, dice())
+        );
+    }
+
+    #[test]
+    fn test_swapped_sides_with_swapped_player_rngs() {
+        let (d1, d2) = decklists();
+        let mut a = StandardNondetHandlerState::new_with_player_rngs(&d1, &d2, rng(1), ByPlayer(rng(2), rng(3)));
+        let mut b = StandardNondetHandlerState::new_with_player_rngs(&d2, &d1, rng(1), ByPlayer(rng(3), rng(2)));
+        assert_eq!(
+            a.draw_cards(PlayerId::PlayerFirst, 5),
+            b.draw_cards(PlayerId::PlayerSecond, 5)
+        );
+        assert_eq!(
+            a.roll_dice(PlayerId::PlayerSecond, dice()),
+            b.roll_dice(PlayerId::PlayerFirst, dice())
+        );
+    }
+
+    #[test]
+    fn test_large_draws_and_empty_deck() {
+        let (d1, d2) = decklists();
+        let mut a = StandardNondetHandlerState::new(&d1, &d2, rng(1));
+        let deck_size = d1.cards.len();
+        let mut drawn = 0;
+        for _ in 0..deck_size {
+            let cards = a.draw_cards(PlayerId::PlayerFirst, 20);
+            assert!(cards.len() <= NondetRequest::MAX_DRAW_COUNT);
+            drawn += cards.len() as usize;
+        }
+        assert_eq!(deck_size, drawn);
+        assert!(a.draw_cards(PlayerId::PlayerFirst, 2).is_empty());
+    }
 }
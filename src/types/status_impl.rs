@@ -33,6 +33,8 @@ pub enum RespondsTo {
     CannotPerformActions,
     /// Switch from (not to) the active character is Fast Action
     SwitchIsFastAction,
+    /// Prevents the attached character from being defeated
+    PreventDefeat,
 }
 
 status_impl_trait_decl!(
@@ -128,6 +130,12 @@ status_impl_trait_decl!(
             None
         }
 
+        /// Called when the character would be defeated.
+        /// Sets `revive_hp` to the HP the character is revived with.
+        fn prevent_defeat(&self, eff_state: &AppliedEffectState, revive_hp: &mut u8) -> Option<AppliedEffectResult> {
+            None
+        }
+
         fn trigger_event(&self, e: &mut TriggerEventContext<EventId>) -> Option<AppliedEffectResult> {
             None
         }
@@ -25,14 +25,16 @@ macro_rules! phc {
 }
 
 impl PlayerState {
+    /// Returns: false if the hand is full and the card is not added.
     #[inline]
-    pub fn add_card_to_hand(&mut self, (h, player_id): PlayerHashContext, card_id: CardId) {
+    pub fn add_card_to_hand(&mut self, (h, player_id): PlayerHashContext, card_id: CardId) -> bool {
         let count = self.hand.iter().copied().filter(|c| *c == card_id).count() as u8;
         if self.hand.push(card_id).is_err() {
-            return;
+            return false;
         };
         h.hash(HASH_PROVIDER.hand(player_id, card_id, count));
         h.hash(HASH_PROVIDER.hand(player_id, card_id, count + 1));
+        true
     }
 
     #[inline]
@@ -123,6 +125,21 @@ impl PlayerState {
         h.hash(HASH_PROVIDER.player_flags(player_id, self.flags));
     }
 
+    /// Records the use of a "once per round" limit shared across instances of a card or status.
+    #[inline]
+    pub fn mark_used_this_round(&mut self, (h, player_id): PlayerHashContext, key: RoundUsageKey) {
+        if self.is_used_this_round(key) || self.used_this_round.push(key).is_err() {
+            return;
+        }
+        h.hash(HASH_PROVIDER.round_usage(player_id, key));
+    }
+
+    #[inline]
+    pub fn clear_used_this_round(&mut self, (h, player_id): PlayerHashContext) {
+        self.zobrist_hash_for_round_usages(h, player_id);
+        self.used_this_round = Default::default();
+    }
+
     #[inline]
     pub fn insert_flag(&mut self, (h, player_id): PlayerHashContext, flag: PlayerFlag) {
         h.hash(HASH_PROVIDER.player_flags(player_id, self.flags));
@@ -207,7 +224,8 @@ impl CharState {
     #[inline]
     fn on_death_hashed(&mut self, (h, player_id, char_idx): CharacterHashContext) {
         self.set_energy_hashed((h, player_id, char_idx), 0);
-        self.set_flags_hashed((h, player_id, char_idx), Default::default());
+        // Retained flags are cleared when the defeat is resolved, unless the character is revived
+        self.set_flags_hashed((h, player_id, char_idx), self.flags & CharFlag::RETAIN);
         self.set_applied_elements_hashed((h, player_id, char_idx), Default::default());
         h.hash(HASH_PROVIDER.total_dmg_taken(player_id, char_idx, self.total_dmg_taken));
         self.total_dmg_taken = 0;
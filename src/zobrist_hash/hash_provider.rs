@@ -286,6 +286,14 @@ impl HashProvider {
         h.finish() as HashValue
     }
 
+    #[inline]
+    pub fn round_usage(&self, player_id: PlayerId, key: RoundUsageKey) -> HashValue {
+        let mut h = FxHasher::default();
+        player_id.hash(&mut h);
+        key.hash(&mut h);
+        h.finish() as HashValue
+    }
+
     #[inline]
     pub fn post_death_switch(&self, player_id: PlayerId) -> HashValue {
         self.other_hashes[player_id][2]
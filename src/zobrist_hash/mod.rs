@@ -419,6 +419,13 @@ impl PlayerState {
         h.hash(HASH_PROVIDER.player_flags(player_id, self.flags));
     }
 
+    #[inline]
+    pub fn zobrist_hash_for_round_usages(&self, h: &mut ZobristHasher, player_id: PlayerId) {
+        for &key in self.used_this_round.iter() {
+            h.hash(HASH_PROVIDER.round_usage(player_id, key));
+        }
+    }
+
     pub fn zobrist_hash_for_char_states(&self, h: &mut ZobristHasher, player_id: PlayerId) {
         for (i, cs) in self.char_states.iter_all().enumerate() {
             cs.zobrist_hash(h, player_id, i as u8);
@@ -433,6 +440,7 @@ impl PlayerState {
     pub fn incremental_zobrist_hash(&self, h: &mut ZobristHasher, player_id: PlayerId) {
         h.hash(HASH_PROVIDER.active_char_idx(player_id, self.active_char_idx));
         self.zobrist_hash_for_flags(h, player_id);
+        self.zobrist_hash_for_round_usages(h, player_id);
         self.zobrist_hash_for_dice(h, player_id);
         self.zobrist_hash_for_hand(h, player_id);
         self.zobrist_hash_for_char_states(h, player_id);